@@ -1,6 +1,9 @@
 /// 消息类型
 enum MessageType { text, image, voice, video, file }
 
+/// 消息发送状态（对应 Rust 发件箱的 MessageSendStatus）
+enum MessageStatus { sending, sent, failed }
+
 /// 消息模型
 class Message {
   final String id;
@@ -8,7 +11,7 @@ class Message {
   final String content;
   final MessageType type;
   final DateTime timestamp;
-  final bool isSent; // 是否已发送
+  final MessageStatus status; // 发送状态
 
   Message({
     required this.id,
@@ -16,9 +19,12 @@ class Message {
     required this.content,
     this.type = MessageType.text,
     required this.timestamp,
-    this.isSent = true,
+    this.status = MessageStatus.sent,
   });
 
+  // 是否已发送
+  bool get isSent => status == MessageStatus.sent;
+
   // 判断是否是自己发送的消息
   bool get isFromMe => senderId == '1';
 }
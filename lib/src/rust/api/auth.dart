@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'auth.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `authenticator`, `token_storage`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartTokenStorage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `delete`, `load`, `save`
+
+
+            /// 设置 token 存储（默认保存在内存中），应在 login / restore_session 之前调用
+Future<void>  setTokenStorage({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) delete }) => RustLib.instance.api.crateApiAuthSetTokenStorage(load: load, save: save, delete: delete);
+
+/// 登录并保存 token
+Future<LoginSession>  login({required LoginCredentials credentials , required int platformId }) => RustLib.instance.api.crateApiAuthLogin(credentials: credentials, platformId: platformId);
+
+/// 恢复上次登录的会话（token 不存在或已过期时返回 None）
+Future<LoginSession?>  restoreSession() => RustLib.instance.api.crateApiAuthRestoreSession();
+
+/// 退出登录，清除保存的 token
+Future<void>  logout() => RustLib.instance.api.crateApiAuthLogout();
+
+            @freezed
+                sealed class LoginCredentials with _$LoginCredentials  {
+                    const LoginCredentials._();
+
+                     /// 业务服务器账号密码登录，url 为完整登录地址
+const factory LoginCredentials.appServer({   required String url ,  required String account ,  required String password , }) = LoginCredentials_AppServer;
+ /// OpenIM 管理员密钥换取用户 token（仅限开发测试），admin_user_id 为空时使用 imAdmin
+const factory LoginCredentials.adminSecret({   required String apiUrl ,  required String secret ,  required String userId ,  required String adminUserId , }) = LoginCredentials_AdminSecret;
+
+                    
+
+                    
+                }
+
+/// 登录后的会话信息，用于创建 OpenIMClient
+class LoginSession  {
+                final String userId;
+final String token;
+final int platformId;
+/// token 过期时间（秒）
+final PlatformInt64 expiresAt;
+
+                const LoginSession({required this.userId ,required this.token ,required this.platformId ,required this.expiresAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^token.hashCode^platformId.hashCode^expiresAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LoginSession &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& token == other.token&& platformId == other.platformId&& expiresAt == other.expiresAt;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,72 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `replay_frames`
+
+
+            /// 回放抓包：在以 user_id 身份新建的内存客户端上重新解码收到的帧，返回解析失败的帧
+///
+/// 回放不会写入任何已登录用户的数据库（抓包中可能包含其他用户的消息）。
+Future<ReplayReport>  replayCapture({required String userId , required String path }) => RustLib.instance.api.crateApiCaptureReplayCapture(userId: userId, path: path);
+
+            /// 回放失败的帧
+class ReplayError  {
+                /// 帧在抓包文件中的序号（从 0 开始）
+final int index;
+final PlatformInt64 timeMs;
+final String error;
+
+                const ReplayError({required this.index ,required this.timeMs ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^timeMs.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplayError &&
+                runtimeType == other.runtimeType
+                && index == other.index&& timeMs == other.timeMs&& error == other.error;
+        
+            }
+
+/// 回放结果
+class ReplayReport  {
+                /// 抓包中的总帧数
+final int frames;
+/// 重新解码的帧数（收到的二进制帧）
+final int replayed;
+final List<ReplayError> errors;
+
+                const ReplayReport({required this.frames ,required this.replayed ,required this.errors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => frames.hashCode^replayed.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReplayReport &&
+                runtimeType == other.runtimeType
+                && frames == other.frames&& replayed == other.replayed&& errors == other.errors;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,114 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`, `from`
+
+
+            
+
+            /// 黑名单用户
+class BlockedUser  {
+                final String userId;
+final String nickname;
+final String faceUrl;
+final PlatformInt64 createTime;
+
+                const BlockedUser({required this.userId ,required this.nickname ,required this.faceUrl ,required this.createTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^nickname.hashCode^faceUrl.hashCode^createTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BlockedUser &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& nickname == other.nickname&& faceUrl == other.faceUrl&& createTime == other.createTime;
+        
+            }
+
+/// 联系人缓存变化，收到后重新读取对应列表
+enum ContactChange {
+                    friends,
+blacklist,
+friendRequests,
+                    ;
+                    
+                }
+
+/// 好友
+class Friend  {
+                final String userId;
+final String nickname;
+final String faceUrl;
+final String remark;
+final String ex;
+final PlatformInt64 createTime;
+
+                const Friend({required this.userId ,required this.nickname ,required this.faceUrl ,required this.remark ,required this.ex ,required this.createTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^nickname.hashCode^faceUrl.hashCode^remark.hashCode^ex.hashCode^createTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Friend &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& nickname == other.nickname&& faceUrl == other.faceUrl&& remark == other.remark&& ex == other.ex&& createTime == other.createTime;
+        
+            }
+
+/// 好友申请（包括收到的和自己发出的）
+class FriendRequest  {
+                final String fromUserId;
+final String fromNickname;
+final String fromFaceUrl;
+final String toUserId;
+final String toNickname;
+final String toFaceUrl;
+/// 0 未处理，1 已同意，-1 已拒绝
+final int handleResult;
+final String reqMsg;
+final String handleMsg;
+final PlatformInt64 createTime;
+final PlatformInt64 handleTime;
+
+                const FriendRequest({required this.fromUserId ,required this.fromNickname ,required this.fromFaceUrl ,required this.toUserId ,required this.toNickname ,required this.toFaceUrl ,required this.handleResult ,required this.reqMsg ,required this.handleMsg ,required this.createTime ,required this.handleTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fromUserId.hashCode^fromNickname.hashCode^fromFaceUrl.hashCode^toUserId.hashCode^toNickname.hashCode^toFaceUrl.hashCode^handleResult.hashCode^reqMsg.hashCode^handleMsg.hashCode^createTime.hashCode^handleTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FriendRequest &&
+                runtimeType == other.runtimeType
+                && fromUserId == other.fromUserId&& fromNickname == other.fromNickname&& fromFaceUrl == other.fromFaceUrl&& toUserId == other.toUserId&& toNickname == other.toNickname&& toFaceUrl == other.toFaceUrl&& handleResult == other.handleResult&& reqMsg == other.reqMsg&& handleMsg == other.handleMsg&& createTime == other.createTime&& handleTime == other.handleTime;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`
+
+
+            
+
+            /// 会话变化，收到后重新读取会话列表
+class ConversationChange  {
+                final String conversationId;
+
+                const ConversationChange({required this.conversationId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => conversationId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConversationChange &&
+                runtimeType == other.runtimeType
+                && conversationId == other.conversationId;
+        
+            }
+
+/// 会话列表中的会话
+class ConversationInfo  {
+                final String conversationId;
+/// 1 单聊，3 群聊
+final int conversationType;
+final String userId;
+final String groupId;
+final RecvMsgOpt recvMsgOpt;
+final bool isPinned;
+final String draftText;
+final PlatformInt64 draftTextTime;
+final PlatformInt64 latestMsgTime;
+final String? latestMsgClientMsgId;
+final String? latestMsgSendId;
+final int? latestMsgContentType;
+/// 最新消息的文本（不含文本的消息类型为 None）
+final String? latestMsgText;
+final String ex;
+
+                const ConversationInfo({required this.conversationId ,required this.conversationType ,required this.userId ,required this.groupId ,required this.recvMsgOpt ,required this.isPinned ,required this.draftText ,required this.draftTextTime ,required this.latestMsgTime ,this.latestMsgClientMsgId ,this.latestMsgSendId ,this.latestMsgContentType ,this.latestMsgText ,required this.ex ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => conversationId.hashCode^conversationType.hashCode^userId.hashCode^groupId.hashCode^recvMsgOpt.hashCode^isPinned.hashCode^draftText.hashCode^draftTextTime.hashCode^latestMsgTime.hashCode^latestMsgClientMsgId.hashCode^latestMsgSendId.hashCode^latestMsgContentType.hashCode^latestMsgText.hashCode^ex.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConversationInfo &&
+                runtimeType == other.runtimeType
+                && conversationId == other.conversationId&& conversationType == other.conversationType&& userId == other.userId&& groupId == other.groupId&& recvMsgOpt == other.recvMsgOpt&& isPinned == other.isPinned&& draftText == other.draftText&& draftTextTime == other.draftTextTime&& latestMsgTime == other.latestMsgTime&& latestMsgClientMsgId == other.latestMsgClientMsgId&& latestMsgSendId == other.latestMsgSendId&& latestMsgContentType == other.latestMsgContentType&& latestMsgText == other.latestMsgText&& ex == other.ex;
+        
+            }
+
+/// 消息接收选项
+enum RecvMsgOpt {
+                    /// 正常接收并通知
+normal,
+/// 不接收消息
+notReceive,
+/// 接收但不通知（免打扰）
+notNotify,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,103 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'groups.freezed.dart';
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`
+
+
+            
+
+            /// 群资料
+class Group  {
+                final String groupId;
+final String groupName;
+/// 群公告
+final String notification;
+final String introduction;
+final String faceUrl;
+final String ownerUserId;
+final int memberCount;
+/// 0 正常，1 被封禁，2 已解散，3 全员禁言
+final int status;
+final PlatformInt64 createTime;
+
+                const Group({required this.groupId ,required this.groupName ,required this.notification ,required this.introduction ,required this.faceUrl ,required this.ownerUserId ,required this.memberCount ,required this.status ,required this.createTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => groupId.hashCode^groupName.hashCode^notification.hashCode^introduction.hashCode^faceUrl.hashCode^ownerUserId.hashCode^memberCount.hashCode^status.hashCode^createTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Group &&
+                runtimeType == other.runtimeType
+                && groupId == other.groupId&& groupName == other.groupName&& notification == other.notification&& introduction == other.introduction&& faceUrl == other.faceUrl&& ownerUserId == other.ownerUserId&& memberCount == other.memberCount&& status == other.status&& createTime == other.createTime;
+        
+            }
+
+@freezed
+                sealed class GroupChange with _$GroupChange  {
+                    const GroupChange._();
+
+                     /// 已加入的群列表变化
+const factory GroupChange.joinedGroups() = GroupChange_JoinedGroups;
+ const factory GroupChange.info({   required String groupId , }) = GroupChange_Info;
+ const factory GroupChange.members({   required String groupId , }) = GroupChange_Members;
+
+                    
+
+                    
+                }
+
+/// 群成员
+class GroupMember  {
+                final String groupId;
+final String userId;
+final String nickname;
+final String faceUrl;
+final GroupRole role;
+final PlatformInt64 joinTime;
+/// 禁言结束时间（毫秒），0 表示未禁言
+final PlatformInt64 muteEndTime;
+
+                const GroupMember({required this.groupId ,required this.userId ,required this.nickname ,required this.faceUrl ,required this.role ,required this.joinTime ,required this.muteEndTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => groupId.hashCode^userId.hashCode^nickname.hashCode^faceUrl.hashCode^role.hashCode^joinTime.hashCode^muteEndTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GroupMember &&
+                runtimeType == other.runtimeType
+                && groupId == other.groupId&& userId == other.userId&& nickname == other.nickname&& faceUrl == other.faceUrl&& role == other.role&& joinTime == other.joinTime&& muteEndTime == other.muteEndTime;
+        
+            }
+
+/// 群成员角色
+enum GroupRole {
+                    owner,
+admin,
+member,
+                    ;
+                    
+                }
+            
\ No newline at end of file
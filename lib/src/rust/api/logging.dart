@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`, `from`
+
+
+            /// 初始化日志（只能调用一次），log_dir 不为空时按天滚动写入日志文件，最多保留 max_files 个
+Future<void>  initLogging({required LogLevel level , String? logDir , required int maxFiles }) => RustLib.instance.api.crateApiLoggingInitLogging(level: level, logDir: logDir, maxFiles: maxFiles);
+
+/// 日志目录中的日志文件路径（从旧到新），用于问题反馈时上传
+Future<List<String>>  getLogFiles({required String logDir }) => RustLib.instance.api.crateApiLoggingGetLogFiles(logDir: logDir);
+
+/// 订阅日志（只包含订阅之后的日志）
+Stream<LogEntry>  logStream() => RustLib.instance.api.crateApiLoggingLogStream();
+
+            /// 一条日志
+class LogEntry  {
+                final PlatformInt64 timeMs;
+final LogLevel level;
+final String target;
+final String message;
+final List<LogField> fields;
+/// 所在的 span，如 `openim{user_id=u1}:session{operation_id=1}`
+final String spans;
+
+                const LogEntry({required this.timeMs ,required this.level ,required this.target ,required this.message ,required this.fields ,required this.spans ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timeMs.hashCode^level.hashCode^target.hashCode^message.hashCode^fields.hashCode^spans.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogEntry &&
+                runtimeType == other.runtimeType
+                && timeMs == other.timeMs&& level == other.level&& target == other.target&& message == other.message&& fields == other.fields&& spans == other.spans;
+        
+            }
+
+/// 日志字段
+class LogField  {
+                final String name;
+final String value;
+
+                const LogField({required this.name ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogField &&
+                runtimeType == other.runtimeType
+                && name == other.name&& value == other.value;
+        
+            }
+
+/// 日志级别
+enum LogLevel {
+                    trace,
+debug,
+info,
+warn,
+error,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`
+
+
+            
+
+            /// 聊天消息
+class ChatMessage  {
+                final String clientMsgId;
+final String serverMsgId;
+final String conversationId;
+final PlatformInt64 seq;
+final String sendId;
+final String recvId;
+final String groupId;
+final int sessionType;
+final int contentType;
+/// 消息内容（JSON）
+final Uint8List content;
+/// 消息中的文本（不含文本的消息类型为 None）
+final String? text;
+final PlatformInt64 sendTime;
+
+                const ChatMessage({required this.clientMsgId ,required this.serverMsgId ,required this.conversationId ,required this.seq ,required this.sendId ,required this.recvId ,required this.groupId ,required this.sessionType ,required this.contentType ,required this.content ,this.text ,required this.sendTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => clientMsgId.hashCode^serverMsgId.hashCode^conversationId.hashCode^seq.hashCode^sendId.hashCode^recvId.hashCode^groupId.hashCode^sessionType.hashCode^contentType.hashCode^content.hashCode^text.hashCode^sendTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChatMessage &&
+                runtimeType == other.runtimeType
+                && clientMsgId == other.clientMsgId&& serverMsgId == other.serverMsgId&& conversationId == other.conversationId&& seq == other.seq&& sendId == other.sendId&& recvId == other.recvId&& groupId == other.groupId&& sessionType == other.sessionType&& contentType == other.contentType&& content == other.content&& text == other.text&& sendTime == other.sendTime;
+        
+            }
+
+/// 一页历史消息
+class MessageHistoryPage  {
+                /// 按 seq 升序（从旧到新）
+final List<ChatMessage> messages;
+/// 是否还有更早的消息，继续加载时以第一条消息的 seq 作为 before_seq
+final bool hasMore;
+
+                const MessageHistoryPage({required this.messages ,required this.hasMore ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => messages.hashCode^hasMore.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageHistoryPage &&
+                runtimeType == other.runtimeType
+                && messages == other.messages&& hasMore == other.hasMore;
+        
+            }
+            
\ No newline at end of file
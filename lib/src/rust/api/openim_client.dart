@@ -0,0 +1,577 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'contacts.dart';
+import 'conversations.dart';
+import 'groups.dart';
+import 'messages.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'reactions.dart';
+import 'sync.dart';
+import 'users.dart';
+part 'openim_client.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `build_msg_data`, `build_url`, `cancel_background_tasks`, `close`, `deliver`, `deserialize_base64`, `enqueue`, `fetch_messages_by_seq`, `flush_outbox`, `flush_presence`, `handle_binary_frame`, `handle_notification`, `handle_online_status`, `handle_push_message`, `handle_send_msg_resp`, `handle_typing_signal`, `is_duplicate_message`, `log_msg_data`, `newest_seqs`, `record_frame`, `run_command`, `run_connection`, `run_gap_filler`, `run_session`, `run_sessions`, `run_sync`, `run_until_closed`, `run_writer`, `send_background_status`, `send_request`, `set_state`, `sleep_until`, `spawn_background`, `sync_messages`, `with_store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartTokenRefresher`, `OpenIMReq`, `OpenIMResp`, `ServerResponse`, `SessionEnd`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `handle`, `load_history`, `pull_messages_by_seq`, `rest_api`, `subscribe_message_status`, `subscribe_messages`, `use_connector`, `use_token_refresher`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `refresh`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<OpenIMClient>>
+                abstract class OpenImClient implements RustOpaqueInterface {
+                    /// 同意好友申请
+ Future<void>  acceptFriendRequest({required String fromUserId , required String handleMsg });
+
+
+/// 同意入群申请（群主或管理员）
+ Future<void>  acceptGroupApplication({required String groupId , required String fromUserId , required String handledMsg });
+
+
+/// 发送好友申请
+ Future<void>  addFriend({required String userId , required String reqMsg });
+
+
+/// 对消息添加表情回应
+ Future<void>  addReaction({required String conversationId , required String clientMsgId , required String emoji });
+
+
+/// 加入黑名单
+ Future<void>  addToBlacklist({required String userId });
+
+
+ String get apiUrl;
+
+
+ int get platformId;
+
+
+ String get userId;
+
+
+ String get wsUrl;
+
+
+  set apiUrl(String apiUrl);
+
+
+  set platformId(int platformId);
+
+
+  set userId(String userId);
+
+
+  set wsUrl(String wsUrl);
+
+
+/// 清空会话中的消息（服务器和本地）
+ Future<void>  clearConversationMessages({required String conversationId });
+
+
+/// 连接并运行客户端，直到主动断开、被踢下线或 token 失效
+///
+/// 连接中断后按 1s、2s、4s…（最长 30s）的间隔重连；token 即将过期或握手时被拒绝会调用刷新回调，
+/// 换成新 token 后重连。发件箱中的消息保留到重连后发送。每次连接成功后自动同步（见 [`crate::openim::sync`]）。
+ Future<void>  connectAndRun();
+
+
+/// 当前连接状态
+ Future<ConnectionStatus>  connectionState();
+
+
+/// 订阅连接状态（先推送当前状态），用于显示“连接中…”等提示
+ Stream<ConnectionStatus>  connectionStateStream();
+
+
+/// 订阅联系人缓存变化
+ Stream<ContactChange>  contactChangeStream();
+
+
+/// 订阅会话变化
+ Stream<ConversationChange>  conversationChangeStream();
+
+
+/// 创建群，自己为群主
+ Future<Group>  createGroup({required String groupName , required List<String> memberUserIds });
+
+
+/// 删除会话及其消息
+ Future<void>  deleteConversation({required String conversationId });
+
+
+/// 删除好友
+ Future<void>  deleteFriend({required String userId });
+
+
+/// 断开连接：发送 close 帧，取消后台同步任务，等待响应的请求以取消错误结束，`connect_and_run` 随后返回
+///
+/// 发件箱中未确认的消息保留在数据库中，下次连接时重新发送。
+ Future<void>  disconnect();
+
+
+/// 解散群（仅群主）
+ Future<void>  dismissGroup({required String groupId });
+
+
+/// 黑名单（本地缓存）
+ Future<List<BlockedUser>>  getBlacklist();
+
+
+/// 会话列表（本地），置顶在前，其余按最新消息或草稿时间倒序
+ Future<List<ConversationInfo>>  getConversationList();
+
+
+/// 好友申请（本地缓存）
+ Future<List<FriendRequest>>  getFriendRequests();
+
+
+/// 好友列表（本地缓存）
+ Future<List<Friend>>  getFriends();
+
+
+/// 群资料（本地缓存）
+ Future<Group?>  getGroup({required String groupId });
+
+
+/// 分页获取群成员（本地缓存，群主、管理员在前）
+ Future<List<GroupMember>>  getGroupMembers({required String groupId , required int offset , required int limit });
+
+
+/// 加载会话的历史消息：before_seq 之前（不含）的最多 limit 条，before_seq 为 0 时从最新的消息开始
+///
+/// 优先读取本地，缺失的消息自动从服务器拉取；离线或拉取失败时只返回本地已有的消息。
+ Future<MessageHistoryPage>  getHistory({required String conversationId , required PlatformInt64 beforeSeq , required int limit });
+
+
+/// 已加入的群（本地缓存）
+ Future<List<Group>>  getJoinedGroups();
+
+
+/// 消息的表情回应（按每个表情最早的回应时间排序）
+ Future<List<MessageReaction>>  getMessageReactions({required String clientMsgId });
+
+
+/// 获取自己的资料
+ Future<UserProfile>  getSelfInfo();
+
+
+/// 已订阅用户的当前在线状态
+ Future<UserStatus>  getUserStatus({required String userId });
+
+
+/// 批量获取用户资料（优先使用缓存，force_refresh 为 true 时从服务器获取）
+ Future<List<UserProfile>>  getUsersInfo({required List<String> userIds , required bool forceRefresh });
+
+
+/// 订阅群缓存变化
+ Stream<GroupChange>  groupChangeStream();
+
+
+/// 从会话列表中隐藏（只影响本地，收到新消息后重新显示）
+ Future<void>  hideConversation({required String conversationId });
+
+
+/// 邀请入群
+ Future<void>  inviteToGroup({required String groupId , required List<String> userIds , required String reason });
+
+
+/// 申请入群（通过搜索），不需要验证的群直接加入
+ Future<void>  joinGroup({required String groupId , required String reqMsg });
+
+
+/// 踢出群
+ Future<void>  kickGroupMembers({required String groupId , required List<String> userIds , required String reason });
+
+
+/// 退出登录：通知服务器后断开连接并关闭本地数据库，之后客户端不可再使用
+///
+/// 保存的登录 token 由 auth 模块的 `logout` 清除。
+ Future<void>  logout();
+
+
+/// 订阅消息发送状态变化
+ Stream<MessageStatusEvent>  messageStatusStream();
+
+
+/// 全员禁言 / 解除全员禁言
+ Future<void>  muteGroup({required String groupId , required bool muted });
+
+
+/// 禁言群成员，muted_seconds 为 0 时解除禁言
+ Future<void>  muteGroupMember({required String groupId , required String userId , required int mutedSeconds });
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// 创建客户端（数据保存在内存中，进程退出后丢失）
+static Future<OpenImClient>  newInstance({required String userId , required String token , required int platformId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientNew(userId: userId, token: token, platformId: platformId);
+
+
+/// 创建客户端，发件箱等数据持久化到 `{data_dir}/openim.db`
+static Future<OpenImClient>  open({required String userId , required String token , required int platformId , required String dataDir })=>RustLib.instance.api.crateApiOpenimClientOpenImClientOpen(userId: userId, token: token, platformId: platformId, dataDir: dataDir);
+
+
+/// 退出群
+ Future<void>  quitGroup({required String groupId });
+
+
+/// 订阅表情回应变化
+ Stream<ReactionChange>  reactionChangeStream();
+
+
+/// 拒绝好友申请
+ Future<void>  rejectFriendRequest({required String fromUserId , required String handleMsg });
+
+
+/// 拒绝入群申请（群主或管理员）
+ Future<void>  rejectGroupApplication({required String groupId , required String fromUserId , required String handledMsg });
+
+
+/// 移出黑名单
+ Future<void>  removeFromBlacklist({required String userId });
+
+
+/// 取消自己对消息的表情回应
+ Future<void>  removeReaction({required String conversationId , required String clientMsgId , required String emoji });
+
+
+/// 重新发送已失败的消息（对方收到的顺序在失败期间发出的消息之后）
+ Future<void>  retryMessage({required PlatformInt64 outboxId });
+
+
+/// 搜索本地消息（按发送时间倒序分页，摘要中标出命中位置）
+ Future<MessageSearchPage>  searchMessages({required MessageSearchQuery query });
+
+
+/// 发送群文本消息，返回发件箱 ID
+ Future<PlatformInt64>  sendGroupTextMessage({required String groupId , required String text });
+
+
+/// 发送文本消息：先写入发件箱，连接可用时按顺序发送，返回发件箱 ID
+ Future<PlatformInt64>  sendTextMessage({required String recvId , required String text });
+
+
+/// 在会话中发送正在输入 / 停止输入状态（单聊或群聊），按会话限流，不保证送达
+ Future<void>  sendTyping({required String conversationId , required bool typing });
+
+
+/// 切换前后台状态，进入后台后服务器改为离线推送
+ Future<void>  setAppBackground({required bool background });
+
+
+/// 置顶 / 取消置顶
+ Future<void>  setConversationPinned({required String conversationId , required bool pinned });
+
+
+/// 保存草稿（只保存在本地，空字符串表示清除）
+ Future<void>  setDraft({required String conversationId , required String draft });
+
+
+/// 设置好友备注（空字符串表示清除）
+ Future<void>  setFriendRemark({required String userId , required String remark });
+
+
+/// 设置或取消管理员
+ Future<void>  setGroupAdmin({required String groupId , required String userId , required bool admin });
+
+
+/// 设置消息接收选项（免打扰）
+ Future<void>  setRecvMsgOpt({required String conversationId , required RecvMsgOpt opt });
+
+
+/// 修改自己的资料，None 表示不修改
+ Future<UserProfile>  setSelfInfo({String? nickname , String? faceUrl , String? ex });
+
+
+/// 连接（包括重连）后是否自动同步资料、联系人、群、会话和缺失的消息，默认开启
+ Future<void>  setSyncOnConnect({required bool enabled });
+
+
+/// 设置 token 刷新回调（返回新的 token），token 即将过期或被服务器拒绝时调用，之后自动重连
+ Future<void>  setTokenRefresher({required FutureOr<String> Function() callback });
+
+
+/// 开始录制协议帧到抓包文件（已存在时追加），用于问题反馈
+ Future<void>  startFrameCapture({required String path });
+
+
+/// 停止录制
+ Future<void>  stopFrameCapture();
+
+
+/// 订阅用户在线状态（断线重连后自动重新订阅）
+ Future<void>  subscribeUsersStatus({required List<String> userIds });
+
+
+/// 从服务器同步好友、黑名单和好友申请到本地缓存
+ Future<void>  syncContacts();
+
+
+/// 从服务器同步置顶、免打扰等会话设置
+ Future<void>  syncConversations();
+
+
+/// 从服务器同步群成员
+ Future<void>  syncGroupMembers({required String groupId });
+
+
+/// 从服务器同步已加入的群
+ Future<void>  syncJoinedGroups();
+
+
+/// 订阅同步进度（先推送最近一次的状态，尚未同步过时不推送）
+ Stream<SyncStatus>  syncStatusStream();
+
+
+/// 当前使用的 token
+ Future<String>  token();
+
+
+/// 订阅 token 状态变化
+ Stream<TokenStatus>  tokenStatusStream();
+
+
+/// 转让群主
+ Future<void>  transferGroupOwner({required String groupId , required String newOwnerUserId });
+
+
+/// 订阅某个会话中对方的正在输入状态（超时自动变为停止输入）
+ Stream<TypingStatus>  typingStream({required String conversationId });
+
+
+/// 取消订阅用户在线状态
+ Future<void>  unsubscribeUsersStatus({required List<String> userIds });
+
+
+/// 订阅在线状态变化
+ Stream<UserStatus>  userStatusStream();
+
+
+
+                    
+                }
+                
+
+@freezed
+                sealed class ConnectionStatus with _$ConnectionStatus  {
+                    const ConnectionStatus._();
+
+                     const factory ConnectionStatus.idle() = ConnectionStatus_Idle;
+ const factory ConnectionStatus.connecting() = ConnectionStatus_Connecting;
+ const factory ConnectionStatus.handshaking() = ConnectionStatus_Handshaking;
+ const factory ConnectionStatus.connected() = ConnectionStatus_Connected;
+ /// 正在同步离线消息
+const factory ConnectionStatus.syncing() = ConnectionStatus_Syncing;
+ /// 连接断开，backoff_ms 后第 attempt 次重连
+const factory ConnectionStatus.reconnecting({   required int attempt ,  required BigInt backoffMs , }) = ConnectionStatus_Reconnecting;
+ /// 被踢下线，需要用户确认后重新连接
+const factory ConnectionStatus.kicked() = ConnectionStatus_Kicked;
+ /// token 失效，需要重新登录
+const factory ConnectionStatus.tokenExpired() = ConnectionStatus_TokenExpired;
+ const factory ConnectionStatus.closed() = ConnectionStatus_Closed;
+
+                    
+
+                    
+                }
+
+/// 消息搜索结果
+class MessageSearchHit  {
+                final String clientMsgId;
+final String conversationId;
+final String sendId;
+final int contentType;
+final PlatformInt64 sendTime;
+final PlatformInt64 seq;
+final String snippet;
+final List<SnippetHighlight> highlights;
+
+                const MessageSearchHit({required this.clientMsgId ,required this.conversationId ,required this.sendId ,required this.contentType ,required this.sendTime ,required this.seq ,required this.snippet ,required this.highlights ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => clientMsgId.hashCode^conversationId.hashCode^sendId.hashCode^contentType.hashCode^sendTime.hashCode^seq.hashCode^snippet.hashCode^highlights.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageSearchHit &&
+                runtimeType == other.runtimeType
+                && clientMsgId == other.clientMsgId&& conversationId == other.conversationId&& sendId == other.sendId&& contentType == other.contentType&& sendTime == other.sendTime&& seq == other.seq&& snippet == other.snippet&& highlights == other.highlights;
+        
+            }
+
+/// 一页搜索结果
+class MessageSearchPage  {
+                final List<MessageSearchHit> hits;
+final bool hasMore;
+
+                const MessageSearchPage({required this.hits ,required this.hasMore ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^hasMore.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageSearchPage &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& hasMore == other.hasMore;
+        
+            }
+
+/// 消息搜索条件（关键词为空时只按其余条件过滤）
+class MessageSearchQuery  {
+                final String keyword;
+final String? conversationId;
+final String? senderId;
+/// 为空表示不限类型
+final Int32List contentTypes;
+final PlatformInt64? startTime;
+final PlatformInt64? endTime;
+final int offset;
+final int limit;
+
+                const MessageSearchQuery({required this.keyword ,this.conversationId ,this.senderId ,required this.contentTypes ,this.startTime ,this.endTime ,required this.offset ,required this.limit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => keyword.hashCode^conversationId.hashCode^senderId.hashCode^contentTypes.hashCode^startTime.hashCode^endTime.hashCode^offset.hashCode^limit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageSearchQuery &&
+                runtimeType == other.runtimeType
+                && keyword == other.keyword&& conversationId == other.conversationId&& senderId == other.senderId&& contentTypes == other.contentTypes&& startTime == other.startTime&& endTime == other.endTime&& offset == other.offset&& limit == other.limit;
+        
+            }
+
+/// 消息发送状态
+enum MessageSendStatus {
+                    sending,
+sent,
+failed,
+                    ;
+                    
+                }
+
+/// 消息发送状态变化（推送给 Dart）
+class MessageStatusEvent  {
+                /// 发件箱 ID（`send_text_message` 的返回值）
+final PlatformInt64 outboxId;
+final String clientMsgId;
+final String conversationId;
+final MessageSendStatus status;
+final PlatformInt64 attempts;
+final String serverMsgId;
+final PlatformInt64 sendTime;
+final String error;
+
+                const MessageStatusEvent({required this.outboxId ,required this.clientMsgId ,required this.conversationId ,required this.status ,required this.attempts ,required this.serverMsgId ,required this.sendTime ,required this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outboxId.hashCode^clientMsgId.hashCode^conversationId.hashCode^status.hashCode^attempts.hashCode^serverMsgId.hashCode^sendTime.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageStatusEvent &&
+                runtimeType == other.runtimeType
+                && outboxId == other.outboxId&& clientMsgId == other.clientMsgId&& conversationId == other.conversationId&& status == other.status&& attempts == other.attempts&& serverMsgId == other.serverMsgId&& sendTime == other.sendTime&& error == other.error;
+        
+            }
+
+/// 摘要中的命中区间（UTF-16 偏移）
+class SnippetHighlight  {
+                final int start;
+final int end;
+
+                const SnippetHighlight({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnippetHighlight &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+@freezed
+                sealed class TokenStatus with _$TokenStatus  {
+                    const TokenStatus._();
+
+                     /// 即将过期，未设置刷新回调时需要重新登录
+const factory TokenStatus.willExpire({   required PlatformInt64 expiresAt , }) = TokenStatus_WillExpire;
+ const factory TokenStatus.refreshed({   required PlatformInt64 expiresAt , }) = TokenStatus_Refreshed;
+ const factory TokenStatus.refreshFailed({   required String error , }) = TokenStatus_RefreshFailed;
+
+                    
+
+                    
+                }
+
+/// 正在输入状态（推送给 Dart）
+class TypingStatus  {
+                final String conversationId;
+final String userId;
+final bool typing;
+
+                const TypingStatus({required this.conversationId ,required this.userId ,required this.typing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => conversationId.hashCode^userId.hashCode^typing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TypingStatus &&
+                runtimeType == other.runtimeType
+                && conversationId == other.conversationId&& userId == other.userId&& typing == other.typing;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// 解码图片，生成大图和缩略图，并清除 EXIF GPS 信息
+Future<PreparedPicture>  preparePicture({required List<int> data , required String sourcePath }) => RustLib.instance.api.crateApiPicturePreparePicture(data: data, sourcePath: sourcePath);
+
+            /// 预处理后的图片消息（上传三个尺寸后将 url 写回 PictureElem 再发送）
+class PreparedPicture  {
+                final Uint8List source;
+final Uint8List big;
+final Uint8List snapshot;
+/// PictureElem JSON（url 为空）
+final String pictureElemJson;
+
+                const PreparedPicture({required this.source ,required this.big ,required this.snapshot ,required this.pictureElemJson ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => source.hashCode^big.hashCode^snapshot.hashCode^pictureElemJson.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreparedPicture &&
+                runtimeType == other.runtimeType
+                && source == other.source&& big == other.big&& snapshot == other.snapshot&& pictureElemJson == other.pictureElemJson;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`
+
+
+            
+
+            /// 消息上一个表情的回应
+class MessageReaction  {
+                final String emoji;
+final int count;
+/// 回应过的用户（按回应时间）
+final List<String> userIds;
+/// 自己是否回应过
+final bool reactedByMe;
+
+                const MessageReaction({required this.emoji ,required this.count ,required this.userIds ,required this.reactedByMe ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => emoji.hashCode^count.hashCode^userIds.hashCode^reactedByMe.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MessageReaction &&
+                runtimeType == other.runtimeType
+                && emoji == other.emoji&& count == other.count&& userIds == other.userIds&& reactedByMe == other.reactedByMe;
+        
+            }
+
+/// 消息的表情回应变化（reactions 为变化后的全部回应）
+class ReactionChange  {
+                final String conversationId;
+final String clientMsgId;
+final List<MessageReaction> reactions;
+
+                const ReactionChange({required this.conversationId ,required this.clientMsgId ,required this.reactions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => conversationId.hashCode^clientMsgId.hashCode^reactions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReactionChange &&
+                runtimeType == other.runtimeType
+                && conversationId == other.conversationId&& clientMsgId == other.clientMsgId&& reactions == other.reactions;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'openim_client.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'sessions.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `registry`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Session`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `from`
+
+
+            /// 打开会话并返回会话 ID，每个会话的数据保存在 `{root_dir}/{session_id}` 下
+///
+/// root_dir 为 None 时数据保存在内存中。第一个打开的会话自动成为当前会话。
+Future<String>  openSession({required String userId , required String token , required int platformId , String? rootDir }) => RustLib.instance.api.crateApiSessionsOpenSession(userId: userId, token: token, platformId: platformId, rootDir: rootDir);
+
+/// 按会话 ID 获取客户端
+Future<OpenImClient>  getSession({required String sessionId }) => RustLib.instance.api.crateApiSessionsGetSession(sessionId: sessionId);
+
+/// 当前会话的客户端
+Future<OpenImClient?>  activeSession() => RustLib.instance.api.crateApiSessionsActiveSession();
+
+/// 切换当前会话
+Future<void>  setActiveSession({required String sessionId }) => RustLib.instance.api.crateApiSessionsSetActiveSession(sessionId: sessionId);
+
+/// 已打开的全部会话
+Future<List<SessionInfo>>  listSessions() => RustLib.instance.api.crateApiSessionsListSessions();
+
+/// 关闭会话：断开连接、关闭本地数据库后从会话表中移除，关闭的是当前会话时切换到其他会话
+///
+/// 只关闭本地的会话，不通知服务器退出登录（退出登录先调用客户端的 `logout`）。
+Future<void>  closeSession({required String sessionId }) => RustLib.instance.api.crateApiSessionsCloseSession(sessionId: sessionId);
+
+/// 订阅会话的打开、关闭和切换
+Stream<SessionChange>  sessionChangeStream() => RustLib.instance.api.crateApiSessionsSessionChangeStream();
+
+            @freezed
+                sealed class SessionChange with _$SessionChange  {
+                    const SessionChange._();
+
+                     const factory SessionChange.opened({   required String sessionId , }) = SessionChange_Opened;
+ const factory SessionChange.closed({   required String sessionId , }) = SessionChange_Closed;
+ /// 当前会话切换，None 表示已没有会话
+const factory SessionChange.activated({   String? sessionId , }) = SessionChange_Activated;
+
+                    
+
+                    
+                }
+
+/// 已打开的会话
+class SessionInfo  {
+                final String sessionId;
+final String userId;
+final int platformId;
+/// 数据目录，None 表示数据保存在内存中
+final String? dataDir;
+final bool isActive;
+
+                const SessionInfo({required this.sessionId ,required this.userId ,required this.platformId ,this.dataDir ,required this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sessionId.hashCode^userId.hashCode^platformId.hashCode^dataDir.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SessionInfo &&
+                runtimeType == other.runtimeType
+                && sessionId == other.sessionId&& userId == other.userId&& platformId == other.platformId&& dataDir == other.dataDir&& isActive == other.isActive;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'sync.freezed.dart';
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`
+
+
+            
+
+            /// 同步阶段
+enum SyncStage {
+                    selfInfo,
+/// 好友、黑名单和好友申请
+friends,
+groups,
+conversations,
+/// 补齐离线期间的消息
+messages,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class SyncStatus with _$SyncStatus  {
+                    const SyncStatus._();
+
+                     /// 正在同步，percent 为整体进度（0-100）
+const factory SyncStatus.syncing({   required SyncStage stage ,  required int percent , }) = SyncStatus_Syncing;
+ /// 同步结束，failed 为失败的阶段（下次连接时重试）
+const factory SyncStatus.done({   required List<SyncStage> failed , }) = SyncStatus_Done;
+ /// 连接断开，重连后重新同步
+const factory SyncStatus.interrupted() = SyncStatus_Interrupted;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `from`, `from`
+
+
+            
+
+            /// 用户资料
+class UserProfile  {
+                final String userId;
+final String nickname;
+final String faceUrl;
+final String ex;
+final PlatformInt64 createTime;
+
+                const UserProfile({required this.userId ,required this.nickname ,required this.faceUrl ,required this.ex ,required this.createTime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^nickname.hashCode^faceUrl.hashCode^ex.hashCode^createTime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UserProfile &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& nickname == other.nickname&& faceUrl == other.faceUrl&& ex == other.ex&& createTime == other.createTime;
+        
+            }
+
+/// 用户在线状态
+class UserStatus  {
+                final String userId;
+final bool online;
+/// 在线的平台（1 iOS，2 Android，3 Windows，4 macOS，5 Web ...）
+final Int32List platformIds;
+
+                const UserStatus({required this.userId ,required this.online ,required this.platformIds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^online.hashCode^platformIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UserStatus &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& online == other.online&& platformIds == other.platformIds;
+        
+            }
+            
\ No newline at end of file
@@ -3,240 +3,5061 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/auth.dart';
+import 'api/capture.dart';
+import 'api/contacts.dart';
+import 'api/conversations.dart';
+import 'api/groups.dart';
+import 'api/logging.dart';
+import 'api/messages.dart';
+import 'api/openim_client.dart';
+import 'api/picture.dart';
+import 'api/reactions.dart';
+import 'api/sessions.dart';
 import 'api/simple.dart';
+import 'api/sync.dart';
+import 'api/users.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -1918914929;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_flutter_rust_demo',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateApiSimpleGreet({required String name});
-
-  Future<void> crateApiSimpleInitApp();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => 259498517;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_flutter_rust_demo',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiOpenimClientOpenImClientAcceptFriendRequest({required OpenImClient that , required String fromUserId , required String handleMsg });
+
+Future<void> crateApiOpenimClientOpenImClientAcceptGroupApplication({required OpenImClient that , required String groupId , required String fromUserId , required String handledMsg });
+
+Future<void> crateApiOpenimClientOpenImClientAddFriend({required OpenImClient that , required String userId , required String reqMsg });
+
+Future<void> crateApiOpenimClientOpenImClientAddReaction({required OpenImClient that , required String conversationId , required String clientMsgId , required String emoji });
+
+Future<void> crateApiOpenimClientOpenImClientAddToBlacklist({required OpenImClient that , required String userId });
+
+String crateApiOpenimClientOpenImClientAutoAccessorGetApiUrl({required OpenImClient that });
+
+int crateApiOpenimClientOpenImClientAutoAccessorGetPlatformId({required OpenImClient that });
+
+String crateApiOpenimClientOpenImClientAutoAccessorGetUserId({required OpenImClient that });
+
+String crateApiOpenimClientOpenImClientAutoAccessorGetWsUrl({required OpenImClient that });
+
+void crateApiOpenimClientOpenImClientAutoAccessorSetApiUrl({required OpenImClient that , required String apiUrl });
+
+void crateApiOpenimClientOpenImClientAutoAccessorSetPlatformId({required OpenImClient that , required int platformId });
+
+void crateApiOpenimClientOpenImClientAutoAccessorSetUserId({required OpenImClient that , required String userId });
+
+void crateApiOpenimClientOpenImClientAutoAccessorSetWsUrl({required OpenImClient that , required String wsUrl });
+
+Future<void> crateApiOpenimClientOpenImClientClearConversationMessages({required OpenImClient that , required String conversationId });
+
+Future<void> crateApiOpenimClientOpenImClientConnectAndRun({required OpenImClient that });
+
+Future<ConnectionStatus> crateApiOpenimClientOpenImClientConnectionState({required OpenImClient that });
+
+Stream<ConnectionStatus> crateApiOpenimClientOpenImClientConnectionStateStream({required OpenImClient that });
+
+Stream<ContactChange> crateApiOpenimClientOpenImClientContactChangeStream({required OpenImClient that });
+
+Stream<ConversationChange> crateApiOpenimClientOpenImClientConversationChangeStream({required OpenImClient that });
+
+Future<Group> crateApiOpenimClientOpenImClientCreateGroup({required OpenImClient that , required String groupName , required List<String> memberUserIds });
+
+Future<void> crateApiOpenimClientOpenImClientDeleteConversation({required OpenImClient that , required String conversationId });
+
+Future<void> crateApiOpenimClientOpenImClientDeleteFriend({required OpenImClient that , required String userId });
+
+Future<void> crateApiOpenimClientOpenImClientDisconnect({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientDismissGroup({required OpenImClient that , required String groupId });
+
+Future<List<BlockedUser>> crateApiOpenimClientOpenImClientGetBlacklist({required OpenImClient that });
+
+Future<List<ConversationInfo>> crateApiOpenimClientOpenImClientGetConversationList({required OpenImClient that });
+
+Future<List<FriendRequest>> crateApiOpenimClientOpenImClientGetFriendRequests({required OpenImClient that });
+
+Future<List<Friend>> crateApiOpenimClientOpenImClientGetFriends({required OpenImClient that });
+
+Future<Group?> crateApiOpenimClientOpenImClientGetGroup({required OpenImClient that , required String groupId });
+
+Future<List<GroupMember>> crateApiOpenimClientOpenImClientGetGroupMembers({required OpenImClient that , required String groupId , required int offset , required int limit });
+
+Future<MessageHistoryPage> crateApiOpenimClientOpenImClientGetHistory({required OpenImClient that , required String conversationId , required PlatformInt64 beforeSeq , required int limit });
+
+Future<List<Group>> crateApiOpenimClientOpenImClientGetJoinedGroups({required OpenImClient that });
+
+Future<List<MessageReaction>> crateApiOpenimClientOpenImClientGetMessageReactions({required OpenImClient that , required String clientMsgId });
+
+Future<UserProfile> crateApiOpenimClientOpenImClientGetSelfInfo({required OpenImClient that });
+
+Future<UserStatus> crateApiOpenimClientOpenImClientGetUserStatus({required OpenImClient that , required String userId });
+
+Future<List<UserProfile>> crateApiOpenimClientOpenImClientGetUsersInfo({required OpenImClient that , required List<String> userIds , required bool forceRefresh });
+
+Stream<GroupChange> crateApiOpenimClientOpenImClientGroupChangeStream({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientHideConversation({required OpenImClient that , required String conversationId });
+
+Future<void> crateApiOpenimClientOpenImClientInviteToGroup({required OpenImClient that , required String groupId , required List<String> userIds , required String reason });
+
+Future<void> crateApiOpenimClientOpenImClientJoinGroup({required OpenImClient that , required String groupId , required String reqMsg });
+
+Future<void> crateApiOpenimClientOpenImClientKickGroupMembers({required OpenImClient that , required String groupId , required List<String> userIds , required String reason });
+
+Future<void> crateApiOpenimClientOpenImClientLogout({required OpenImClient that });
+
+Stream<MessageStatusEvent> crateApiOpenimClientOpenImClientMessageStatusStream({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientMuteGroup({required OpenImClient that , required String groupId , required bool muted });
+
+Future<void> crateApiOpenimClientOpenImClientMuteGroupMember({required OpenImClient that , required String groupId , required String userId , required int mutedSeconds });
+
+Future<OpenImClient> crateApiOpenimClientOpenImClientNew({required String userId , required String token , required int platformId });
+
+Future<OpenImClient> crateApiOpenimClientOpenImClientOpen({required String userId , required String token , required int platformId , required String dataDir });
+
+Future<void> crateApiOpenimClientOpenImClientQuitGroup({required OpenImClient that , required String groupId });
+
+Stream<ReactionChange> crateApiOpenimClientOpenImClientReactionChangeStream({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientRejectFriendRequest({required OpenImClient that , required String fromUserId , required String handleMsg });
+
+Future<void> crateApiOpenimClientOpenImClientRejectGroupApplication({required OpenImClient that , required String groupId , required String fromUserId , required String handledMsg });
+
+Future<void> crateApiOpenimClientOpenImClientRemoveFromBlacklist({required OpenImClient that , required String userId });
+
+Future<void> crateApiOpenimClientOpenImClientRemoveReaction({required OpenImClient that , required String conversationId , required String clientMsgId , required String emoji });
+
+Future<void> crateApiOpenimClientOpenImClientRetryMessage({required OpenImClient that , required PlatformInt64 outboxId });
+
+Future<MessageSearchPage> crateApiOpenimClientOpenImClientSearchMessages({required OpenImClient that , required MessageSearchQuery query });
+
+Future<PlatformInt64> crateApiOpenimClientOpenImClientSendGroupTextMessage({required OpenImClient that , required String groupId , required String text });
+
+Future<PlatformInt64> crateApiOpenimClientOpenImClientSendTextMessage({required OpenImClient that , required String recvId , required String text });
+
+Future<void> crateApiOpenimClientOpenImClientSendTyping({required OpenImClient that , required String conversationId , required bool typing });
+
+Future<void> crateApiOpenimClientOpenImClientSetAppBackground({required OpenImClient that , required bool background });
+
+Future<void> crateApiOpenimClientOpenImClientSetConversationPinned({required OpenImClient that , required String conversationId , required bool pinned });
+
+Future<void> crateApiOpenimClientOpenImClientSetDraft({required OpenImClient that , required String conversationId , required String draft });
+
+Future<void> crateApiOpenimClientOpenImClientSetFriendRemark({required OpenImClient that , required String userId , required String remark });
+
+Future<void> crateApiOpenimClientOpenImClientSetGroupAdmin({required OpenImClient that , required String groupId , required String userId , required bool admin });
+
+Future<void> crateApiOpenimClientOpenImClientSetRecvMsgOpt({required OpenImClient that , required String conversationId , required RecvMsgOpt opt });
+
+Future<UserProfile> crateApiOpenimClientOpenImClientSetSelfInfo({required OpenImClient that , String? nickname , String? faceUrl , String? ex });
+
+Future<void> crateApiOpenimClientOpenImClientSetSyncOnConnect({required OpenImClient that , required bool enabled });
+
+Future<void> crateApiOpenimClientOpenImClientSetTokenRefresher({required OpenImClient that , required FutureOr<String> Function() callback });
+
+Future<void> crateApiOpenimClientOpenImClientStartFrameCapture({required OpenImClient that , required String path });
+
+Future<void> crateApiOpenimClientOpenImClientStopFrameCapture({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientSubscribeUsersStatus({required OpenImClient that , required List<String> userIds });
+
+Future<void> crateApiOpenimClientOpenImClientSyncContacts({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientSyncConversations({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientSyncGroupMembers({required OpenImClient that , required String groupId });
+
+Future<void> crateApiOpenimClientOpenImClientSyncJoinedGroups({required OpenImClient that });
+
+Stream<SyncStatus> crateApiOpenimClientOpenImClientSyncStatusStream({required OpenImClient that });
+
+Future<String> crateApiOpenimClientOpenImClientToken({required OpenImClient that });
+
+Stream<TokenStatus> crateApiOpenimClientOpenImClientTokenStatusStream({required OpenImClient that });
+
+Future<void> crateApiOpenimClientOpenImClientTransferGroupOwner({required OpenImClient that , required String groupId , required String newOwnerUserId });
+
+Stream<TypingStatus> crateApiOpenimClientOpenImClientTypingStream({required OpenImClient that , required String conversationId });
+
+Future<void> crateApiOpenimClientOpenImClientUnsubscribeUsersStatus({required OpenImClient that , required List<String> userIds });
+
+Stream<UserStatus> crateApiOpenimClientOpenImClientUserStatusStream({required OpenImClient that });
+
+Future<OpenImClient?> crateApiSessionsActiveSession();
+
+Future<void> crateApiSessionsCloseSession({required String sessionId });
+
+Future<List<String>> crateApiLoggingGetLogFiles({required String logDir });
+
+Future<OpenImClient> crateApiSessionsGetSession({required String sessionId });
+
+String crateApiSimpleGreet({required String name });
+
+Future<void> crateApiSimpleInitApp();
+
+Future<void> crateApiLoggingInitLogging({required LogLevel level , String? logDir , required int maxFiles });
+
+Future<List<SessionInfo>> crateApiSessionsListSessions();
+
+Stream<LogEntry> crateApiLoggingLogStream();
+
+Future<LoginSession> crateApiAuthLogin({required LoginCredentials credentials , required int platformId });
+
+Future<void> crateApiAuthLogout();
+
+Future<String> crateApiSessionsOpenSession({required String userId , required String token , required int platformId , String? rootDir });
+
+Future<PreparedPicture> crateApiPicturePreparePicture({required List<int> data , required String sourcePath });
+
+Future<ReplayReport> crateApiCaptureReplayCapture({required String userId , required String path });
+
+Future<LoginSession?> crateApiAuthRestoreSession();
+
+Stream<SessionChange> crateApiSessionsSessionChangeStream();
+
+Future<void> crateApiSessionsSetActiveSession({required String sessionId });
+
+Future<void> crateApiAuthSetTokenStorage({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) delete });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OpenImClient;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OpenImClient;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OpenImClientPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiOpenimClientOpenImClientAcceptFriendRequest({required OpenImClient that , required String fromUserId , required String handleMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(fromUserId, serializer);
+sse_encode_String(handleMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAcceptFriendRequestConstMeta,
+            argValues: [that, fromUserId, handleMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAcceptFriendRequestConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_accept_friend_request",
+            argNames: ["that", "fromUserId", "handleMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientAcceptGroupApplication({required OpenImClient that , required String groupId , required String fromUserId , required String handledMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(fromUserId, serializer);
+sse_encode_String(handledMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAcceptGroupApplicationConstMeta,
+            argValues: [that, groupId, fromUserId, handledMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAcceptGroupApplicationConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_accept_group_application",
+            argNames: ["that", "groupId", "fromUserId", "handledMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientAddFriend({required OpenImClient that , required String userId , required String reqMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_String(reqMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAddFriendConstMeta,
+            argValues: [that, userId, reqMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAddFriendConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_add_friend",
+            argNames: ["that", "userId", "reqMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientAddReaction({required OpenImClient that , required String conversationId , required String clientMsgId , required String emoji })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_String(clientMsgId, serializer);
+sse_encode_String(emoji, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAddReactionConstMeta,
+            argValues: [that, conversationId, clientMsgId, emoji],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAddReactionConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_add_reaction",
+            argNames: ["that", "conversationId", "clientMsgId", "emoji"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientAddToBlacklist({required OpenImClient that , required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAddToBlacklistConstMeta,
+            argValues: [that, userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAddToBlacklistConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_add_to_blacklist",
+            argNames: ["that", "userId"],
+        );
+        
+
+@override String crateApiOpenimClientOpenImClientAutoAccessorGetApiUrl({required OpenImClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorGetApiUrlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorGetApiUrlConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_get_api_url",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiOpenimClientOpenImClientAutoAccessorGetPlatformId({required OpenImClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorGetPlatformIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorGetPlatformIdConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_get_platform_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiOpenimClientOpenImClientAutoAccessorGetUserId({required OpenImClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorGetUserIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorGetUserIdConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_get_user_id",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiOpenimClientOpenImClientAutoAccessorGetWsUrl({required OpenImClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorGetWsUrlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorGetWsUrlConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_get_ws_url",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiOpenimClientOpenImClientAutoAccessorSetApiUrl({required OpenImClient that , required String apiUrl })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(apiUrl, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorSetApiUrlConstMeta,
+            argValues: [that, apiUrl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorSetApiUrlConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_set_api_url",
+            argNames: ["that", "apiUrl"],
+        );
+        
+
+@override void crateApiOpenimClientOpenImClientAutoAccessorSetPlatformId({required OpenImClient that , required int platformId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_i_32(platformId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorSetPlatformIdConstMeta,
+            argValues: [that, platformId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorSetPlatformIdConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_set_platform_id",
+            argNames: ["that", "platformId"],
+        );
+        
+
+@override void crateApiOpenimClientOpenImClientAutoAccessorSetUserId({required OpenImClient that , required String userId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorSetUserIdConstMeta,
+            argValues: [that, userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorSetUserIdConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_set_user_id",
+            argNames: ["that", "userId"],
+        );
+        
+
+@override void crateApiOpenimClientOpenImClientAutoAccessorSetWsUrl({required OpenImClient that , required String wsUrl })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(wsUrl, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientAutoAccessorSetWsUrlConstMeta,
+            argValues: [that, wsUrl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientAutoAccessorSetWsUrlConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_auto_accessor_set_ws_url",
+            argNames: ["that", "wsUrl"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientClearConversationMessages({required OpenImClient that , required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientClearConversationMessagesConstMeta,
+            argValues: [that, conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientClearConversationMessagesConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_clear_conversation_messages",
+            argNames: ["that", "conversationId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientConnectAndRun({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientConnectAndRunConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientConnectAndRunConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_connect_and_run",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ConnectionStatus> crateApiOpenimClientOpenImClientConnectionState({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientConnectionStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientConnectionStateConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_connection_state",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<ConnectionStatus> crateApiOpenimClientOpenImClientConnectionStateStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<ConnectionStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_connection_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientConnectionStateStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientConnectionStateStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_connection_state_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Stream<ContactChange> crateApiOpenimClientOpenImClientContactChangeStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<ContactChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_contact_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientContactChangeStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientContactChangeStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_contact_change_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Stream<ConversationChange> crateApiOpenimClientOpenImClientConversationChangeStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<ConversationChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_conversation_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientConversationChangeStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientConversationChangeStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_conversation_change_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<Group> crateApiOpenimClientOpenImClientCreateGroup({required OpenImClient that , required String groupName , required List<String> memberUserIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupName, serializer);
+sse_encode_list_String(memberUserIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_group,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientCreateGroupConstMeta,
+            argValues: [that, groupName, memberUserIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientCreateGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_create_group",
+            argNames: ["that", "groupName", "memberUserIds"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientDeleteConversation({required OpenImClient that , required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientDeleteConversationConstMeta,
+            argValues: [that, conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientDeleteConversationConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_delete_conversation",
+            argNames: ["that", "conversationId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientDeleteFriend({required OpenImClient that , required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientDeleteFriendConstMeta,
+            argValues: [that, userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientDeleteFriendConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_delete_friend",
+            argNames: ["that", "userId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientDisconnect({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientDisconnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientDisconnectConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_disconnect",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientDismissGroup({required OpenImClient that , required String groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientDismissGroupConstMeta,
+            argValues: [that, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientDismissGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_dismiss_group",
+            argNames: ["that", "groupId"],
+        );
+        
+
+@override Future<List<BlockedUser>> crateApiOpenimClientOpenImClientGetBlacklist({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_blocked_user,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetBlacklistConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetBlacklistConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_blacklist",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<ConversationInfo>> crateApiOpenimClientOpenImClientGetConversationList({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_conversation_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetConversationListConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetConversationListConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_conversation_list",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<FriendRequest>> crateApiOpenimClientOpenImClientGetFriendRequests({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_friend_request,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetFriendRequestsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetFriendRequestsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_friend_requests",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<Friend>> crateApiOpenimClientOpenImClientGetFriends({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_friend,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetFriendsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetFriendsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_friends",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Group?> crateApiOpenimClientOpenImClientGetGroup({required OpenImClient that , required String groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_group,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetGroupConstMeta,
+            argValues: [that, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_group",
+            argNames: ["that", "groupId"],
+        );
+        
+
+@override Future<List<GroupMember>> crateApiOpenimClientOpenImClientGetGroupMembers({required OpenImClient that , required String groupId , required int offset , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_u_32(offset, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_group_member,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetGroupMembersConstMeta,
+            argValues: [that, groupId, offset, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetGroupMembersConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_group_members",
+            argNames: ["that", "groupId", "offset", "limit"],
+        );
+        
+
+@override Future<MessageHistoryPage> crateApiOpenimClientOpenImClientGetHistory({required OpenImClient that , required String conversationId , required PlatformInt64 beforeSeq , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_i_64(beforeSeq, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_message_history_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetHistoryConstMeta,
+            argValues: [that, conversationId, beforeSeq, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetHistoryConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_history",
+            argNames: ["that", "conversationId", "beforeSeq", "limit"],
+        );
+        
+
+@override Future<List<Group>> crateApiOpenimClientOpenImClientGetJoinedGroups({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_group,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetJoinedGroupsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetJoinedGroupsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_joined_groups",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<MessageReaction>> crateApiOpenimClientOpenImClientGetMessageReactions({required OpenImClient that , required String clientMsgId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(clientMsgId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_message_reaction,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetMessageReactionsConstMeta,
+            argValues: [that, clientMsgId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetMessageReactionsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_message_reactions",
+            argNames: ["that", "clientMsgId"],
+        );
+        
+
+@override Future<UserProfile> crateApiOpenimClientOpenImClientGetSelfInfo({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_user_profile,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetSelfInfoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetSelfInfoConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_self_info",
+            argNames: ["that"],
+        );
+        
+
+@override Future<UserStatus> crateApiOpenimClientOpenImClientGetUserStatus({required OpenImClient that , required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_user_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetUserStatusConstMeta,
+            argValues: [that, userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetUserStatusConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_user_status",
+            argNames: ["that", "userId"],
+        );
+        
+
+@override Future<List<UserProfile>> crateApiOpenimClientOpenImClientGetUsersInfo({required OpenImClient that , required List<String> userIds , required bool forceRefresh })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_list_String(userIds, serializer);
+sse_encode_bool(forceRefresh, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_user_profile,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGetUsersInfoConstMeta,
+            argValues: [that, userIds, forceRefresh],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGetUsersInfoConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_get_users_info",
+            argNames: ["that", "userIds", "forceRefresh"],
+        );
+        
+
+@override Stream<GroupChange> crateApiOpenimClientOpenImClientGroupChangeStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<GroupChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_group_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientGroupChangeStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientGroupChangeStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_group_change_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientHideConversation({required OpenImClient that , required String conversationId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientHideConversationConstMeta,
+            argValues: [that, conversationId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientHideConversationConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_hide_conversation",
+            argNames: ["that", "conversationId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientInviteToGroup({required OpenImClient that , required String groupId , required List<String> userIds , required String reason })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_list_String(userIds, serializer);
+sse_encode_String(reason, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientInviteToGroupConstMeta,
+            argValues: [that, groupId, userIds, reason],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientInviteToGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_invite_to_group",
+            argNames: ["that", "groupId", "userIds", "reason"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientJoinGroup({required OpenImClient that , required String groupId , required String reqMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(reqMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientJoinGroupConstMeta,
+            argValues: [that, groupId, reqMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientJoinGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_join_group",
+            argNames: ["that", "groupId", "reqMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientKickGroupMembers({required OpenImClient that , required String groupId , required List<String> userIds , required String reason })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_list_String(userIds, serializer);
+sse_encode_String(reason, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientKickGroupMembersConstMeta,
+            argValues: [that, groupId, userIds, reason],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientKickGroupMembersConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_kick_group_members",
+            argNames: ["that", "groupId", "userIds", "reason"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientLogout({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientLogoutConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientLogoutConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_logout",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<MessageStatusEvent> crateApiOpenimClientOpenImClientMessageStatusStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<MessageStatusEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_message_status_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientMessageStatusStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientMessageStatusStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_message_status_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientMuteGroup({required OpenImClient that , required String groupId , required bool muted })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_bool(muted, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientMuteGroupConstMeta,
+            argValues: [that, groupId, muted],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientMuteGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_mute_group",
+            argNames: ["that", "groupId", "muted"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientMuteGroupMember({required OpenImClient that , required String groupId , required String userId , required int mutedSeconds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_u_32(mutedSeconds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientMuteGroupMemberConstMeta,
+            argValues: [that, groupId, userId, mutedSeconds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientMuteGroupMemberConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_mute_group_member",
+            argNames: ["that", "groupId", "userId", "mutedSeconds"],
+        );
+        
+
+@override Future<OpenImClient> crateApiOpenimClientOpenImClientNew({required String userId , required String token , required int platformId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(token, serializer);
+sse_encode_i_32(platformId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientNewConstMeta,
+            argValues: [userId, token, platformId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientNewConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_new",
+            argNames: ["userId", "token", "platformId"],
+        );
+        
+
+@override Future<OpenImClient> crateApiOpenimClientOpenImClientOpen({required String userId , required String token , required int platformId , required String dataDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(token, serializer);
+sse_encode_i_32(platformId, serializer);
+sse_encode_String(dataDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientOpenConstMeta,
+            argValues: [userId, token, platformId, dataDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientOpenConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_open",
+            argNames: ["userId", "token", "platformId", "dataDir"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientQuitGroup({required OpenImClient that , required String groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientQuitGroupConstMeta,
+            argValues: [that, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientQuitGroupConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_quit_group",
+            argNames: ["that", "groupId"],
+        );
+        
+
+@override Stream<ReactionChange> crateApiOpenimClientOpenImClientReactionChangeStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<ReactionChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_reaction_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientReactionChangeStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientReactionChangeStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_reaction_change_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientRejectFriendRequest({required OpenImClient that , required String fromUserId , required String handleMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(fromUserId, serializer);
+sse_encode_String(handleMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientRejectFriendRequestConstMeta,
+            argValues: [that, fromUserId, handleMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientRejectFriendRequestConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_reject_friend_request",
+            argNames: ["that", "fromUserId", "handleMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientRejectGroupApplication({required OpenImClient that , required String groupId , required String fromUserId , required String handledMsg })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(fromUserId, serializer);
+sse_encode_String(handledMsg, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientRejectGroupApplicationConstMeta,
+            argValues: [that, groupId, fromUserId, handledMsg],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientRejectGroupApplicationConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_reject_group_application",
+            argNames: ["that", "groupId", "fromUserId", "handledMsg"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientRemoveFromBlacklist({required OpenImClient that , required String userId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientRemoveFromBlacklistConstMeta,
+            argValues: [that, userId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientRemoveFromBlacklistConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_remove_from_blacklist",
+            argNames: ["that", "userId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientRemoveReaction({required OpenImClient that , required String conversationId , required String clientMsgId , required String emoji })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_String(clientMsgId, serializer);
+sse_encode_String(emoji, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientRemoveReactionConstMeta,
+            argValues: [that, conversationId, clientMsgId, emoji],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientRemoveReactionConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_remove_reaction",
+            argNames: ["that", "conversationId", "clientMsgId", "emoji"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientRetryMessage({required OpenImClient that , required PlatformInt64 outboxId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_i_64(outboxId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientRetryMessageConstMeta,
+            argValues: [that, outboxId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientRetryMessageConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_retry_message",
+            argNames: ["that", "outboxId"],
+        );
+        
+
+@override Future<MessageSearchPage> crateApiOpenimClientOpenImClientSearchMessages({required OpenImClient that , required MessageSearchQuery query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_box_autoadd_message_search_query(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_message_search_page,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSearchMessagesConstMeta,
+            argValues: [that, query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSearchMessagesConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_search_messages",
+            argNames: ["that", "query"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiOpenimClientOpenImClientSendGroupTextMessage({required OpenImClient that , required String groupId , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSendGroupTextMessageConstMeta,
+            argValues: [that, groupId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSendGroupTextMessageConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_send_group_text_message",
+            argNames: ["that", "groupId", "text"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiOpenimClientOpenImClientSendTextMessage({required OpenImClient that , required String recvId , required String text })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(recvId, serializer);
+sse_encode_String(text, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSendTextMessageConstMeta,
+            argValues: [that, recvId, text],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSendTextMessageConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_send_text_message",
+            argNames: ["that", "recvId", "text"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSendTyping({required OpenImClient that , required String conversationId , required bool typing })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_bool(typing, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSendTypingConstMeta,
+            argValues: [that, conversationId, typing],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSendTypingConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_send_typing",
+            argNames: ["that", "conversationId", "typing"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetAppBackground({required OpenImClient that , required bool background })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_bool(background, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetAppBackgroundConstMeta,
+            argValues: [that, background],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetAppBackgroundConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_app_background",
+            argNames: ["that", "background"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetConversationPinned({required OpenImClient that , required String conversationId , required bool pinned })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_bool(pinned, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetConversationPinnedConstMeta,
+            argValues: [that, conversationId, pinned],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetConversationPinnedConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_conversation_pinned",
+            argNames: ["that", "conversationId", "pinned"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetDraft({required OpenImClient that , required String conversationId , required String draft })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_String(draft, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetDraftConstMeta,
+            argValues: [that, conversationId, draft],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetDraftConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_draft",
+            argNames: ["that", "conversationId", "draft"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetFriendRemark({required OpenImClient that , required String userId , required String remark })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_String(remark, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetFriendRemarkConstMeta,
+            argValues: [that, userId, remark],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetFriendRemarkConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_friend_remark",
+            argNames: ["that", "userId", "remark"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetGroupAdmin({required OpenImClient that , required String groupId , required String userId , required bool admin })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_bool(admin, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetGroupAdminConstMeta,
+            argValues: [that, groupId, userId, admin],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetGroupAdminConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_group_admin",
+            argNames: ["that", "groupId", "userId", "admin"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetRecvMsgOpt({required OpenImClient that , required String conversationId , required RecvMsgOpt opt })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_recv_msg_opt(opt, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetRecvMsgOptConstMeta,
+            argValues: [that, conversationId, opt],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetRecvMsgOptConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_recv_msg_opt",
+            argNames: ["that", "conversationId", "opt"],
+        );
+        
+
+@override Future<UserProfile> crateApiOpenimClientOpenImClientSetSelfInfo({required OpenImClient that , String? nickname , String? faceUrl , String? ex })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_opt_String(nickname, serializer);
+sse_encode_opt_String(faceUrl, serializer);
+sse_encode_opt_String(ex, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_user_profile,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetSelfInfoConstMeta,
+            argValues: [that, nickname, faceUrl, ex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetSelfInfoConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_self_info",
+            argNames: ["that", "nickname", "faceUrl", "ex"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetSyncOnConnect({required OpenImClient that , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetSyncOnConnectConstMeta,
+            argValues: [that, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetSyncOnConnectConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_sync_on_connect",
+            argNames: ["that", "enabled"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSetTokenRefresher({required OpenImClient that , required FutureOr<String> Function() callback })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_DartFn_Inputs__Output_String_AnyhowException(callback, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSetTokenRefresherConstMeta,
+            argValues: [that, callback],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSetTokenRefresherConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_set_token_refresher",
+            argNames: ["that", "callback"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientStartFrameCapture({required OpenImClient that , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientStartFrameCaptureConstMeta,
+            argValues: [that, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientStartFrameCaptureConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_start_frame_capture",
+            argNames: ["that", "path"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientStopFrameCapture({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientStopFrameCaptureConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientStopFrameCaptureConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_stop_frame_capture",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSubscribeUsersStatus({required OpenImClient that , required List<String> userIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_list_String(userIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSubscribeUsersStatusConstMeta,
+            argValues: [that, userIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSubscribeUsersStatusConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_subscribe_users_status",
+            argNames: ["that", "userIds"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSyncContacts({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSyncContactsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSyncContactsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_sync_contacts",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSyncConversations({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSyncConversationsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSyncConversationsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_sync_conversations",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSyncGroupMembers({required OpenImClient that , required String groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSyncGroupMembersConstMeta,
+            argValues: [that, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSyncGroupMembersConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_sync_group_members",
+            argNames: ["that", "groupId"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientSyncJoinedGroups({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSyncJoinedGroupsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSyncJoinedGroupsConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_sync_joined_groups",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<SyncStatus> crateApiOpenimClientOpenImClientSyncStatusStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<SyncStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_sync_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientSyncStatusStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientSyncStatusStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_sync_status_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<String> crateApiOpenimClientOpenImClientToken({required OpenImClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientTokenConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientTokenConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_token",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<TokenStatus> crateApiOpenimClientOpenImClientTokenStatusStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<TokenStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_token_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientTokenStatusStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientTokenStatusStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_token_status_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientTransferGroupOwner({required OpenImClient that , required String groupId , required String newOwnerUserId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(groupId, serializer);
+sse_encode_String(newOwnerUserId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientTransferGroupOwnerConstMeta,
+            argValues: [that, groupId, newOwnerUserId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientTransferGroupOwnerConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_transfer_group_owner",
+            argNames: ["that", "groupId", "newOwnerUserId"],
+        );
+        
+
+@override Stream<TypingStatus> crateApiOpenimClientOpenImClientTypingStream({required OpenImClient that , required String conversationId })  { 
+            final sink = RustStreamSink<TypingStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_String(conversationId, serializer);
+sse_encode_StreamSink_typing_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientTypingStreamConstMeta,
+            argValues: [that, conversationId, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientTypingStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_typing_stream",
+            argNames: ["that", "conversationId", "sink"],
+        );
+        
+
+@override Future<void> crateApiOpenimClientOpenImClientUnsubscribeUsersStatus({required OpenImClient that , required List<String> userIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_list_String(userIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientUnsubscribeUsersStatusConstMeta,
+            argValues: [that, userIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientUnsubscribeUsersStatusConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_unsubscribe_users_status",
+            argNames: ["that", "userIds"],
+        );
+        
+
+@override Stream<UserStatus> crateApiOpenimClientOpenImClientUserStatusStream({required OpenImClient that })  { 
+            final sink = RustStreamSink<UserStatus>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(that, serializer);
+sse_encode_StreamSink_user_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiOpenimClientOpenImClientUserStatusStreamConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiOpenimClientOpenImClientUserStatusStreamConstMeta => const TaskConstMeta(
+            debugName: "OpenImClient_user_status_stream",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<OpenImClient?> crateApiSessionsActiveSession()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSessionsActiveSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsActiveSessionConstMeta => const TaskConstMeta(
+            debugName: "active_session",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSessionsCloseSession({required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSessionsCloseSessionConstMeta,
+            argValues: [sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsCloseSessionConstMeta => const TaskConstMeta(
+            debugName: "close_session",
+            argNames: ["sessionId"],
+        );
+        
+
+@override Future<List<String>> crateApiLoggingGetLogFiles({required String logDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(logDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggingGetLogFilesConstMeta,
+            argValues: [logDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggingGetLogFilesConstMeta => const TaskConstMeta(
+            debugName: "get_log_files",
+            argNames: ["logDir"],
+        );
+        
+
+@override Future<OpenImClient> crateApiSessionsGetSession({required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSessionsGetSessionConstMeta,
+            argValues: [sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsGetSessionConstMeta => const TaskConstMeta(
+            debugName: "get_session",
+            argNames: ["sessionId"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLoggingInitLogging({required LogLevel level , String? logDir , required int maxFiles })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_log_level(level, serializer);
+sse_encode_opt_String(logDir, serializer);
+sse_encode_u_32(maxFiles, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiLoggingInitLoggingConstMeta,
+            argValues: [level, logDir, maxFiles],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoggingInitLoggingConstMeta => const TaskConstMeta(
+            debugName: "init_logging",
+            argNames: ["level", "logDir", "maxFiles"],
+        );
+        
+
+@override Future<List<SessionInfo>> crateApiSessionsListSessions()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_session_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSessionsListSessionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsListSessionsConstMeta => const TaskConstMeta(
+            debugName: "list_sessions",
+            argNames: [],
+        );
+        
+
+@override Stream<LogEntry> crateApiLoggingLogStream()  { 
+            final sink = RustStreamSink<LogEntry>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_log_entry_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiLoggingLogStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiLoggingLogStreamConstMeta => const TaskConstMeta(
+            debugName: "log_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<LoginSession> crateApiAuthLogin({required LoginCredentials credentials , required int platformId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_login_credentials(credentials, serializer);
+sse_encode_i_32(platformId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_login_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuthLoginConstMeta,
+            argValues: [credentials, platformId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuthLoginConstMeta => const TaskConstMeta(
+            debugName: "login",
+            argNames: ["credentials", "platformId"],
+        );
+        
+
+@override Future<void> crateApiAuthLogout()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuthLogoutConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuthLogoutConstMeta => const TaskConstMeta(
+            debugName: "logout",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiSessionsOpenSession({required String userId , required String token , required int platformId , String? rootDir })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(token, serializer);
+sse_encode_i_32(platformId, serializer);
+sse_encode_opt_String(rootDir, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSessionsOpenSessionConstMeta,
+            argValues: [userId, token, platformId, rootDir],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsOpenSessionConstMeta => const TaskConstMeta(
+            debugName: "open_session",
+            argNames: ["userId", "token", "platformId", "rootDir"],
+        );
+        
+
+@override Future<PreparedPicture> crateApiPicturePreparePicture({required List<int> data , required String sourcePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_String(sourcePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_prepared_picture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPicturePreparePictureConstMeta,
+            argValues: [data, sourcePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPicturePreparePictureConstMeta => const TaskConstMeta(
+            debugName: "prepare_picture",
+            argNames: ["data", "sourcePath"],
+        );
+        
+
+@override Future<ReplayReport> crateApiCaptureReplayCapture({required String userId , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(userId, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_replay_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiCaptureReplayCaptureConstMeta,
+            argValues: [userId, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCaptureReplayCaptureConstMeta => const TaskConstMeta(
+            debugName: "replay_capture",
+            argNames: ["userId", "path"],
+        );
+        
+
+@override Future<LoginSession?> crateApiAuthRestoreSession()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_login_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAuthRestoreSessionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuthRestoreSessionConstMeta => const TaskConstMeta(
+            debugName: "restore_session",
+            argNames: [],
+        );
+        
+
+@override Stream<SessionChange> crateApiSessionsSessionChangeStream()  { 
+            final sink = RustStreamSink<SessionChange>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_StreamSink_session_change_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSessionsSessionChangeStreamConstMeta,
+            argValues: [sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiSessionsSessionChangeStreamConstMeta => const TaskConstMeta(
+            debugName: "session_change_stream",
+            argNames: ["sink"],
+        );
+        
+
+@override Future<void> crateApiSessionsSetActiveSession({required String sessionId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(sessionId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiSessionsSetActiveSessionConstMeta,
+            argValues: [sessionId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSessionsSetActiveSessionConstMeta => const TaskConstMeta(
+            debugName: "set_active_session",
+            argNames: ["sessionId"],
+        );
+        
+
+@override Future<void> crateApiAuthSetTokenStorage({required FutureOr<String?> Function(String) load , required FutureOr<void> Function(String, String) save , required FutureOr<void> Function(String) delete })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(load, serializer);
+sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(save, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(delete, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAuthSetTokenStorageConstMeta,
+            argValues: [load, save, delete],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAuthSetTokenStorageConstMeta => const TaskConstMeta(
+            debugName: "set_token_storage",
+            argNames: ["load", "save", "delete"],
+        );
+        
+
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<String?>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_opt_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() raw) {
+              return (callId, ) async {
+                
+
+                Box<String>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_OpenImClient => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_OpenImClient => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected OpenImClient dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(raw); }
+
+@protected OpenImClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OpenImClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected OpenImClient dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OpenImClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected OpenImClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OpenImClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected FutureOr<String?> Function(String) dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<String> Function() dco_decode_DartFn_Inputs__Output_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected OpenImClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OpenImClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<ConnectionStatus> dco_decode_StreamSink_connection_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ContactChange> dco_decode_StreamSink_contact_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ConversationChange> dco_decode_StreamSink_conversation_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<GroupChange> dco_decode_StreamSink_group_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<MessageStatusEvent> dco_decode_StreamSink_message_status_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ReactionChange> dco_decode_StreamSink_reaction_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SessionChange> dco_decode_StreamSink_session_change_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<SyncStatus> dco_decode_StreamSink_sync_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<TokenStatus> dco_decode_StreamSink_token_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<TypingStatus> dco_decode_StreamSink_typing_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<UserStatus> dco_decode_StreamSink_user_status_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected BlockedUser dco_decode_blocked_user(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BlockedUser(userId: dco_decode_String(arr[0]),
+nickname: dco_decode_String(arr[1]),
+faceUrl: dco_decode_String(arr[2]),
+createTime: dco_decode_i_64(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected Group dco_decode_box_autoadd_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_group(raw); }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected LoginCredentials dco_decode_box_autoadd_login_credentials(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_login_credentials(raw); }
+
+@protected LoginSession dco_decode_box_autoadd_login_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_login_session(raw); }
+
+@protected MessageSearchQuery dco_decode_box_autoadd_message_search_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_message_search_query(raw); }
+
+@protected ChatMessage dco_decode_chat_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return ChatMessage(clientMsgId: dco_decode_String(arr[0]),
+serverMsgId: dco_decode_String(arr[1]),
+conversationId: dco_decode_String(arr[2]),
+seq: dco_decode_i_64(arr[3]),
+sendId: dco_decode_String(arr[4]),
+recvId: dco_decode_String(arr[5]),
+groupId: dco_decode_String(arr[6]),
+sessionType: dco_decode_i_32(arr[7]),
+contentType: dco_decode_i_32(arr[8]),
+content: dco_decode_list_prim_u_8_strict(arr[9]),
+text: dco_decode_opt_String(arr[10]),
+sendTime: dco_decode_i_64(arr[11]),); }
+
+@protected ConnectionStatus dco_decode_connection_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ConnectionStatus_Idle();
+case 1: return ConnectionStatus_Connecting();
+case 2: return ConnectionStatus_Handshaking();
+case 3: return ConnectionStatus_Connected();
+case 4: return ConnectionStatus_Syncing();
+case 5: return ConnectionStatus_Reconnecting(attempt: dco_decode_u_32(raw[1]),backoffMs: dco_decode_u_64(raw[2]),);
+case 6: return ConnectionStatus_Kicked();
+case 7: return ConnectionStatus_TokenExpired();
+case 8: return ConnectionStatus_Closed();
+                default: throw Exception("unreachable");
+            } }
+
+@protected ContactChange dco_decode_contact_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ContactChange.values[raw as int]; }
+
+@protected ConversationChange dco_decode_conversation_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return ConversationChange(conversationId: dco_decode_String(arr[0]),); }
+
+@protected ConversationInfo dco_decode_conversation_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return ConversationInfo(conversationId: dco_decode_String(arr[0]),
+conversationType: dco_decode_i_32(arr[1]),
+userId: dco_decode_String(arr[2]),
+groupId: dco_decode_String(arr[3]),
+recvMsgOpt: dco_decode_recv_msg_opt(arr[4]),
+isPinned: dco_decode_bool(arr[5]),
+draftText: dco_decode_String(arr[6]),
+draftTextTime: dco_decode_i_64(arr[7]),
+latestMsgTime: dco_decode_i_64(arr[8]),
+latestMsgClientMsgId: dco_decode_opt_String(arr[9]),
+latestMsgSendId: dco_decode_opt_String(arr[10]),
+latestMsgContentType: dco_decode_opt_box_autoadd_i_32(arr[11]),
+latestMsgText: dco_decode_opt_String(arr[12]),
+ex: dco_decode_String(arr[13]),); }
+
+@protected Friend dco_decode_friend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return Friend(userId: dco_decode_String(arr[0]),
+nickname: dco_decode_String(arr[1]),
+faceUrl: dco_decode_String(arr[2]),
+remark: dco_decode_String(arr[3]),
+ex: dco_decode_String(arr[4]),
+createTime: dco_decode_i_64(arr[5]),); }
+
+@protected FriendRequest dco_decode_friend_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return FriendRequest(fromUserId: dco_decode_String(arr[0]),
+fromNickname: dco_decode_String(arr[1]),
+fromFaceUrl: dco_decode_String(arr[2]),
+toUserId: dco_decode_String(arr[3]),
+toNickname: dco_decode_String(arr[4]),
+toFaceUrl: dco_decode_String(arr[5]),
+handleResult: dco_decode_i_32(arr[6]),
+reqMsg: dco_decode_String(arr[7]),
+handleMsg: dco_decode_String(arr[8]),
+createTime: dco_decode_i_64(arr[9]),
+handleTime: dco_decode_i_64(arr[10]),); }
+
+@protected Group dco_decode_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return Group(groupId: dco_decode_String(arr[0]),
+groupName: dco_decode_String(arr[1]),
+notification: dco_decode_String(arr[2]),
+introduction: dco_decode_String(arr[3]),
+faceUrl: dco_decode_String(arr[4]),
+ownerUserId: dco_decode_String(arr[5]),
+memberCount: dco_decode_i_32(arr[6]),
+status: dco_decode_i_32(arr[7]),
+createTime: dco_decode_i_64(arr[8]),); }
+
+@protected GroupChange dco_decode_group_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return GroupChange_JoinedGroups();
+case 1: return GroupChange_Info(groupId: dco_decode_String(raw[1]),);
+case 2: return GroupChange_Members(groupId: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected GroupMember dco_decode_group_member(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return GroupMember(groupId: dco_decode_String(arr[0]),
+userId: dco_decode_String(arr[1]),
+nickname: dco_decode_String(arr[2]),
+faceUrl: dco_decode_String(arr[3]),
+role: dco_decode_group_role(arr[4]),
+joinTime: dco_decode_i_64(arr[5]),
+muteEndTime: dco_decode_i_64(arr[6]),); }
+
+@protected GroupRole dco_decode_group_role(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return GroupRole.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<BlockedUser> dco_decode_list_blocked_user(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_blocked_user).toList(); }
+
+@protected List<ChatMessage> dco_decode_list_chat_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_chat_message).toList(); }
+
+@protected List<ConversationInfo> dco_decode_list_conversation_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_conversation_info).toList(); }
+
+@protected List<Friend> dco_decode_list_friend(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_friend).toList(); }
+
+@protected List<FriendRequest> dco_decode_list_friend_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_friend_request).toList(); }
+
+@protected List<Group> dco_decode_list_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_group).toList(); }
+
+@protected List<GroupMember> dco_decode_list_group_member(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_group_member).toList(); }
+
+@protected List<LogField> dco_decode_list_log_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_log_field).toList(); }
+
+@protected List<MessageReaction> dco_decode_list_message_reaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_message_reaction).toList(); }
+
+@protected List<MessageSearchHit> dco_decode_list_message_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_message_search_hit).toList(); }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ReplayError> dco_decode_list_replay_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_replay_error).toList(); }
+
+@protected List<SessionInfo> dco_decode_list_session_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_session_info).toList(); }
+
+@protected List<SnippetHighlight> dco_decode_list_snippet_highlight(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snippet_highlight).toList(); }
+
+@protected List<SyncStage> dco_decode_list_sync_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sync_stage).toList(); }
+
+@protected List<UserProfile> dco_decode_list_user_profile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_user_profile).toList(); }
+
+@protected LogEntry dco_decode_log_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LogEntry(timeMs: dco_decode_i_64(arr[0]),
+level: dco_decode_log_level(arr[1]),
+target: dco_decode_String(arr[2]),
+message: dco_decode_String(arr[3]),
+fields: dco_decode_list_log_field(arr[4]),
+spans: dco_decode_String(arr[5]),); }
+
+@protected LogField dco_decode_log_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LogField(name: dco_decode_String(arr[0]),
+value: dco_decode_String(arr[1]),); }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LoginCredentials dco_decode_login_credentials(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return LoginCredentials_AppServer(url: dco_decode_String(raw[1]),account: dco_decode_String(raw[2]),password: dco_decode_String(raw[3]),);
+case 1: return LoginCredentials_AdminSecret(apiUrl: dco_decode_String(raw[1]),secret: dco_decode_String(raw[2]),userId: dco_decode_String(raw[3]),adminUserId: dco_decode_String(raw[4]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LoginSession dco_decode_login_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LoginSession(userId: dco_decode_String(arr[0]),
+token: dco_decode_String(arr[1]),
+platformId: dco_decode_i_32(arr[2]),
+expiresAt: dco_decode_i_64(arr[3]),); }
+
+@protected MessageHistoryPage dco_decode_message_history_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MessageHistoryPage(messages: dco_decode_list_chat_message(arr[0]),
+hasMore: dco_decode_bool(arr[1]),); }
+
+@protected MessageReaction dco_decode_message_reaction(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MessageReaction(emoji: dco_decode_String(arr[0]),
+count: dco_decode_u_32(arr[1]),
+userIds: dco_decode_list_String(arr[2]),
+reactedByMe: dco_decode_bool(arr[3]),); }
+
+@protected MessageSearchHit dco_decode_message_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MessageSearchHit(clientMsgId: dco_decode_String(arr[0]),
+conversationId: dco_decode_String(arr[1]),
+sendId: dco_decode_String(arr[2]),
+contentType: dco_decode_i_32(arr[3]),
+sendTime: dco_decode_i_64(arr[4]),
+seq: dco_decode_i_64(arr[5]),
+snippet: dco_decode_String(arr[6]),
+highlights: dco_decode_list_snippet_highlight(arr[7]),); }
+
+@protected MessageSearchPage dco_decode_message_search_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MessageSearchPage(hits: dco_decode_list_message_search_hit(arr[0]),
+hasMore: dco_decode_bool(arr[1]),); }
+
+@protected MessageSearchQuery dco_decode_message_search_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MessageSearchQuery(keyword: dco_decode_String(arr[0]),
+conversationId: dco_decode_opt_String(arr[1]),
+senderId: dco_decode_opt_String(arr[2]),
+contentTypes: dco_decode_list_prim_i_32_strict(arr[3]),
+startTime: dco_decode_opt_box_autoadd_i_64(arr[4]),
+endTime: dco_decode_opt_box_autoadd_i_64(arr[5]),
+offset: dco_decode_u_32(arr[6]),
+limit: dco_decode_u_32(arr[7]),); }
+
+@protected MessageSendStatus dco_decode_message_send_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MessageSendStatus.values[raw as int]; }
+
+@protected MessageStatusEvent dco_decode_message_status_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return MessageStatusEvent(outboxId: dco_decode_i_64(arr[0]),
+clientMsgId: dco_decode_String(arr[1]),
+conversationId: dco_decode_String(arr[2]),
+status: dco_decode_message_send_status(arr[3]),
+attempts: dco_decode_i_64(arr[4]),
+serverMsgId: dco_decode_String(arr[5]),
+sendTime: dco_decode_i_64(arr[6]),
+error: dco_decode_String(arr[7]),); }
+
+@protected OpenImClient? dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(raw); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected Group? dco_decode_opt_box_autoadd_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_group(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected LoginSession? dco_decode_opt_box_autoadd_login_session(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_login_session(raw); }
+
+@protected PreparedPicture dco_decode_prepared_picture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PreparedPicture(source: dco_decode_list_prim_u_8_strict(arr[0]),
+big: dco_decode_list_prim_u_8_strict(arr[1]),
+snapshot: dco_decode_list_prim_u_8_strict(arr[2]),
+pictureElemJson: dco_decode_String(arr[3]),); }
+
+@protected ReactionChange dco_decode_reaction_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReactionChange(conversationId: dco_decode_String(arr[0]),
+clientMsgId: dco_decode_String(arr[1]),
+reactions: dco_decode_list_message_reaction(arr[2]),); }
+
+@protected RecvMsgOpt dco_decode_recv_msg_opt(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RecvMsgOpt.values[raw as int]; }
+
+@protected ReplayError dco_decode_replay_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReplayError(index: dco_decode_u_32(arr[0]),
+timeMs: dco_decode_i_64(arr[1]),
+error: dco_decode_String(arr[2]),); }
+
+@protected ReplayReport dco_decode_replay_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ReplayReport(frames: dco_decode_u_32(arr[0]),
+replayed: dco_decode_u_32(arr[1]),
+errors: dco_decode_list_replay_error(arr[2]),); }
+
+@protected SessionChange dco_decode_session_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SessionChange_Opened(sessionId: dco_decode_String(raw[1]),);
+case 1: return SessionChange_Closed(sessionId: dco_decode_String(raw[1]),);
+case 2: return SessionChange_Activated(sessionId: dco_decode_opt_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SessionInfo dco_decode_session_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SessionInfo(sessionId: dco_decode_String(arr[0]),
+userId: dco_decode_String(arr[1]),
+platformId: dco_decode_i_32(arr[2]),
+dataDir: dco_decode_opt_String(arr[3]),
+isActive: dco_decode_bool(arr[4]),); }
+
+@protected SnippetHighlight dco_decode_snippet_highlight(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SnippetHighlight(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected SyncStage dco_decode_sync_stage(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SyncStage.values[raw as int]; }
+
+@protected SyncStatus dco_decode_sync_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SyncStatus_Syncing(stage: dco_decode_sync_stage(raw[1]),percent: dco_decode_u_8(raw[2]),);
+case 1: return SyncStatus_Done(failed: dco_decode_list_sync_stage(raw[1]),);
+case 2: return SyncStatus_Interrupted();
+                default: throw Exception("unreachable");
+            } }
+
+@protected TokenStatus dco_decode_token_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return TokenStatus_WillExpire(expiresAt: dco_decode_i_64(raw[1]),);
+case 1: return TokenStatus_Refreshed(expiresAt: dco_decode_i_64(raw[1]),);
+case 2: return TokenStatus_RefreshFailed(error: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected TypingStatus dco_decode_typing_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TypingStatus(conversationId: dco_decode_String(arr[0]),
+userId: dco_decode_String(arr[1]),
+typing: dco_decode_bool(arr[2]),); }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UserProfile dco_decode_user_profile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UserProfile(userId: dco_decode_String(arr[0]),
+nickname: dco_decode_String(arr[1]),
+faceUrl: dco_decode_String(arr[2]),
+ex: dco_decode_String(arr[3]),
+createTime: dco_decode_i_64(arr[4]),); }
+
+@protected UserStatus dco_decode_user_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UserStatus(userId: dco_decode_String(arr[0]),
+online: dco_decode_bool(arr[1]),
+platformIds: dco_decode_list_prim_i_32_strict(arr[2]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected OpenImClient sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(deserializer);
+        return inner; }
+
+@protected OpenImClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OpenImClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected OpenImClient sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OpenImClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected OpenImClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OpenImClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected OpenImClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return OpenImClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<ConnectionStatus> sse_decode_StreamSink_connection_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ContactChange> sse_decode_StreamSink_contact_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ConversationChange> sse_decode_StreamSink_conversation_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<GroupChange> sse_decode_StreamSink_group_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<MessageStatusEvent> sse_decode_StreamSink_message_status_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ReactionChange> sse_decode_StreamSink_reaction_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SessionChange> sse_decode_StreamSink_session_change_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<SyncStatus> sse_decode_StreamSink_sync_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<TokenStatus> sse_decode_StreamSink_token_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<TypingStatus> sse_decode_StreamSink_typing_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<UserStatus> sse_decode_StreamSink_user_status_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected BlockedUser sse_decode_blocked_user(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_nickname = sse_decode_String(deserializer);
+var var_faceUrl = sse_decode_String(deserializer);
+var var_createTime = sse_decode_i_64(deserializer);
+return BlockedUser(userId: var_userId, nickname: var_nickname, faceUrl: var_faceUrl, createTime: var_createTime); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected Group sse_decode_box_autoadd_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_group(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected LoginCredentials sse_decode_box_autoadd_login_credentials(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_login_credentials(deserializer)); }
+
+@protected LoginSession sse_decode_box_autoadd_login_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_login_session(deserializer)); }
+
+@protected MessageSearchQuery sse_decode_box_autoadd_message_search_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_message_search_query(deserializer)); }
+
+@protected ChatMessage sse_decode_chat_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_clientMsgId = sse_decode_String(deserializer);
+var var_serverMsgId = sse_decode_String(deserializer);
+var var_conversationId = sse_decode_String(deserializer);
+var var_seq = sse_decode_i_64(deserializer);
+var var_sendId = sse_decode_String(deserializer);
+var var_recvId = sse_decode_String(deserializer);
+var var_groupId = sse_decode_String(deserializer);
+var var_sessionType = sse_decode_i_32(deserializer);
+var var_contentType = sse_decode_i_32(deserializer);
+var var_content = sse_decode_list_prim_u_8_strict(deserializer);
+var var_text = sse_decode_opt_String(deserializer);
+var var_sendTime = sse_decode_i_64(deserializer);
+return ChatMessage(clientMsgId: var_clientMsgId, serverMsgId: var_serverMsgId, conversationId: var_conversationId, seq: var_seq, sendId: var_sendId, recvId: var_recvId, groupId: var_groupId, sessionType: var_sessionType, contentType: var_contentType, content: var_content, text: var_text, sendTime: var_sendTime); }
+
+@protected ConnectionStatus sse_decode_connection_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ConnectionStatus_Idle();case 1: return ConnectionStatus_Connecting();case 2: return ConnectionStatus_Handshaking();case 3: return ConnectionStatus_Connected();case 4: return ConnectionStatus_Syncing();case 5: var var_attempt = sse_decode_u_32(deserializer);
+var var_backoffMs = sse_decode_u_64(deserializer);
+return ConnectionStatus_Reconnecting(attempt: var_attempt, backoffMs: var_backoffMs);case 6: return ConnectionStatus_Kicked();case 7: return ConnectionStatus_TokenExpired();case 8: return ConnectionStatus_Closed(); default: throw UnimplementedError(''); }
+             }
+
+@protected ContactChange sse_decode_contact_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ContactChange.values[inner]; }
+
+@protected ConversationChange sse_decode_conversation_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conversationId = sse_decode_String(deserializer);
+return ConversationChange(conversationId: var_conversationId); }
+
+@protected ConversationInfo sse_decode_conversation_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conversationId = sse_decode_String(deserializer);
+var var_conversationType = sse_decode_i_32(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_groupId = sse_decode_String(deserializer);
+var var_recvMsgOpt = sse_decode_recv_msg_opt(deserializer);
+var var_isPinned = sse_decode_bool(deserializer);
+var var_draftText = sse_decode_String(deserializer);
+var var_draftTextTime = sse_decode_i_64(deserializer);
+var var_latestMsgTime = sse_decode_i_64(deserializer);
+var var_latestMsgClientMsgId = sse_decode_opt_String(deserializer);
+var var_latestMsgSendId = sse_decode_opt_String(deserializer);
+var var_latestMsgContentType = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_latestMsgText = sse_decode_opt_String(deserializer);
+var var_ex = sse_decode_String(deserializer);
+return ConversationInfo(conversationId: var_conversationId, conversationType: var_conversationType, userId: var_userId, groupId: var_groupId, recvMsgOpt: var_recvMsgOpt, isPinned: var_isPinned, draftText: var_draftText, draftTextTime: var_draftTextTime, latestMsgTime: var_latestMsgTime, latestMsgClientMsgId: var_latestMsgClientMsgId, latestMsgSendId: var_latestMsgSendId, latestMsgContentType: var_latestMsgContentType, latestMsgText: var_latestMsgText, ex: var_ex); }
+
+@protected Friend sse_decode_friend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_nickname = sse_decode_String(deserializer);
+var var_faceUrl = sse_decode_String(deserializer);
+var var_remark = sse_decode_String(deserializer);
+var var_ex = sse_decode_String(deserializer);
+var var_createTime = sse_decode_i_64(deserializer);
+return Friend(userId: var_userId, nickname: var_nickname, faceUrl: var_faceUrl, remark: var_remark, ex: var_ex, createTime: var_createTime); }
+
+@protected FriendRequest sse_decode_friend_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fromUserId = sse_decode_String(deserializer);
+var var_fromNickname = sse_decode_String(deserializer);
+var var_fromFaceUrl = sse_decode_String(deserializer);
+var var_toUserId = sse_decode_String(deserializer);
+var var_toNickname = sse_decode_String(deserializer);
+var var_toFaceUrl = sse_decode_String(deserializer);
+var var_handleResult = sse_decode_i_32(deserializer);
+var var_reqMsg = sse_decode_String(deserializer);
+var var_handleMsg = sse_decode_String(deserializer);
+var var_createTime = sse_decode_i_64(deserializer);
+var var_handleTime = sse_decode_i_64(deserializer);
+return FriendRequest(fromUserId: var_fromUserId, fromNickname: var_fromNickname, fromFaceUrl: var_fromFaceUrl, toUserId: var_toUserId, toNickname: var_toNickname, toFaceUrl: var_toFaceUrl, handleResult: var_handleResult, reqMsg: var_reqMsg, handleMsg: var_handleMsg, createTime: var_createTime, handleTime: var_handleTime); }
+
+@protected Group sse_decode_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_groupId = sse_decode_String(deserializer);
+var var_groupName = sse_decode_String(deserializer);
+var var_notification = sse_decode_String(deserializer);
+var var_introduction = sse_decode_String(deserializer);
+var var_faceUrl = sse_decode_String(deserializer);
+var var_ownerUserId = sse_decode_String(deserializer);
+var var_memberCount = sse_decode_i_32(deserializer);
+var var_status = sse_decode_i_32(deserializer);
+var var_createTime = sse_decode_i_64(deserializer);
+return Group(groupId: var_groupId, groupName: var_groupName, notification: var_notification, introduction: var_introduction, faceUrl: var_faceUrl, ownerUserId: var_ownerUserId, memberCount: var_memberCount, status: var_status, createTime: var_createTime); }
+
+@protected GroupChange sse_decode_group_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return GroupChange_JoinedGroups();case 1: var var_groupId = sse_decode_String(deserializer);
+return GroupChange_Info(groupId: var_groupId);case 2: var var_groupId = sse_decode_String(deserializer);
+return GroupChange_Members(groupId: var_groupId); default: throw UnimplementedError(''); }
+             }
+
+@protected GroupMember sse_decode_group_member(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_groupId = sse_decode_String(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_nickname = sse_decode_String(deserializer);
+var var_faceUrl = sse_decode_String(deserializer);
+var var_role = sse_decode_group_role(deserializer);
+var var_joinTime = sse_decode_i_64(deserializer);
+var var_muteEndTime = sse_decode_i_64(deserializer);
+return GroupMember(groupId: var_groupId, userId: var_userId, nickname: var_nickname, faceUrl: var_faceUrl, role: var_role, joinTime: var_joinTime, muteEndTime: var_muteEndTime); }
+
+@protected GroupRole sse_decode_group_role(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return GroupRole.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BlockedUser> sse_decode_list_blocked_user(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BlockedUser>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_blocked_user(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ChatMessage> sse_decode_list_chat_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ChatMessage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_chat_message(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConversationInfo> sse_decode_list_conversation_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConversationInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_conversation_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Friend> sse_decode_list_friend(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Friend>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_friend(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FriendRequest> sse_decode_list_friend_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FriendRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_friend_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Group> sse_decode_list_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Group>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<GroupMember> sse_decode_list_group_member(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <GroupMember>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_group_member(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LogField> sse_decode_list_log_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LogField>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_log_field(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MessageReaction> sse_decode_list_message_reaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MessageReaction>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_message_reaction(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MessageSearchHit> sse_decode_list_message_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MessageSearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_message_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ReplayError> sse_decode_list_replay_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReplayError>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_replay_error(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SessionInfo> sse_decode_list_session_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SessionInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_session_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SnippetHighlight> sse_decode_list_snippet_highlight(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SnippetHighlight>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snippet_highlight(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SyncStage> sse_decode_list_sync_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SyncStage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sync_stage(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UserProfile> sse_decode_list_user_profile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UserProfile>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_user_profile(deserializer)); }
+        return ans_;
+         }
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timeMs = sse_decode_i_64(deserializer);
+var var_level = sse_decode_log_level(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_fields = sse_decode_list_log_field(deserializer);
+var var_spans = sse_decode_String(deserializer);
+return LogEntry(timeMs: var_timeMs, level: var_level, target: var_target, message: var_message, fields: var_fields, spans: var_spans); }
+
+@protected LogField sse_decode_log_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return LogField(name: var_name, value: var_value); }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LoginCredentials sse_decode_login_credentials(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_url = sse_decode_String(deserializer);
+var var_account = sse_decode_String(deserializer);
+var var_password = sse_decode_String(deserializer);
+return LoginCredentials_AppServer(url: var_url, account: var_account, password: var_password);case 1: var var_apiUrl = sse_decode_String(deserializer);
+var var_secret = sse_decode_String(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_adminUserId = sse_decode_String(deserializer);
+return LoginCredentials_AdminSecret(apiUrl: var_apiUrl, secret: var_secret, userId: var_userId, adminUserId: var_adminUserId); default: throw UnimplementedError(''); }
+             }
+
+@protected LoginSession sse_decode_login_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_token = sse_decode_String(deserializer);
+var var_platformId = sse_decode_i_32(deserializer);
+var var_expiresAt = sse_decode_i_64(deserializer);
+return LoginSession(userId: var_userId, token: var_token, platformId: var_platformId, expiresAt: var_expiresAt); }
+
+@protected MessageHistoryPage sse_decode_message_history_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_messages = sse_decode_list_chat_message(deserializer);
+var var_hasMore = sse_decode_bool(deserializer);
+return MessageHistoryPage(messages: var_messages, hasMore: var_hasMore); }
+
+@protected MessageReaction sse_decode_message_reaction(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_emoji = sse_decode_String(deserializer);
+var var_count = sse_decode_u_32(deserializer);
+var var_userIds = sse_decode_list_String(deserializer);
+var var_reactedByMe = sse_decode_bool(deserializer);
+return MessageReaction(emoji: var_emoji, count: var_count, userIds: var_userIds, reactedByMe: var_reactedByMe); }
+
+@protected MessageSearchHit sse_decode_message_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_clientMsgId = sse_decode_String(deserializer);
+var var_conversationId = sse_decode_String(deserializer);
+var var_sendId = sse_decode_String(deserializer);
+var var_contentType = sse_decode_i_32(deserializer);
+var var_sendTime = sse_decode_i_64(deserializer);
+var var_seq = sse_decode_i_64(deserializer);
+var var_snippet = sse_decode_String(deserializer);
+var var_highlights = sse_decode_list_snippet_highlight(deserializer);
+return MessageSearchHit(clientMsgId: var_clientMsgId, conversationId: var_conversationId, sendId: var_sendId, contentType: var_contentType, sendTime: var_sendTime, seq: var_seq, snippet: var_snippet, highlights: var_highlights); }
+
+@protected MessageSearchPage sse_decode_message_search_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_message_search_hit(deserializer);
+var var_hasMore = sse_decode_bool(deserializer);
+return MessageSearchPage(hits: var_hits, hasMore: var_hasMore); }
+
+@protected MessageSearchQuery sse_decode_message_search_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_keyword = sse_decode_String(deserializer);
+var var_conversationId = sse_decode_opt_String(deserializer);
+var var_senderId = sse_decode_opt_String(deserializer);
+var var_contentTypes = sse_decode_list_prim_i_32_strict(deserializer);
+var var_startTime = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_endTime = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_offset = sse_decode_u_32(deserializer);
+var var_limit = sse_decode_u_32(deserializer);
+return MessageSearchQuery(keyword: var_keyword, conversationId: var_conversationId, senderId: var_senderId, contentTypes: var_contentTypes, startTime: var_startTime, endTime: var_endTime, offset: var_offset, limit: var_limit); }
+
+@protected MessageSendStatus sse_decode_message_send_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MessageSendStatus.values[inner]; }
+
+@protected MessageStatusEvent sse_decode_message_status_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outboxId = sse_decode_i_64(deserializer);
+var var_clientMsgId = sse_decode_String(deserializer);
+var var_conversationId = sse_decode_String(deserializer);
+var var_status = sse_decode_message_send_status(deserializer);
+var var_attempts = sse_decode_i_64(deserializer);
+var var_serverMsgId = sse_decode_String(deserializer);
+var var_sendTime = sse_decode_i_64(deserializer);
+var var_error = sse_decode_String(deserializer);
+return MessageStatusEvent(outboxId: var_outboxId, clientMsgId: var_clientMsgId, conversationId: var_conversationId, status: var_status, attempts: var_attempts, serverMsgId: var_serverMsgId, sendTime: var_sendTime, error: var_error); }
+
+@protected OpenImClient? sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Group? sse_decode_opt_box_autoadd_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_group(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LoginSession? sse_decode_opt_box_autoadd_login_session(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_login_session(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PreparedPicture sse_decode_prepared_picture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_source = sse_decode_list_prim_u_8_strict(deserializer);
+var var_big = sse_decode_list_prim_u_8_strict(deserializer);
+var var_snapshot = sse_decode_list_prim_u_8_strict(deserializer);
+var var_pictureElemJson = sse_decode_String(deserializer);
+return PreparedPicture(source: var_source, big: var_big, snapshot: var_snapshot, pictureElemJson: var_pictureElemJson); }
+
+@protected ReactionChange sse_decode_reaction_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conversationId = sse_decode_String(deserializer);
+var var_clientMsgId = sse_decode_String(deserializer);
+var var_reactions = sse_decode_list_message_reaction(deserializer);
+return ReactionChange(conversationId: var_conversationId, clientMsgId: var_clientMsgId, reactions: var_reactions); }
+
+@protected RecvMsgOpt sse_decode_recv_msg_opt(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RecvMsgOpt.values[inner]; }
+
+@protected ReplayError sse_decode_replay_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_timeMs = sse_decode_i_64(deserializer);
+var var_error = sse_decode_String(deserializer);
+return ReplayError(index: var_index, timeMs: var_timeMs, error: var_error); }
+
+@protected ReplayReport sse_decode_replay_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_frames = sse_decode_u_32(deserializer);
+var var_replayed = sse_decode_u_32(deserializer);
+var var_errors = sse_decode_list_replay_error(deserializer);
+return ReplayReport(frames: var_frames, replayed: var_replayed, errors: var_errors); }
+
+@protected SessionChange sse_decode_session_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_sessionId = sse_decode_String(deserializer);
+return SessionChange_Opened(sessionId: var_sessionId);case 1: var var_sessionId = sse_decode_String(deserializer);
+return SessionChange_Closed(sessionId: var_sessionId);case 2: var var_sessionId = sse_decode_opt_String(deserializer);
+return SessionChange_Activated(sessionId: var_sessionId); default: throw UnimplementedError(''); }
+             }
+
+@protected SessionInfo sse_decode_session_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sessionId = sse_decode_String(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_platformId = sse_decode_i_32(deserializer);
+var var_dataDir = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+return SessionInfo(sessionId: var_sessionId, userId: var_userId, platformId: var_platformId, dataDir: var_dataDir, isActive: var_isActive); }
+
+@protected SnippetHighlight sse_decode_snippet_highlight(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return SnippetHighlight(start: var_start, end: var_end); }
+
+@protected SyncStage sse_decode_sync_stage(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SyncStage.values[inner]; }
+
+@protected SyncStatus sse_decode_sync_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_stage = sse_decode_sync_stage(deserializer);
+var var_percent = sse_decode_u_8(deserializer);
+return SyncStatus_Syncing(stage: var_stage, percent: var_percent);case 1: var var_failed = sse_decode_list_sync_stage(deserializer);
+return SyncStatus_Done(failed: var_failed);case 2: return SyncStatus_Interrupted(); default: throw UnimplementedError(''); }
+             }
+
+@protected TokenStatus sse_decode_token_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_expiresAt = sse_decode_i_64(deserializer);
+return TokenStatus_WillExpire(expiresAt: var_expiresAt);case 1: var var_expiresAt = sse_decode_i_64(deserializer);
+return TokenStatus_Refreshed(expiresAt: var_expiresAt);case 2: var var_error = sse_decode_String(deserializer);
+return TokenStatus_RefreshFailed(error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected TypingStatus sse_decode_typing_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_conversationId = sse_decode_String(deserializer);
+var var_userId = sse_decode_String(deserializer);
+var var_typing = sse_decode_bool(deserializer);
+return TypingStatus(conversationId: var_conversationId, userId: var_userId, typing: var_typing); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UserProfile sse_decode_user_profile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_nickname = sse_decode_String(deserializer);
+var var_faceUrl = sse_decode_String(deserializer);
+var var_ex = sse_decode_String(deserializer);
+var var_createTime = sse_decode_i_64(deserializer);
+return UserProfile(userId: var_userId, nickname: var_nickname, faceUrl: var_faceUrl, ex: var_ex, createTime: var_createTime); }
+
+@protected UserStatus sse_decode_user_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_online = sse_decode_bool(deserializer);
+var var_platformIds = sse_decode_list_prim_i_32_strict(deserializer);
+return UserStatus(userId: var_userId, online: var_online, platformIds: var_platformIds); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(self, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OpenImClientImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OpenImClientImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OpenImClientImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as OpenImClientImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_connection_status_Sse(RustStreamSink<ConnectionStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_connection_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_contact_change_Sse(RustStreamSink<ContactChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_contact_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_conversation_change_Sse(RustStreamSink<ConversationChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_conversation_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_group_change_Sse(RustStreamSink<GroupChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_group_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_log_entry_Sse(RustStreamSink<LogEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_log_entry,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_message_status_event_Sse(RustStreamSink<MessageStatusEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_message_status_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_reaction_change_Sse(RustStreamSink<ReactionChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_reaction_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_session_change_Sse(RustStreamSink<SessionChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_session_change,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_sync_status_Sse(RustStreamSink<SyncStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_sync_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_token_status_Sse(RustStreamSink<TokenStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_token_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_typing_status_Sse(RustStreamSink<TypingStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_typing_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_user_status_Sse(RustStreamSink<UserStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_user_status,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_blocked_user(BlockedUser self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.nickname, serializer);
+sse_encode_String(self.faceUrl, serializer);
+sse_encode_i_64(self.createTime, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_group(Group self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_group(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_login_credentials(LoginCredentials self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_login_credentials(self, serializer); }
+
+@protected void sse_encode_box_autoadd_login_session(LoginSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_login_session(self, serializer); }
+
+@protected void sse_encode_box_autoadd_message_search_query(MessageSearchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_message_search_query(self, serializer); }
+
+@protected void sse_encode_chat_message(ChatMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.clientMsgId, serializer);
+sse_encode_String(self.serverMsgId, serializer);
+sse_encode_String(self.conversationId, serializer);
+sse_encode_i_64(self.seq, serializer);
+sse_encode_String(self.sendId, serializer);
+sse_encode_String(self.recvId, serializer);
+sse_encode_String(self.groupId, serializer);
+sse_encode_i_32(self.sessionType, serializer);
+sse_encode_i_32(self.contentType, serializer);
+sse_encode_list_prim_u_8_strict(self.content, serializer);
+sse_encode_opt_String(self.text, serializer);
+sse_encode_i_64(self.sendTime, serializer);
+ }
+
+@protected void sse_encode_connection_status(ConnectionStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ConnectionStatus_Idle(): sse_encode_i_32(0, serializer); case ConnectionStatus_Connecting(): sse_encode_i_32(1, serializer); case ConnectionStatus_Handshaking(): sse_encode_i_32(2, serializer); case ConnectionStatus_Connected(): sse_encode_i_32(3, serializer); case ConnectionStatus_Syncing(): sse_encode_i_32(4, serializer); case ConnectionStatus_Reconnecting(attempt: final attempt,backoffMs: final backoffMs): sse_encode_i_32(5, serializer); sse_encode_u_32(attempt, serializer);
+sse_encode_u_64(backoffMs, serializer);
+case ConnectionStatus_Kicked(): sse_encode_i_32(6, serializer); case ConnectionStatus_TokenExpired(): sse_encode_i_32(7, serializer); case ConnectionStatus_Closed(): sse_encode_i_32(8, serializer);   } }
+
+@protected void sse_encode_contact_change(ContactChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_conversation_change(ConversationChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.conversationId, serializer);
+ }
+
+@protected void sse_encode_conversation_info(ConversationInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.conversationId, serializer);
+sse_encode_i_32(self.conversationType, serializer);
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.groupId, serializer);
+sse_encode_recv_msg_opt(self.recvMsgOpt, serializer);
+sse_encode_bool(self.isPinned, serializer);
+sse_encode_String(self.draftText, serializer);
+sse_encode_i_64(self.draftTextTime, serializer);
+sse_encode_i_64(self.latestMsgTime, serializer);
+sse_encode_opt_String(self.latestMsgClientMsgId, serializer);
+sse_encode_opt_String(self.latestMsgSendId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.latestMsgContentType, serializer);
+sse_encode_opt_String(self.latestMsgText, serializer);
+sse_encode_String(self.ex, serializer);
+ }
+
+@protected void sse_encode_friend(Friend self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.nickname, serializer);
+sse_encode_String(self.faceUrl, serializer);
+sse_encode_String(self.remark, serializer);
+sse_encode_String(self.ex, serializer);
+sse_encode_i_64(self.createTime, serializer);
+ }
+
+@protected void sse_encode_friend_request(FriendRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fromUserId, serializer);
+sse_encode_String(self.fromNickname, serializer);
+sse_encode_String(self.fromFaceUrl, serializer);
+sse_encode_String(self.toUserId, serializer);
+sse_encode_String(self.toNickname, serializer);
+sse_encode_String(self.toFaceUrl, serializer);
+sse_encode_i_32(self.handleResult, serializer);
+sse_encode_String(self.reqMsg, serializer);
+sse_encode_String(self.handleMsg, serializer);
+sse_encode_i_64(self.createTime, serializer);
+sse_encode_i_64(self.handleTime, serializer);
+ }
+
+@protected void sse_encode_group(Group self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.groupId, serializer);
+sse_encode_String(self.groupName, serializer);
+sse_encode_String(self.notification, serializer);
+sse_encode_String(self.introduction, serializer);
+sse_encode_String(self.faceUrl, serializer);
+sse_encode_String(self.ownerUserId, serializer);
+sse_encode_i_32(self.memberCount, serializer);
+sse_encode_i_32(self.status, serializer);
+sse_encode_i_64(self.createTime, serializer);
+ }
+
+@protected void sse_encode_group_change(GroupChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case GroupChange_JoinedGroups(): sse_encode_i_32(0, serializer); case GroupChange_Info(groupId: final groupId): sse_encode_i_32(1, serializer); sse_encode_String(groupId, serializer);
+case GroupChange_Members(groupId: final groupId): sse_encode_i_32(2, serializer); sse_encode_String(groupId, serializer);
+  } }
+
+@protected void sse_encode_group_member(GroupMember self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.groupId, serializer);
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.nickname, serializer);
+sse_encode_String(self.faceUrl, serializer);
+sse_encode_group_role(self.role, serializer);
+sse_encode_i_64(self.joinTime, serializer);
+sse_encode_i_64(self.muteEndTime, serializer);
+ }
+
+@protected void sse_encode_group_role(GroupRole self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_blocked_user(List<BlockedUser> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_blocked_user(item, serializer); } }
+
+@protected void sse_encode_list_chat_message(List<ChatMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_chat_message(item, serializer); } }
+
+@protected void sse_encode_list_conversation_info(List<ConversationInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_conversation_info(item, serializer); } }
+
+@protected void sse_encode_list_friend(List<Friend> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_friend(item, serializer); } }
+
+@protected void sse_encode_list_friend_request(List<FriendRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_friend_request(item, serializer); } }
+
+@protected void sse_encode_list_group(List<Group> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_group(item, serializer); } }
+
+@protected void sse_encode_list_group_member(List<GroupMember> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_group_member(item, serializer); } }
+
+@protected void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_log_field(item, serializer); } }
+
+@protected void sse_encode_list_message_reaction(List<MessageReaction> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_message_reaction(item, serializer); } }
+
+@protected void sse_encode_list_message_search_hit(List<MessageSearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_message_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_replay_error(List<ReplayError> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_replay_error(item, serializer); } }
+
+@protected void sse_encode_list_session_info(List<SessionInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_session_info(item, serializer); } }
+
+@protected void sse_encode_list_snippet_highlight(List<SnippetHighlight> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snippet_highlight(item, serializer); } }
+
+@protected void sse_encode_list_sync_stage(List<SyncStage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sync_stage(item, serializer); } }
+
+@protected void sse_encode_list_user_profile(List<UserProfile> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_user_profile(item, serializer); } }
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.timeMs, serializer);
+sse_encode_log_level(self.level, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_list_log_field(self.fields, serializer);
+sse_encode_String(self.spans, serializer);
+ }
+
+@protected void sse_encode_log_field(LogField self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.value, serializer);
+ }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_login_credentials(LoginCredentials self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case LoginCredentials_AppServer(url: final url,account: final account,password: final password): sse_encode_i_32(0, serializer); sse_encode_String(url, serializer);
+sse_encode_String(account, serializer);
+sse_encode_String(password, serializer);
+case LoginCredentials_AdminSecret(apiUrl: final apiUrl,secret: final secret,userId: final userId,adminUserId: final adminUserId): sse_encode_i_32(1, serializer); sse_encode_String(apiUrl, serializer);
+sse_encode_String(secret, serializer);
+sse_encode_String(userId, serializer);
+sse_encode_String(adminUserId, serializer);
+  } }
+
+@protected void sse_encode_login_session(LoginSession self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.token, serializer);
+sse_encode_i_32(self.platformId, serializer);
+sse_encode_i_64(self.expiresAt, serializer);
+ }
+
+@protected void sse_encode_message_history_page(MessageHistoryPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_chat_message(self.messages, serializer);
+sse_encode_bool(self.hasMore, serializer);
+ }
+
+@protected void sse_encode_message_reaction(MessageReaction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.emoji, serializer);
+sse_encode_u_32(self.count, serializer);
+sse_encode_list_String(self.userIds, serializer);
+sse_encode_bool(self.reactedByMe, serializer);
+ }
+
+@protected void sse_encode_message_search_hit(MessageSearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.clientMsgId, serializer);
+sse_encode_String(self.conversationId, serializer);
+sse_encode_String(self.sendId, serializer);
+sse_encode_i_32(self.contentType, serializer);
+sse_encode_i_64(self.sendTime, serializer);
+sse_encode_i_64(self.seq, serializer);
+sse_encode_String(self.snippet, serializer);
+sse_encode_list_snippet_highlight(self.highlights, serializer);
+ }
+
+@protected void sse_encode_message_search_page(MessageSearchPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_message_search_hit(self.hits, serializer);
+sse_encode_bool(self.hasMore, serializer);
+ }
+
+@protected void sse_encode_message_search_query(MessageSearchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.keyword, serializer);
+sse_encode_opt_String(self.conversationId, serializer);
+sse_encode_opt_String(self.senderId, serializer);
+sse_encode_list_prim_i_32_strict(self.contentTypes, serializer);
+sse_encode_opt_box_autoadd_i_64(self.startTime, serializer);
+sse_encode_opt_box_autoadd_i_64(self.endTime, serializer);
+sse_encode_u_32(self.offset, serializer);
+sse_encode_u_32(self.limit, serializer);
+ }
+
+@protected void sse_encode_message_send_status(MessageSendStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_message_status_event(MessageStatusEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.outboxId, serializer);
+sse_encode_String(self.clientMsgId, serializer);
+sse_encode_String(self.conversationId, serializer);
+sse_encode_message_send_status(self.status, serializer);
+sse_encode_i_64(self.attempts, serializer);
+sse_encode_String(self.serverMsgId, serializer);
+sse_encode_i_64(self.sendTime, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_group(Group? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_group(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_login_session(LoginSession? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_login_session(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_prepared_picture(PreparedPicture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.source, serializer);
+sse_encode_list_prim_u_8_strict(self.big, serializer);
+sse_encode_list_prim_u_8_strict(self.snapshot, serializer);
+sse_encode_String(self.pictureElemJson, serializer);
+ }
+
+@protected void sse_encode_reaction_change(ReactionChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.conversationId, serializer);
+sse_encode_String(self.clientMsgId, serializer);
+sse_encode_list_message_reaction(self.reactions, serializer);
+ }
+
+@protected void sse_encode_recv_msg_opt(RecvMsgOpt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_replay_error(ReplayError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_i_64(self.timeMs, serializer);
+sse_encode_String(self.error, serializer);
+ }
+
+@protected void sse_encode_replay_report(ReplayReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.frames, serializer);
+sse_encode_u_32(self.replayed, serializer);
+sse_encode_list_replay_error(self.errors, serializer);
+ }
+
+@protected void sse_encode_session_change(SessionChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SessionChange_Opened(sessionId: final sessionId): sse_encode_i_32(0, serializer); sse_encode_String(sessionId, serializer);
+case SessionChange_Closed(sessionId: final sessionId): sse_encode_i_32(1, serializer); sse_encode_String(sessionId, serializer);
+case SessionChange_Activated(sessionId: final sessionId): sse_encode_i_32(2, serializer); sse_encode_opt_String(sessionId, serializer);
+  } }
+
+@protected void sse_encode_session_info(SessionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.sessionId, serializer);
+sse_encode_String(self.userId, serializer);
+sse_encode_i_32(self.platformId, serializer);
+sse_encode_opt_String(self.dataDir, serializer);
+sse_encode_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_snippet_highlight(SnippetHighlight self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_sync_stage(SyncStage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_sync_status(SyncStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SyncStatus_Syncing(stage: final stage,percent: final percent): sse_encode_i_32(0, serializer); sse_encode_sync_stage(stage, serializer);
+sse_encode_u_8(percent, serializer);
+case SyncStatus_Done(failed: final failed): sse_encode_i_32(1, serializer); sse_encode_list_sync_stage(failed, serializer);
+case SyncStatus_Interrupted(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_token_status(TokenStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case TokenStatus_WillExpire(expiresAt: final expiresAt): sse_encode_i_32(0, serializer); sse_encode_i_64(expiresAt, serializer);
+case TokenStatus_Refreshed(expiresAt: final expiresAt): sse_encode_i_32(1, serializer); sse_encode_i_64(expiresAt, serializer);
+case TokenStatus_RefreshFailed(error: final error): sse_encode_i_32(2, serializer); sse_encode_String(error, serializer);
+  } }
+
+@protected void sse_encode_typing_status(TypingStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.conversationId, serializer);
+sse_encode_String(self.userId, serializer);
+sse_encode_bool(self.typing, serializer);
+ }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_user_profile(UserProfile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_String(self.nickname, serializer);
+sse_encode_String(self.faceUrl, serializer);
+sse_encode_String(self.ex, serializer);
+sse_encode_i_64(self.createTime, serializer);
+ }
+
+@protected void sse_encode_user_status(UserStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_bool(self.online, serializer);
+sse_encode_list_prim_i_32_strict(self.platformIds, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+                }
+                
+
+            @sealed class OpenImClientImpl extends RustOpaque implements OpenImClient {
+                // Not to be used by end users
+                OpenImClientImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                OpenImClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_OpenImClient,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_OpenImClient,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_OpenImClientPtr,
+                );
+
+                /// 同意好友申请
+ Future<void>  acceptFriendRequest({required String fromUserId , required String handleMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientAcceptFriendRequest(that: this, fromUserId: fromUserId, handleMsg: handleMsg);
+
+
+/// 同意入群申请（群主或管理员）
+ Future<void>  acceptGroupApplication({required String groupId , required String fromUserId , required String handledMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientAcceptGroupApplication(that: this, groupId: groupId, fromUserId: fromUserId, handledMsg: handledMsg);
+
+
+/// 发送好友申请
+ Future<void>  addFriend({required String userId , required String reqMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientAddFriend(that: this, userId: userId, reqMsg: reqMsg);
+
+
+/// 对消息添加表情回应
+ Future<void>  addReaction({required String conversationId , required String clientMsgId , required String emoji })=>RustLib.instance.api.crateApiOpenimClientOpenImClientAddReaction(that: this, conversationId: conversationId, clientMsgId: clientMsgId, emoji: emoji);
+
+
+/// 加入黑名单
+ Future<void>  addToBlacklist({required String userId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientAddToBlacklist(that: this, userId: userId);
+
+
+ String get apiUrl=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorGetApiUrl(that: this, );
+
+
+ int get platformId=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorGetPlatformId(that: this, );
+
+
+ String get userId=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorGetUserId(that: this, );
+
+
+ String get wsUrl=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorGetWsUrl(that: this, );
+
+
+  set apiUrl(String apiUrl)=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorSetApiUrl(that: this, apiUrl: apiUrl);
+
+
+  set platformId(int platformId)=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorSetPlatformId(that: this, platformId: platformId);
+
+
+  set userId(String userId)=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorSetUserId(that: this, userId: userId);
+
+
+  set wsUrl(String wsUrl)=>RustLib.instance.api.crateApiOpenimClientOpenImClientAutoAccessorSetWsUrl(that: this, wsUrl: wsUrl);
+
+
+/// 清空会话中的消息（服务器和本地）
+ Future<void>  clearConversationMessages({required String conversationId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientClearConversationMessages(that: this, conversationId: conversationId);
+
+
+/// 连接并运行客户端，直到主动断开、被踢下线或 token 失效
+///
+/// 连接中断后按 1s、2s、4s…（最长 30s）的间隔重连；token 即将过期或握手时被拒绝会调用刷新回调，
+/// 换成新 token 后重连。发件箱中的消息保留到重连后发送。每次连接成功后自动同步（见 [`crate::openim::sync`]）。
+ Future<void>  connectAndRun()=>RustLib.instance.api.crateApiOpenimClientOpenImClientConnectAndRun(that: this, );
+
+
+/// 当前连接状态
+ Future<ConnectionStatus>  connectionState()=>RustLib.instance.api.crateApiOpenimClientOpenImClientConnectionState(that: this, );
+
+
+/// 订阅连接状态（先推送当前状态），用于显示“连接中…”等提示
+ Stream<ConnectionStatus>  connectionStateStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientConnectionStateStream(that: this, );
+
+
+/// 订阅联系人缓存变化
+ Stream<ContactChange>  contactChangeStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientContactChangeStream(that: this, );
+
+
+/// 订阅会话变化
+ Stream<ConversationChange>  conversationChangeStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientConversationChangeStream(that: this, );
+
+
+/// 创建群，自己为群主
+ Future<Group>  createGroup({required String groupName , required List<String> memberUserIds })=>RustLib.instance.api.crateApiOpenimClientOpenImClientCreateGroup(that: this, groupName: groupName, memberUserIds: memberUserIds);
+
+
+/// 删除会话及其消息
+ Future<void>  deleteConversation({required String conversationId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientDeleteConversation(that: this, conversationId: conversationId);
+
+
+/// 删除好友
+ Future<void>  deleteFriend({required String userId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientDeleteFriend(that: this, userId: userId);
+
+
+/// 断开连接：发送 close 帧，取消后台同步任务，等待响应的请求以取消错误结束，`connect_and_run` 随后返回
+///
+/// 发件箱中未确认的消息保留在数据库中，下次连接时重新发送。
+ Future<void>  disconnect()=>RustLib.instance.api.crateApiOpenimClientOpenImClientDisconnect(that: this, );
+
+
+/// 解散群（仅群主）
+ Future<void>  dismissGroup({required String groupId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientDismissGroup(that: this, groupId: groupId);
+
+
+/// 黑名单（本地缓存）
+ Future<List<BlockedUser>>  getBlacklist()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetBlacklist(that: this, );
+
+
+/// 会话列表（本地），置顶在前，其余按最新消息或草稿时间倒序
+ Future<List<ConversationInfo>>  getConversationList()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetConversationList(that: this, );
+
+
+/// 好友申请（本地缓存）
+ Future<List<FriendRequest>>  getFriendRequests()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetFriendRequests(that: this, );
+
+
+/// 好友列表（本地缓存）
+ Future<List<Friend>>  getFriends()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetFriends(that: this, );
+
+
+/// 群资料（本地缓存）
+ Future<Group?>  getGroup({required String groupId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetGroup(that: this, groupId: groupId);
+
+
+/// 分页获取群成员（本地缓存，群主、管理员在前）
+ Future<List<GroupMember>>  getGroupMembers({required String groupId , required int offset , required int limit })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetGroupMembers(that: this, groupId: groupId, offset: offset, limit: limit);
+
+
+/// 加载会话的历史消息：before_seq 之前（不含）的最多 limit 条，before_seq 为 0 时从最新的消息开始
+///
+/// 优先读取本地，缺失的消息自动从服务器拉取；离线或拉取失败时只返回本地已有的消息。
+ Future<MessageHistoryPage>  getHistory({required String conversationId , required PlatformInt64 beforeSeq , required int limit })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetHistory(that: this, conversationId: conversationId, beforeSeq: beforeSeq, limit: limit);
+
+
+/// 已加入的群（本地缓存）
+ Future<List<Group>>  getJoinedGroups()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetJoinedGroups(that: this, );
+
+
+/// 消息的表情回应（按每个表情最早的回应时间排序）
+ Future<List<MessageReaction>>  getMessageReactions({required String clientMsgId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetMessageReactions(that: this, clientMsgId: clientMsgId);
+
+
+/// 获取自己的资料
+ Future<UserProfile>  getSelfInfo()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetSelfInfo(that: this, );
+
+
+/// 已订阅用户的当前在线状态
+ Future<UserStatus>  getUserStatus({required String userId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetUserStatus(that: this, userId: userId);
+
+
+/// 批量获取用户资料（优先使用缓存，force_refresh 为 true 时从服务器获取）
+ Future<List<UserProfile>>  getUsersInfo({required List<String> userIds , required bool forceRefresh })=>RustLib.instance.api.crateApiOpenimClientOpenImClientGetUsersInfo(that: this, userIds: userIds, forceRefresh: forceRefresh);
+
+
+/// 订阅群缓存变化
+ Stream<GroupChange>  groupChangeStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientGroupChangeStream(that: this, );
+
+
+/// 从会话列表中隐藏（只影响本地，收到新消息后重新显示）
+ Future<void>  hideConversation({required String conversationId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientHideConversation(that: this, conversationId: conversationId);
+
+
+/// 邀请入群
+ Future<void>  inviteToGroup({required String groupId , required List<String> userIds , required String reason })=>RustLib.instance.api.crateApiOpenimClientOpenImClientInviteToGroup(that: this, groupId: groupId, userIds: userIds, reason: reason);
+
+
+/// 申请入群（通过搜索），不需要验证的群直接加入
+ Future<void>  joinGroup({required String groupId , required String reqMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientJoinGroup(that: this, groupId: groupId, reqMsg: reqMsg);
+
+
+/// 踢出群
+ Future<void>  kickGroupMembers({required String groupId , required List<String> userIds , required String reason })=>RustLib.instance.api.crateApiOpenimClientOpenImClientKickGroupMembers(that: this, groupId: groupId, userIds: userIds, reason: reason);
+
+
+/// 退出登录：通知服务器后断开连接并关闭本地数据库，之后客户端不可再使用
+///
+/// 保存的登录 token 由 auth 模块的 `logout` 清除。
+ Future<void>  logout()=>RustLib.instance.api.crateApiOpenimClientOpenImClientLogout(that: this, );
+
+
+/// 订阅消息发送状态变化
+ Stream<MessageStatusEvent>  messageStatusStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientMessageStatusStream(that: this, );
+
+
+/// 全员禁言 / 解除全员禁言
+ Future<void>  muteGroup({required String groupId , required bool muted })=>RustLib.instance.api.crateApiOpenimClientOpenImClientMuteGroup(that: this, groupId: groupId, muted: muted);
+
+
+/// 禁言群成员，muted_seconds 为 0 时解除禁言
+ Future<void>  muteGroupMember({required String groupId , required String userId , required int mutedSeconds })=>RustLib.instance.api.crateApiOpenimClientOpenImClientMuteGroupMember(that: this, groupId: groupId, userId: userId, mutedSeconds: mutedSeconds);
+
+
+/// 退出群
+ Future<void>  quitGroup({required String groupId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientQuitGroup(that: this, groupId: groupId);
+
+
+/// 订阅表情回应变化
+ Stream<ReactionChange>  reactionChangeStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientReactionChangeStream(that: this, );
+
+
+/// 拒绝好友申请
+ Future<void>  rejectFriendRequest({required String fromUserId , required String handleMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientRejectFriendRequest(that: this, fromUserId: fromUserId, handleMsg: handleMsg);
+
+
+/// 拒绝入群申请（群主或管理员）
+ Future<void>  rejectGroupApplication({required String groupId , required String fromUserId , required String handledMsg })=>RustLib.instance.api.crateApiOpenimClientOpenImClientRejectGroupApplication(that: this, groupId: groupId, fromUserId: fromUserId, handledMsg: handledMsg);
+
+
+/// 移出黑名单
+ Future<void>  removeFromBlacklist({required String userId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientRemoveFromBlacklist(that: this, userId: userId);
+
+
+/// 取消自己对消息的表情回应
+ Future<void>  removeReaction({required String conversationId , required String clientMsgId , required String emoji })=>RustLib.instance.api.crateApiOpenimClientOpenImClientRemoveReaction(that: this, conversationId: conversationId, clientMsgId: clientMsgId, emoji: emoji);
+
+
+/// 重新发送已失败的消息（对方收到的顺序在失败期间发出的消息之后）
+ Future<void>  retryMessage({required PlatformInt64 outboxId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientRetryMessage(that: this, outboxId: outboxId);
+
+
+/// 搜索本地消息（按发送时间倒序分页，摘要中标出命中位置）
+ Future<MessageSearchPage>  searchMessages({required MessageSearchQuery query })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSearchMessages(that: this, query: query);
+
+
+/// 发送群文本消息，返回发件箱 ID
+ Future<PlatformInt64>  sendGroupTextMessage({required String groupId , required String text })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSendGroupTextMessage(that: this, groupId: groupId, text: text);
+
+
+/// 发送文本消息：先写入发件箱，连接可用时按顺序发送，返回发件箱 ID
+ Future<PlatformInt64>  sendTextMessage({required String recvId , required String text })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSendTextMessage(that: this, recvId: recvId, text: text);
+
+
+/// 在会话中发送正在输入 / 停止输入状态（单聊或群聊），按会话限流，不保证送达
+ Future<void>  sendTyping({required String conversationId , required bool typing })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSendTyping(that: this, conversationId: conversationId, typing: typing);
+
+
+/// 切换前后台状态，进入后台后服务器改为离线推送
+ Future<void>  setAppBackground({required bool background })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetAppBackground(that: this, background: background);
+
+
+/// 置顶 / 取消置顶
+ Future<void>  setConversationPinned({required String conversationId , required bool pinned })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetConversationPinned(that: this, conversationId: conversationId, pinned: pinned);
+
+
+/// 保存草稿（只保存在本地，空字符串表示清除）
+ Future<void>  setDraft({required String conversationId , required String draft })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetDraft(that: this, conversationId: conversationId, draft: draft);
+
+
+/// 设置好友备注（空字符串表示清除）
+ Future<void>  setFriendRemark({required String userId , required String remark })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetFriendRemark(that: this, userId: userId, remark: remark);
+
+
+/// 设置或取消管理员
+ Future<void>  setGroupAdmin({required String groupId , required String userId , required bool admin })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetGroupAdmin(that: this, groupId: groupId, userId: userId, admin: admin);
+
+
+/// 设置消息接收选项（免打扰）
+ Future<void>  setRecvMsgOpt({required String conversationId , required RecvMsgOpt opt })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetRecvMsgOpt(that: this, conversationId: conversationId, opt: opt);
+
+
+/// 修改自己的资料，None 表示不修改
+ Future<UserProfile>  setSelfInfo({String? nickname , String? faceUrl , String? ex })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetSelfInfo(that: this, nickname: nickname, faceUrl: faceUrl, ex: ex);
+
+
+/// 连接（包括重连）后是否自动同步资料、联系人、群、会话和缺失的消息，默认开启
+ Future<void>  setSyncOnConnect({required bool enabled })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetSyncOnConnect(that: this, enabled: enabled);
+
+
+/// 设置 token 刷新回调（返回新的 token），token 即将过期或被服务器拒绝时调用，之后自动重连
+ Future<void>  setTokenRefresher({required FutureOr<String> Function() callback })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSetTokenRefresher(that: this, callback: callback);
+
+
+/// 开始录制协议帧到抓包文件（已存在时追加），用于问题反馈
+ Future<void>  startFrameCapture({required String path })=>RustLib.instance.api.crateApiOpenimClientOpenImClientStartFrameCapture(that: this, path: path);
+
+
+/// 停止录制
+ Future<void>  stopFrameCapture()=>RustLib.instance.api.crateApiOpenimClientOpenImClientStopFrameCapture(that: this, );
+
+
+/// 订阅用户在线状态（断线重连后自动重新订阅）
+ Future<void>  subscribeUsersStatus({required List<String> userIds })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSubscribeUsersStatus(that: this, userIds: userIds);
+
+
+/// 从服务器同步好友、黑名单和好友申请到本地缓存
+ Future<void>  syncContacts()=>RustLib.instance.api.crateApiOpenimClientOpenImClientSyncContacts(that: this, );
+
+
+/// 从服务器同步置顶、免打扰等会话设置
+ Future<void>  syncConversations()=>RustLib.instance.api.crateApiOpenimClientOpenImClientSyncConversations(that: this, );
+
+
+/// 从服务器同步群成员
+ Future<void>  syncGroupMembers({required String groupId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientSyncGroupMembers(that: this, groupId: groupId);
+
+
+/// 从服务器同步已加入的群
+ Future<void>  syncJoinedGroups()=>RustLib.instance.api.crateApiOpenimClientOpenImClientSyncJoinedGroups(that: this, );
+
+
+/// 订阅同步进度（先推送最近一次的状态，尚未同步过时不推送）
+ Stream<SyncStatus>  syncStatusStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientSyncStatusStream(that: this, );
+
+
+/// 当前使用的 token
+ Future<String>  token()=>RustLib.instance.api.crateApiOpenimClientOpenImClientToken(that: this, );
+
+
+/// 订阅 token 状态变化
+ Stream<TokenStatus>  tokenStatusStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientTokenStatusStream(that: this, );
+
+
+/// 转让群主
+ Future<void>  transferGroupOwner({required String groupId , required String newOwnerUserId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientTransferGroupOwner(that: this, groupId: groupId, newOwnerUserId: newOwnerUserId);
+
+
+/// 订阅某个会话中对方的正在输入状态（超时自动变为停止输入）
+ Stream<TypingStatus>  typingStream({required String conversationId })=>RustLib.instance.api.crateApiOpenimClientOpenImClientTypingStream(that: this, conversationId: conversationId);
+
+
+/// 取消订阅用户在线状态
+ Future<void>  unsubscribeUsersStatus({required List<String> userIds })=>RustLib.instance.api.crateApiOpenimClientOpenImClientUnsubscribeUsersStatus(that: this, userIds: userIds);
+
+
+/// 订阅在线状态变化
+ Stream<UserStatus>  userStatusStream()=>RustLib.instance.api.crateApiOpenimClientOpenImClientUserStatusStream(that: this, );
+
+
+            }
\ No newline at end of file
@@ -3,84 +3,665 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/auth.dart';
+import 'api/capture.dart';
+import 'api/contacts.dart';
+import 'api/conversations.dart';
+import 'api/groups.dart';
+import 'api/logging.dart';
+import 'api/messages.dart';
+import 'api/openim_client.dart';
+import 'api/picture.dart';
+import 'api/reactions.dart';
+import 'api/sessions.dart';
 import 'api/simple.dart';
+import 'api/sync.dart';
+import 'api/users.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_OpenImClientPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected OpenImClient dco_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected OpenImClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected OpenImClient dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected OpenImClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected FutureOr<String?> Function(String) dco_decode_DartFn_Inputs_String_Output_opt_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, String) dco_decode_DartFn_Inputs_String_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<String> Function() dco_decode_DartFn_Inputs__Output_String_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
+@protected OpenImClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected RustStreamSink<ConnectionStatus> dco_decode_StreamSink_connection_status_Sse(dynamic raw);
+
+@protected RustStreamSink<ContactChange> dco_decode_StreamSink_contact_change_Sse(dynamic raw);
+
+@protected RustStreamSink<ConversationChange> dco_decode_StreamSink_conversation_change_Sse(dynamic raw);
+
+@protected RustStreamSink<GroupChange> dco_decode_StreamSink_group_change_Sse(dynamic raw);
+
+@protected RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
+@protected RustStreamSink<MessageStatusEvent> dco_decode_StreamSink_message_status_event_Sse(dynamic raw);
+
+@protected RustStreamSink<ReactionChange> dco_decode_StreamSink_reaction_change_Sse(dynamic raw);
+
+@protected RustStreamSink<SessionChange> dco_decode_StreamSink_session_change_Sse(dynamic raw);
+
+@protected RustStreamSink<SyncStatus> dco_decode_StreamSink_sync_status_Sse(dynamic raw);
+
+@protected RustStreamSink<TokenStatus> dco_decode_StreamSink_token_status_Sse(dynamic raw);
+
+@protected RustStreamSink<TypingStatus> dco_decode_StreamSink_typing_status_Sse(dynamic raw);
+
+@protected RustStreamSink<UserStatus> dco_decode_StreamSink_user_status_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected BlockedUser dco_decode_blocked_user(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected Group dco_decode_box_autoadd_group(dynamic raw);
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected LoginCredentials dco_decode_box_autoadd_login_credentials(dynamic raw);
+
+@protected LoginSession dco_decode_box_autoadd_login_session(dynamic raw);
+
+@protected MessageSearchQuery dco_decode_box_autoadd_message_search_query(dynamic raw);
+
+@protected ChatMessage dco_decode_chat_message(dynamic raw);
+
+@protected ConnectionStatus dco_decode_connection_status(dynamic raw);
+
+@protected ContactChange dco_decode_contact_change(dynamic raw);
+
+@protected ConversationChange dco_decode_conversation_change(dynamic raw);
+
+@protected ConversationInfo dco_decode_conversation_info(dynamic raw);
+
+@protected Friend dco_decode_friend(dynamic raw);
+
+@protected FriendRequest dco_decode_friend_request(dynamic raw);
+
+@protected Group dco_decode_group(dynamic raw);
+
+@protected GroupChange dco_decode_group_change(dynamic raw);
+
+@protected GroupMember dco_decode_group_member(dynamic raw);
+
+@protected GroupRole dco_decode_group_role(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BlockedUser> dco_decode_list_blocked_user(dynamic raw);
+
+@protected List<ChatMessage> dco_decode_list_chat_message(dynamic raw);
+
+@protected List<ConversationInfo> dco_decode_list_conversation_info(dynamic raw);
+
+@protected List<Friend> dco_decode_list_friend(dynamic raw);
+
+@protected List<FriendRequest> dco_decode_list_friend_request(dynamic raw);
+
+@protected List<Group> dco_decode_list_group(dynamic raw);
+
+@protected List<GroupMember> dco_decode_list_group_member(dynamic raw);
+
+@protected List<LogField> dco_decode_list_log_field(dynamic raw);
+
+@protected List<MessageReaction> dco_decode_list_message_reaction(dynamic raw);
+
+@protected List<MessageSearchHit> dco_decode_list_message_search_hit(dynamic raw);
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<ReplayError> dco_decode_list_replay_error(dynamic raw);
+
+@protected List<SessionInfo> dco_decode_list_session_info(dynamic raw);
+
+@protected List<SnippetHighlight> dco_decode_list_snippet_highlight(dynamic raw);
+
+@protected List<SyncStage> dco_decode_list_sync_stage(dynamic raw);
+
+@protected List<UserProfile> dco_decode_list_user_profile(dynamic raw);
+
+@protected LogEntry dco_decode_log_entry(dynamic raw);
+
+@protected LogField dco_decode_log_field(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected LoginCredentials dco_decode_login_credentials(dynamic raw);
+
+@protected LoginSession dco_decode_login_session(dynamic raw);
+
+@protected MessageHistoryPage dco_decode_message_history_page(dynamic raw);
+
+@protected MessageReaction dco_decode_message_reaction(dynamic raw);
+
+@protected MessageSearchHit dco_decode_message_search_hit(dynamic raw);
+
+@protected MessageSearchPage dco_decode_message_search_page(dynamic raw);
+
+@protected MessageSearchQuery dco_decode_message_search_query(dynamic raw);
+
+@protected MessageSendStatus dco_decode_message_send_status(dynamic raw);
+
+@protected MessageStatusEvent dco_decode_message_status_event(dynamic raw);
+
+@protected OpenImClient? dco_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected Group? dco_decode_opt_box_autoadd_group(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected LoginSession? dco_decode_opt_box_autoadd_login_session(dynamic raw);
+
+@protected PreparedPicture dco_decode_prepared_picture(dynamic raw);
+
+@protected ReactionChange dco_decode_reaction_change(dynamic raw);
+
+@protected RecvMsgOpt dco_decode_recv_msg_opt(dynamic raw);
+
+@protected ReplayError dco_decode_replay_error(dynamic raw);
+
+@protected ReplayReport dco_decode_replay_report(dynamic raw);
+
+@protected SessionChange dco_decode_session_change(dynamic raw);
+
+@protected SessionInfo dco_decode_session_info(dynamic raw);
+
+@protected SnippetHighlight dco_decode_snippet_highlight(dynamic raw);
+
+@protected SyncStage dco_decode_sync_stage(dynamic raw);
+
+@protected SyncStatus dco_decode_sync_status(dynamic raw);
+
+@protected TokenStatus dco_decode_token_status(dynamic raw);
+
+@protected TypingStatus dco_decode_typing_status(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UserProfile dco_decode_user_profile(dynamic raw);
+
+@protected UserStatus dco_decode_user_status(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected OpenImClient sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected OpenImClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected OpenImClient sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected OpenImClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected OpenImClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected RustStreamSink<ConnectionStatus> sse_decode_StreamSink_connection_status_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ContactChange> sse_decode_StreamSink_contact_change_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ConversationChange> sse_decode_StreamSink_conversation_change_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<GroupChange> sse_decode_StreamSink_group_change_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<MessageStatusEvent> sse_decode_StreamSink_message_status_event_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ReactionChange> sse_decode_StreamSink_reaction_change_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SessionChange> sse_decode_StreamSink_session_change_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<SyncStatus> sse_decode_StreamSink_sync_status_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<TokenStatus> sse_decode_StreamSink_token_status_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<TypingStatus> sse_decode_StreamSink_typing_status_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<UserStatus> sse_decode_StreamSink_user_status_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected BlockedUser sse_decode_blocked_user(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected Group sse_decode_box_autoadd_group(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LoginCredentials sse_decode_box_autoadd_login_credentials(SseDeserializer deserializer);
+
+@protected LoginSession sse_decode_box_autoadd_login_session(SseDeserializer deserializer);
+
+@protected MessageSearchQuery sse_decode_box_autoadd_message_search_query(SseDeserializer deserializer);
+
+@protected ChatMessage sse_decode_chat_message(SseDeserializer deserializer);
+
+@protected ConnectionStatus sse_decode_connection_status(SseDeserializer deserializer);
+
+@protected ContactChange sse_decode_contact_change(SseDeserializer deserializer);
+
+@protected ConversationChange sse_decode_conversation_change(SseDeserializer deserializer);
+
+@protected ConversationInfo sse_decode_conversation_info(SseDeserializer deserializer);
+
+@protected Friend sse_decode_friend(SseDeserializer deserializer);
+
+@protected FriendRequest sse_decode_friend_request(SseDeserializer deserializer);
+
+@protected Group sse_decode_group(SseDeserializer deserializer);
+
+@protected GroupChange sse_decode_group_change(SseDeserializer deserializer);
+
+@protected GroupMember sse_decode_group_member(SseDeserializer deserializer);
+
+@protected GroupRole sse_decode_group_role(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected List<BlockedUser> sse_decode_list_blocked_user(SseDeserializer deserializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected List<ChatMessage> sse_decode_list_chat_message(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected List<ConversationInfo> sse_decode_list_conversation_info(SseDeserializer deserializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected List<Friend> sse_decode_list_friend(SseDeserializer deserializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected List<FriendRequest> sse_decode_list_friend_request(SseDeserializer deserializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected List<Group> sse_decode_list_group(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected List<GroupMember> sse_decode_list_group_member(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected List<LogField> sse_decode_list_log_field(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<MessageReaction> sse_decode_list_message_reaction(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected List<MessageSearchHit> sse_decode_list_message_search_hit(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<ReplayError> sse_decode_list_replay_error(SseDeserializer deserializer);
+
+@protected List<SessionInfo> sse_decode_list_session_info(SseDeserializer deserializer);
+
+@protected List<SnippetHighlight> sse_decode_list_snippet_highlight(SseDeserializer deserializer);
+
+@protected List<SyncStage> sse_decode_list_sync_stage(SseDeserializer deserializer);
+
+@protected List<UserProfile> sse_decode_list_user_profile(SseDeserializer deserializer);
+
+@protected LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+@protected LogField sse_decode_log_field(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected LoginCredentials sse_decode_login_credentials(SseDeserializer deserializer);
+
+@protected LoginSession sse_decode_login_session(SseDeserializer deserializer);
+
+@protected MessageHistoryPage sse_decode_message_history_page(SseDeserializer deserializer);
+
+@protected MessageReaction sse_decode_message_reaction(SseDeserializer deserializer);
+
+@protected MessageSearchHit sse_decode_message_search_hit(SseDeserializer deserializer);
+
+@protected MessageSearchPage sse_decode_message_search_page(SseDeserializer deserializer);
+
+@protected MessageSearchQuery sse_decode_message_search_query(SseDeserializer deserializer);
+
+@protected MessageSendStatus sse_decode_message_send_status(SseDeserializer deserializer);
+
+@protected MessageStatusEvent sse_decode_message_status_event(SseDeserializer deserializer);
+
+@protected OpenImClient? sse_decode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected Group? sse_decode_opt_box_autoadd_group(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected LoginSession? sse_decode_opt_box_autoadd_login_session(SseDeserializer deserializer);
+
+@protected PreparedPicture sse_decode_prepared_picture(SseDeserializer deserializer);
+
+@protected ReactionChange sse_decode_reaction_change(SseDeserializer deserializer);
+
+@protected RecvMsgOpt sse_decode_recv_msg_opt(SseDeserializer deserializer);
+
+@protected ReplayError sse_decode_replay_error(SseDeserializer deserializer);
+
+@protected ReplayReport sse_decode_replay_report(SseDeserializer deserializer);
+
+@protected SessionChange sse_decode_session_change(SseDeserializer deserializer);
+
+@protected SessionInfo sse_decode_session_info(SseDeserializer deserializer);
+
+@protected SnippetHighlight sse_decode_snippet_highlight(SseDeserializer deserializer);
+
+@protected SyncStage sse_decode_sync_stage(SseDeserializer deserializer);
+
+@protected SyncStatus sse_decode_sync_status(SseDeserializer deserializer);
+
+@protected TokenStatus sse_decode_token_status(SseDeserializer deserializer);
+
+@protected TypingStatus sse_decode_typing_status(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UserProfile sse_decode_user_profile(SseDeserializer deserializer);
+
+@protected UserStatus sse_decode_user_status(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_opt_String_AnyhowException(FutureOr<String?> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_String_AnyhowException(FutureOr<String> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_connection_status_Sse(RustStreamSink<ConnectionStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_contact_change_Sse(RustStreamSink<ContactChange> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_conversation_change_Sse(RustStreamSink<ConversationChange> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_group_change_Sse(RustStreamSink<GroupChange> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_log_entry_Sse(RustStreamSink<LogEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_message_status_event_Sse(RustStreamSink<MessageStatusEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_reaction_change_Sse(RustStreamSink<ReactionChange> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_session_change_Sse(RustStreamSink<SessionChange> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_sync_status_Sse(RustStreamSink<SyncStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_token_status_Sse(RustStreamSink<TokenStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_typing_status_Sse(RustStreamSink<TypingStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_user_status_Sse(RustStreamSink<UserStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_blocked_user(BlockedUser self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_group(Group self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_login_credentials(LoginCredentials self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_login_session(LoginSession self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_message_search_query(MessageSearchQuery self, SseSerializer serializer);
+
+@protected void sse_encode_chat_message(ChatMessage self, SseSerializer serializer);
+
+@protected void sse_encode_connection_status(ConnectionStatus self, SseSerializer serializer);
+
+@protected void sse_encode_contact_change(ContactChange self, SseSerializer serializer);
+
+@protected void sse_encode_conversation_change(ConversationChange self, SseSerializer serializer);
+
+@protected void sse_encode_conversation_info(ConversationInfo self, SseSerializer serializer);
+
+@protected void sse_encode_friend(Friend self, SseSerializer serializer);
+
+@protected void sse_encode_friend_request(FriendRequest self, SseSerializer serializer);
+
+@protected void sse_encode_group(Group self, SseSerializer serializer);
+
+@protected void sse_encode_group_change(GroupChange self, SseSerializer serializer);
+
+@protected void sse_encode_group_member(GroupMember self, SseSerializer serializer);
+
+@protected void sse_encode_group_role(GroupRole self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_blocked_user(List<BlockedUser> self, SseSerializer serializer);
+
+@protected void sse_encode_list_chat_message(List<ChatMessage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_conversation_info(List<ConversationInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_friend(List<Friend> self, SseSerializer serializer);
+
+@protected void sse_encode_list_friend_request(List<FriendRequest> self, SseSerializer serializer);
+
+@protected void sse_encode_list_group(List<Group> self, SseSerializer serializer);
+
+@protected void sse_encode_list_group_member(List<GroupMember> self, SseSerializer serializer);
+
+@protected void sse_encode_list_log_field(List<LogField> self, SseSerializer serializer);
+
+@protected void sse_encode_list_message_reaction(List<MessageReaction> self, SseSerializer serializer);
+
+@protected void sse_encode_list_message_search_hit(List<MessageSearchHit> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_replay_error(List<ReplayError> self, SseSerializer serializer);
+
+@protected void sse_encode_list_session_info(List<SessionInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snippet_highlight(List<SnippetHighlight> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sync_stage(List<SyncStage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_user_profile(List<UserProfile> self, SseSerializer serializer);
+
+@protected void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+@protected void sse_encode_log_field(LogField self, SseSerializer serializer);
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_login_credentials(LoginCredentials self, SseSerializer serializer);
+
+@protected void sse_encode_login_session(LoginSession self, SseSerializer serializer);
+
+@protected void sse_encode_message_history_page(MessageHistoryPage self, SseSerializer serializer);
+
+@protected void sse_encode_message_reaction(MessageReaction self, SseSerializer serializer);
+
+@protected void sse_encode_message_search_hit(MessageSearchHit self, SseSerializer serializer);
+
+@protected void sse_encode_message_search_page(MessageSearchPage self, SseSerializer serializer);
+
+@protected void sse_encode_message_search_query(MessageSearchQuery self, SseSerializer serializer);
+
+@protected void sse_encode_message_send_status(MessageSendStatus self, SseSerializer serializer);
+
+@protected void sse_encode_message_status_event(MessageStatusEvent self, SseSerializer serializer);
+
+@protected void sse_encode_opt_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(OpenImClient? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_group(Group? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_login_session(LoginSession? self, SseSerializer serializer);
+
+@protected void sse_encode_prepared_picture(PreparedPicture self, SseSerializer serializer);
+
+@protected void sse_encode_reaction_change(ReactionChange self, SseSerializer serializer);
+
+@protected void sse_encode_recv_msg_opt(RecvMsgOpt self, SseSerializer serializer);
+
+@protected void sse_encode_replay_error(ReplayError self, SseSerializer serializer);
+
+@protected void sse_encode_replay_report(ReplayReport self, SseSerializer serializer);
+
+@protected void sse_encode_session_change(SessionChange self, SseSerializer serializer);
+
+@protected void sse_encode_session_info(SessionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_snippet_highlight(SnippetHighlight self, SseSerializer serializer);
+
+@protected void sse_encode_sync_stage(SyncStage self, SseSerializer serializer);
+
+@protected void sse_encode_sync_status(SyncStatus self, SseSerializer serializer);
+
+@protected void sse_encode_token_status(TokenStatus self, SseSerializer serializer);
+
+@protected void sse_encode_typing_status(TypingStatus self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_user_profile(UserProfile self, SseSerializer serializer);
+
+@protected void sse_encode_user_status(UserStatus self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(int ptr);
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpenIMClient(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,129 @@
+use std::sync::{Arc, OnceLock, RwLock};
+
+use flutter_rust_bridge::DartFnFuture;
+use futures_util::future::BoxFuture;
+
+use crate::openim::auth::{
+    AuthSession, Authenticator, Credentials, MemoryTokenStorage, TokenStorage, DEFAULT_ADMIN_USER_ID,
+};
+use crate::openim::OpenIMError;
+
+/// 登录凭据
+pub enum LoginCredentials {
+    /// 业务服务器账号密码登录，url 为完整登录地址
+    AppServer {
+        url: String,
+        account: String,
+        password: String,
+    },
+    /// OpenIM 管理员密钥换取用户 token（仅限开发测试），admin_user_id 为空时使用 imAdmin
+    AdminSecret {
+        api_url: String,
+        secret: String,
+        user_id: String,
+        admin_user_id: String,
+    },
+}
+
+/// 登录后的会话信息，用于创建 OpenIMClient
+pub struct LoginSession {
+    pub user_id: String,
+    pub token: String,
+    pub platform_id: i32,
+    /// token 过期时间（秒）
+    pub expires_at: i64,
+}
+
+impl From<AuthSession> for LoginSession {
+    fn from(s: AuthSession) -> Self {
+        Self {
+            user_id: s.user_id,
+            token: s.token,
+            platform_id: s.platform_id,
+            expires_at: s.expires_at,
+        }
+    }
+}
+
+/// 由 Dart 回调实现的 token 存储（如 flutter_secure_storage）
+struct DartTokenStorage<L, S, D> {
+    load: L,
+    save: S,
+    delete: D,
+}
+
+impl<L, S, D> TokenStorage for DartTokenStorage<L, S, D>
+where
+    L: Fn(String) -> DartFnFuture<Option<String>> + Send + Sync,
+    S: Fn(String, String) -> DartFnFuture<()> + Send + Sync,
+    D: Fn(String) -> DartFnFuture<()> + Send + Sync,
+{
+    fn load(&self, key: String) -> BoxFuture<'static, Result<Option<String>, OpenIMError>> {
+        let fut = (self.load)(key);
+        Box::pin(async move { Ok(fut.await) })
+    }
+
+    fn save(&self, key: String, token: String) -> BoxFuture<'static, Result<(), OpenIMError>> {
+        let fut = (self.save)(key, token);
+        Box::pin(async move {
+            fut.await;
+            Ok(())
+        })
+    }
+
+    fn delete(&self, key: String) -> BoxFuture<'static, Result<(), OpenIMError>> {
+        let fut = (self.delete)(key);
+        Box::pin(async move {
+            fut.await;
+            Ok(())
+        })
+    }
+}
+
+fn token_storage() -> &'static RwLock<Arc<dyn TokenStorage>> {
+    static STORAGE: OnceLock<RwLock<Arc<dyn TokenStorage>>> = OnceLock::new();
+    STORAGE.get_or_init(|| RwLock::new(Arc::new(MemoryTokenStorage::default())))
+}
+
+fn authenticator() -> Authenticator {
+    Authenticator::new(reqwest::Client::new(), token_storage().read().unwrap().clone())
+}
+
+/// 设置 token 存储（默认保存在内存中），应在 login / restore_session 之前调用
+pub fn set_token_storage(
+    load: impl Fn(String) -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+    save: impl Fn(String, String) -> DartFnFuture<()> + Send + Sync + 'static,
+    delete: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+) {
+    *token_storage().write().unwrap() = Arc::new(DartTokenStorage { load, save, delete });
+}
+
+/// 登录并保存 token
+pub async fn login(credentials: LoginCredentials, platform_id: i32) -> anyhow::Result<LoginSession> {
+    let credentials = match credentials {
+        LoginCredentials::AppServer { url, account, password } => {
+            Credentials::AppServer { url, account, password }
+        }
+        LoginCredentials::AdminSecret { api_url, secret, user_id, admin_user_id } => {
+            let admin_user_id = if admin_user_id.is_empty() {
+                DEFAULT_ADMIN_USER_ID.to_string()
+            } else {
+                admin_user_id
+            };
+            Credentials::AdminSecret { api_url, secret, user_id, admin_user_id }
+        }
+    };
+    Ok(authenticator().login(&credentials, platform_id).await?.into())
+}
+
+/// 恢复上次登录的会话（token 不存在或已过期时返回 None）
+pub async fn restore_session() -> anyhow::Result<Option<LoginSession>> {
+    let now = chrono::Utc::now().timestamp();
+    Ok(authenticator().restore(now).await?.map(Into::into))
+}
+
+/// 退出登录，清除保存的 token
+pub async fn logout() -> anyhow::Result<()> {
+    authenticator().logout().await?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use crate::api::openim_client::OpenIMClient;
+use crate::openim::capture::{self, CapturedFrame, Direction, FrameKind, FrameRecorder};
+
+/// 回放失败的帧
+pub struct ReplayError {
+    /// 帧在抓包文件中的序号（从 0 开始）
+    pub index: u32,
+    pub time_ms: i64,
+    pub error: String,
+}
+
+/// 回放结果
+pub struct ReplayReport {
+    /// 抓包中的总帧数
+    pub frames: u32,
+    /// 重新解码的帧数（收到的二进制帧）
+    pub replayed: u32,
+    pub errors: Vec<ReplayError>,
+}
+
+impl OpenIMClient {
+    /// 开始录制协议帧到抓包文件（已存在时追加），用于问题反馈
+    pub fn start_frame_capture(&self, path: String) -> anyhow::Result<()> {
+        let recorder = FrameRecorder::create(path)?;
+        *self.recorder.write().unwrap() = Some(Arc::new(recorder));
+        Ok(())
+    }
+
+    /// 停止录制
+    pub fn stop_frame_capture(&self) {
+        *self.recorder.write().unwrap() = None;
+    }
+
+    /// 把抓包中收到的帧交给解码流程（只应在回放专用的内存客户端上调用）
+    pub(crate) fn replay_frames(&self, frames: &[CapturedFrame]) -> ReplayReport {
+        let mut report = ReplayReport { frames: frames.len() as u32, replayed: 0, errors: Vec::new() };
+        for (index, frame) in frames.iter().enumerate() {
+            if frame.direction != Direction::Inbound || frame.kind != FrameKind::Binary {
+                continue;
+            }
+            report.replayed += 1;
+            if let Err(e) = frame.raw_bytes().and_then(|raw| self.handle_binary_frame(&raw)) {
+                tracing::warn!(index, error = %e, "回放帧解析失败");
+                report.errors.push(ReplayError { index: index as u32, time_ms: frame.time_ms, error: e.to_string() });
+            }
+        }
+        // 回放时不会拉取缺失的消息，缓存的乱序消息直接入库
+        self.deliver(self.ordering.flush());
+        report
+    }
+}
+
+/// 回放抓包：在以 user_id 身份新建的内存客户端上重新解码收到的帧，返回解析失败的帧
+///
+/// 回放不会写入任何已登录用户的数据库（抓包中可能包含其他用户的消息）。
+pub fn replay_capture(user_id: String, path: String) -> anyhow::Result<ReplayReport> {
+    let frames = capture::read_capture(path)?;
+    let client = OpenIMClient::new(user_id, String::new(), 0)?;
+    Ok(client.replay_frames(&frames))
+}
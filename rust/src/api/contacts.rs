@@ -0,0 +1,176 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream;
+use crate::frb_generated::StreamSink;
+use crate::openim::contacts::{self, ContactList};
+use crate::openim::rest::friend;
+
+/// 好友
+pub struct Friend {
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub remark: String,
+    pub ex: String,
+    pub create_time: i64,
+}
+
+impl From<contacts::Friend> for Friend {
+    fn from(f: contacts::Friend) -> Self {
+        Self {
+            user_id: f.user_id,
+            nickname: f.nickname,
+            face_url: f.face_url,
+            remark: f.remark,
+            ex: f.ex,
+            create_time: f.create_time,
+        }
+    }
+}
+
+/// 黑名单用户
+pub struct BlockedUser {
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub create_time: i64,
+}
+
+impl From<contacts::BlackUser> for BlockedUser {
+    fn from(u: contacts::BlackUser) -> Self {
+        Self {
+            user_id: u.user_id,
+            nickname: u.nickname,
+            face_url: u.face_url,
+            create_time: u.create_time,
+        }
+    }
+}
+
+/// 好友申请（包括收到的和自己发出的）
+pub struct FriendRequest {
+    pub from_user_id: String,
+    pub from_nickname: String,
+    pub from_face_url: String,
+    pub to_user_id: String,
+    pub to_nickname: String,
+    pub to_face_url: String,
+    /// 0 未处理，1 已同意，-1 已拒绝
+    pub handle_result: i32,
+    pub req_msg: String,
+    pub handle_msg: String,
+    pub create_time: i64,
+    pub handle_time: i64,
+}
+
+impl From<friend::FriendRequest> for FriendRequest {
+    fn from(r: friend::FriendRequest) -> Self {
+        Self {
+            from_user_id: r.from_user_id,
+            from_nickname: r.from_nickname,
+            from_face_url: r.from_face_url,
+            to_user_id: r.to_user_id,
+            to_nickname: r.to_nickname,
+            to_face_url: r.to_face_url,
+            handle_result: r.handle_result,
+            req_msg: r.req_msg,
+            handle_msg: r.handle_msg,
+            create_time: r.create_time,
+            handle_time: r.handle_time,
+        }
+    }
+}
+
+/// 联系人缓存变化，收到后重新读取对应列表
+pub enum ContactChange {
+    Friends,
+    Blacklist,
+    FriendRequests,
+}
+
+impl From<ContactList> for ContactChange {
+    fn from(list: ContactList) -> Self {
+        match list {
+            ContactList::Friends => Self::Friends,
+            ContactList::Blacklist => Self::Blacklist,
+            ContactList::FriendRequests => Self::FriendRequests,
+        }
+    }
+}
+
+impl OpenIMClient {
+    /// 从服务器同步好友、黑名单和好友申请到本地缓存
+    pub async fn sync_contacts(&self) -> anyhow::Result<()> {
+        self.contacts.sync_all(&self.rest_api(), &self.user_id).await?;
+        Ok(())
+    }
+
+    /// 好友列表（本地缓存）
+    pub fn get_friends(&self) -> anyhow::Result<Vec<Friend>> {
+        Ok(self.contacts.friends()?.into_iter().map(Into::into).collect())
+    }
+
+    /// 黑名单（本地缓存）
+    pub fn get_blacklist(&self) -> anyhow::Result<Vec<BlockedUser>> {
+        Ok(self.contacts.blacklist()?.into_iter().map(Into::into).collect())
+    }
+
+    /// 好友申请（本地缓存）
+    pub fn get_friend_requests(&self) -> anyhow::Result<Vec<FriendRequest>> {
+        Ok(self.contacts.friend_requests()?.into_iter().map(Into::into).collect())
+    }
+
+    /// 发送好友申请
+    pub async fn add_friend(&self, user_id: String, req_msg: String) -> anyhow::Result<()> {
+        self.contacts
+            .add_friend(&self.rest_api(), &self.user_id, &user_id, &req_msg)
+            .await?;
+        Ok(())
+    }
+
+    /// 同意好友申请
+    pub async fn accept_friend_request(&self, from_user_id: String, handle_msg: String) -> anyhow::Result<()> {
+        self.contacts
+            .respond_friend_request(&self.rest_api(), &self.user_id, &from_user_id, true, &handle_msg)
+            .await?;
+        Ok(())
+    }
+
+    /// 拒绝好友申请
+    pub async fn reject_friend_request(&self, from_user_id: String, handle_msg: String) -> anyhow::Result<()> {
+        self.contacts
+            .respond_friend_request(&self.rest_api(), &self.user_id, &from_user_id, false, &handle_msg)
+            .await?;
+        Ok(())
+    }
+
+    /// 删除好友
+    pub async fn delete_friend(&self, user_id: String) -> anyhow::Result<()> {
+        self.contacts.delete_friend(&self.rest_api(), &self.user_id, &user_id).await?;
+        Ok(())
+    }
+
+    /// 设置好友备注（空字符串表示清除）
+    pub async fn set_friend_remark(&self, user_id: String, remark: String) -> anyhow::Result<()> {
+        self.contacts
+            .set_friend_remark(&self.rest_api(), &self.user_id, &user_id, &remark)
+            .await?;
+        Ok(())
+    }
+
+    /// 加入黑名单
+    pub async fn add_to_blacklist(&self, user_id: String) -> anyhow::Result<()> {
+        self.contacts.add_black(&self.rest_api(), &self.user_id, &user_id).await?;
+        Ok(())
+    }
+
+    /// 移出黑名单
+    pub async fn remove_from_blacklist(&self, user_id: String) -> anyhow::Result<()> {
+        self.contacts.remove_black(&self.rest_api(), &self.user_id, &user_id).await?;
+        Ok(())
+    }
+
+    /// 订阅联系人缓存变化
+    pub fn contact_change_stream(&self, sink: StreamSink<ContactChange>) {
+        forward_stream(self.contacts.subscribe(), sink);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream_with;
+use crate::frb_generated::StreamSink;
+use crate::openim::content::searchable_text;
+use crate::openim::conversations::{self, recv_msg_opt};
+use crate::openim::messages::StoredMessage;
+use crate::openim::rest::conversation::ConversationUpdate;
+
+/// 消息接收选项
+pub enum RecvMsgOpt {
+    /// 正常接收并通知
+    Normal,
+    /// 不接收消息
+    NotReceive,
+    /// 接收但不通知（免打扰）
+    NotNotify,
+}
+
+impl From<i32> for RecvMsgOpt {
+    fn from(opt: i32) -> Self {
+        match opt {
+            recv_msg_opt::NOT_RECEIVE => Self::NotReceive,
+            recv_msg_opt::NOT_NOTIFY => Self::NotNotify,
+            _ => Self::Normal,
+        }
+    }
+}
+
+impl From<RecvMsgOpt> for i32 {
+    fn from(opt: RecvMsgOpt) -> Self {
+        match opt {
+            RecvMsgOpt::Normal => recv_msg_opt::NORMAL,
+            RecvMsgOpt::NotReceive => recv_msg_opt::NOT_RECEIVE,
+            RecvMsgOpt::NotNotify => recv_msg_opt::NOT_NOTIFY,
+        }
+    }
+}
+
+/// 会话列表中的会话
+pub struct ConversationInfo {
+    pub conversation_id: String,
+    /// 1 单聊，3 群聊
+    pub conversation_type: i32,
+    pub user_id: String,
+    pub group_id: String,
+    pub recv_msg_opt: RecvMsgOpt,
+    pub is_pinned: bool,
+    pub draft_text: String,
+    pub draft_text_time: i64,
+    pub latest_msg_time: i64,
+    pub latest_msg_client_msg_id: Option<String>,
+    pub latest_msg_send_id: Option<String>,
+    pub latest_msg_content_type: Option<i32>,
+    /// 最新消息的文本（不含文本的消息类型为 None）
+    pub latest_msg_text: Option<String>,
+    pub ex: String,
+}
+
+impl From<(conversations::Conversation, Option<StoredMessage>)> for ConversationInfo {
+    fn from((c, latest): (conversations::Conversation, Option<StoredMessage>)) -> Self {
+        Self {
+            conversation_id: c.conversation_id,
+            conversation_type: c.conversation_type,
+            user_id: c.user_id,
+            group_id: c.group_id,
+            recv_msg_opt: c.recv_msg_opt.into(),
+            is_pinned: c.is_pinned,
+            draft_text: c.draft_text,
+            draft_text_time: c.draft_text_time,
+            latest_msg_time: c.latest_msg_time,
+            latest_msg_text: latest.as_ref().and_then(|m| searchable_text(m.content_type, &m.content)),
+            latest_msg_content_type: latest.as_ref().map(|m| m.content_type),
+            latest_msg_send_id: latest.as_ref().map(|m| m.send_id.clone()),
+            latest_msg_client_msg_id: latest.map(|m| m.client_msg_id),
+            ex: c.ex,
+        }
+    }
+}
+
+/// 会话变化，收到后重新读取会话列表
+pub struct ConversationChange {
+    pub conversation_id: String,
+}
+
+impl OpenIMClient {
+    /// 会话列表（本地），置顶在前，其余按最新消息或草稿时间倒序
+    pub fn get_conversation_list(&self) -> anyhow::Result<Vec<ConversationInfo>> {
+        Ok(self.conversations.list()?.into_iter().map(Into::into).collect())
+    }
+
+    /// 从服务器同步置顶、免打扰等会话设置
+    pub async fn sync_conversations(&self) -> anyhow::Result<()> {
+        self.conversations.sync(&self.rest_api(), &self.user_id).await?;
+        Ok(())
+    }
+
+    /// 置顶 / 取消置顶
+    pub async fn set_conversation_pinned(&self, conversation_id: String, pinned: bool) -> anyhow::Result<()> {
+        let update = ConversationUpdate { is_pinned: Some(pinned), ..Default::default() };
+        self.conversations
+            .update(&self.rest_api(), &self.user_id, &conversation_id, update)
+            .await?;
+        Ok(())
+    }
+
+    /// 设置消息接收选项（免打扰）
+    pub async fn set_recv_msg_opt(&self, conversation_id: String, opt: RecvMsgOpt) -> anyhow::Result<()> {
+        let update = ConversationUpdate { recv_msg_opt: Some(opt.into()), ..Default::default() };
+        self.conversations
+            .update(&self.rest_api(), &self.user_id, &conversation_id, update)
+            .await?;
+        Ok(())
+    }
+
+    /// 保存草稿（只保存在本地，空字符串表示清除）
+    pub fn set_draft(&self, conversation_id: String, draft: String) -> anyhow::Result<()> {
+        self.conversations.set_draft(
+            &self.user_id,
+            &conversation_id,
+            &draft,
+            chrono::Utc::now().timestamp_millis(),
+        )?;
+        Ok(())
+    }
+
+    /// 从会话列表中隐藏（只影响本地，收到新消息后重新显示）
+    pub fn hide_conversation(&self, conversation_id: String) -> anyhow::Result<()> {
+        self.conversations.hide(&conversation_id)?;
+        Ok(())
+    }
+
+    /// 清空会话中的消息（服务器和本地）
+    pub async fn clear_conversation_messages(&self, conversation_id: String) -> anyhow::Result<()> {
+        self.conversations
+            .clear_messages(&self.rest_api(), &self.user_id, &conversation_id)
+            .await?;
+        Ok(())
+    }
+
+    /// 删除会话及其消息
+    pub async fn delete_conversation(&self, conversation_id: String) -> anyhow::Result<()> {
+        self.conversations
+            .delete(&self.rest_api(), &self.user_id, &conversation_id)
+            .await?;
+        Ok(())
+    }
+
+    /// 订阅会话变化
+    pub fn conversation_change_stream(&self, sink: StreamSink<ConversationChange>) {
+        forward_stream_with(self.conversations.subscribe(), sink, |conversation_id| {
+            Some(ConversationChange { conversation_id })
+        });
+    }
+}
@@ -0,0 +1,222 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream;
+use crate::frb_generated::StreamSink;
+use crate::openim::groups;
+use crate::openim::rest::group::{join_source, GroupInfo, GroupMemberInfo};
+
+/// 群资料
+pub struct Group {
+    pub group_id: String,
+    pub group_name: String,
+    /// 群公告
+    pub notification: String,
+    pub introduction: String,
+    pub face_url: String,
+    pub owner_user_id: String,
+    pub member_count: i32,
+    /// 0 正常，1 被封禁，2 已解散，3 全员禁言
+    pub status: i32,
+    pub create_time: i64,
+}
+
+impl From<GroupInfo> for Group {
+    fn from(g: GroupInfo) -> Self {
+        Self {
+            group_id: g.group_id,
+            group_name: g.group_name,
+            notification: g.notification,
+            introduction: g.introduction,
+            face_url: g.face_url,
+            owner_user_id: g.owner_user_id,
+            member_count: g.member_count,
+            status: g.status,
+            create_time: g.create_time,
+        }
+    }
+}
+
+/// 群成员角色
+pub enum GroupRole {
+    Owner,
+    Admin,
+    Member,
+}
+
+/// 群成员
+pub struct GroupMember {
+    pub group_id: String,
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub role: GroupRole,
+    pub join_time: i64,
+    /// 禁言结束时间（毫秒），0 表示未禁言
+    pub mute_end_time: i64,
+}
+
+impl From<GroupMemberInfo> for GroupMember {
+    fn from(m: GroupMemberInfo) -> Self {
+        use crate::openim::rest::group::role_level;
+        Self {
+            group_id: m.group_id,
+            user_id: m.user_id,
+            nickname: m.nickname,
+            face_url: m.face_url,
+            role: match m.role_level {
+                role_level::OWNER => GroupRole::Owner,
+                role_level::ADMIN => GroupRole::Admin,
+                _ => GroupRole::Member,
+            },
+            join_time: m.join_time,
+            mute_end_time: m.mute_end_time,
+        }
+    }
+}
+
+/// 群缓存变化，收到后重新读取对应数据
+pub enum GroupChange {
+    /// 已加入的群列表变化
+    JoinedGroups,
+    Info { group_id: String },
+    Members { group_id: String },
+}
+
+impl From<groups::GroupChange> for GroupChange {
+    fn from(change: groups::GroupChange) -> Self {
+        match change {
+            groups::GroupChange::JoinedGroups => Self::JoinedGroups,
+            groups::GroupChange::Info { group_id } => Self::Info { group_id },
+            groups::GroupChange::Members { group_id } => Self::Members { group_id },
+        }
+    }
+}
+
+impl OpenIMClient {
+    /// 从服务器同步已加入的群
+    pub async fn sync_joined_groups(&self) -> anyhow::Result<()> {
+        self.groups.sync_joined_groups(&self.rest_api(), &self.user_id).await?;
+        Ok(())
+    }
+
+    /// 从服务器同步群成员
+    pub async fn sync_group_members(&self, group_id: String) -> anyhow::Result<()> {
+        self.groups.sync_members(&self.rest_api(), &group_id).await?;
+        Ok(())
+    }
+
+    /// 已加入的群（本地缓存）
+    pub fn get_joined_groups(&self) -> anyhow::Result<Vec<Group>> {
+        Ok(self.groups.joined_groups()?.into_iter().map(Into::into).collect())
+    }
+
+    /// 群资料（本地缓存）
+    pub fn get_group(&self, group_id: String) -> anyhow::Result<Option<Group>> {
+        Ok(self.groups.group(&group_id)?.map(Into::into))
+    }
+
+    /// 分页获取群成员（本地缓存，群主、管理员在前）
+    pub fn get_group_members(&self, group_id: String, offset: u32, limit: u32) -> anyhow::Result<Vec<GroupMember>> {
+        Ok(self.groups.members(&group_id, offset, limit)?.into_iter().map(Into::into).collect())
+    }
+
+    /// 创建群，自己为群主
+    pub async fn create_group(&self, group_name: String, member_user_ids: Vec<String>) -> anyhow::Result<Group> {
+        let group = self
+            .groups
+            .create_group(&self.rest_api(), &self.user_id, &group_name, &member_user_ids, &[])
+            .await?;
+        Ok(group.into())
+    }
+
+    /// 邀请入群
+    pub async fn invite_to_group(&self, group_id: String, user_ids: Vec<String>, reason: String) -> anyhow::Result<()> {
+        self.groups.invite(&self.rest_api(), &group_id, &user_ids, &reason).await?;
+        Ok(())
+    }
+
+    /// 踢出群
+    pub async fn kick_group_members(&self, group_id: String, user_ids: Vec<String>, reason: String) -> anyhow::Result<()> {
+        self.groups.kick(&self.rest_api(), &group_id, &user_ids, &reason).await?;
+        Ok(())
+    }
+
+    /// 申请入群（通过搜索），不需要验证的群直接加入
+    pub async fn join_group(&self, group_id: String, req_msg: String) -> anyhow::Result<()> {
+        self.groups
+            .join(&self.rest_api(), &group_id, &req_msg, join_source::SEARCH)
+            .await?;
+        Ok(())
+    }
+
+    /// 同意入群申请（群主或管理员）
+    pub async fn accept_group_application(
+        &self,
+        group_id: String,
+        from_user_id: String,
+        handled_msg: String,
+    ) -> anyhow::Result<()> {
+        self.groups
+            .respond_application(&self.rest_api(), &group_id, &from_user_id, true, &handled_msg)
+            .await?;
+        Ok(())
+    }
+
+    /// 拒绝入群申请（群主或管理员）
+    pub async fn reject_group_application(
+        &self,
+        group_id: String,
+        from_user_id: String,
+        handled_msg: String,
+    ) -> anyhow::Result<()> {
+        self.groups
+            .respond_application(&self.rest_api(), &group_id, &from_user_id, false, &handled_msg)
+            .await?;
+        Ok(())
+    }
+
+    /// 退出群
+    pub async fn quit_group(&self, group_id: String) -> anyhow::Result<()> {
+        self.groups.quit(&self.rest_api(), &self.user_id, &group_id).await?;
+        Ok(())
+    }
+
+    /// 解散群（仅群主）
+    pub async fn dismiss_group(&self, group_id: String) -> anyhow::Result<()> {
+        self.groups.dismiss(&self.rest_api(), &group_id).await?;
+        Ok(())
+    }
+
+    /// 转让群主
+    pub async fn transfer_group_owner(&self, group_id: String, new_owner_user_id: String) -> anyhow::Result<()> {
+        self.groups
+            .transfer_owner(&self.rest_api(), &self.user_id, &group_id, &new_owner_user_id)
+            .await?;
+        Ok(())
+    }
+
+    /// 设置或取消管理员
+    pub async fn set_group_admin(&self, group_id: String, user_id: String, admin: bool) -> anyhow::Result<()> {
+        self.groups.set_admin(&self.rest_api(), &group_id, &user_id, admin).await?;
+        Ok(())
+    }
+
+    /// 禁言群成员，muted_seconds 为 0 时解除禁言
+    pub async fn mute_group_member(&self, group_id: String, user_id: String, muted_seconds: u32) -> anyhow::Result<()> {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.groups
+            .mute_member(&self.rest_api(), &group_id, &user_id, muted_seconds, now)
+            .await?;
+        Ok(())
+    }
+
+    /// 全员禁言 / 解除全员禁言
+    pub async fn mute_group(&self, group_id: String, muted: bool) -> anyhow::Result<()> {
+        self.groups.mute_group(&self.rest_api(), &group_id, muted).await?;
+        Ok(())
+    }
+
+    /// 订阅群缓存变化
+    pub fn group_change_stream(&self, sink: StreamSink<GroupChange>) {
+        forward_stream(self.groups.subscribe(), sink);
+    }
+}
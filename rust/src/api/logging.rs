@@ -0,0 +1,85 @@
+use crate::api::stream::forward_stream;
+use crate::frb_generated::StreamSink;
+use crate::openim::logging::{self, LogRecord};
+
+/// 日志级别
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl From<LogLevel> for tracing::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Trace => Self::TRACE,
+            LogLevel::Debug => Self::DEBUG,
+            LogLevel::Info => Self::INFO,
+            LogLevel::Warn => Self::WARN,
+            LogLevel::Error => Self::ERROR,
+        }
+    }
+}
+
+impl From<tracing::Level> for LogLevel {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::TRACE => Self::Trace,
+            tracing::Level::DEBUG => Self::Debug,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::WARN => Self::Warn,
+            _ => Self::Error,
+        }
+    }
+}
+
+/// 日志字段
+pub struct LogField {
+    pub name: String,
+    pub value: String,
+}
+
+/// 一条日志
+pub struct LogEntry {
+    pub time_ms: i64,
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+    pub fields: Vec<LogField>,
+    /// 所在的 span，如 `openim{user_id=u1}:session{operation_id=1}`
+    pub spans: String,
+}
+
+impl From<LogRecord> for LogEntry {
+    fn from(r: LogRecord) -> Self {
+        Self {
+            time_ms: r.time_ms,
+            level: r.level.into(),
+            target: r.target,
+            message: r.message,
+            fields: r.fields.into_iter().map(|(name, value)| LogField { name, value }).collect(),
+            spans: r.spans,
+        }
+    }
+}
+
+/// 初始化日志（只能调用一次），log_dir 不为空时按天滚动写入日志文件，最多保留 max_files 个
+pub fn init_logging(level: LogLevel, log_dir: Option<String>, max_files: u32) -> anyhow::Result<()> {
+    logging::init(level.into(), log_dir.as_deref().map(std::path::Path::new), max_files as usize)?;
+    Ok(())
+}
+
+/// 日志目录中的日志文件路径（从旧到新），用于问题反馈时上传
+pub fn get_log_files(log_dir: String) -> anyhow::Result<Vec<String>> {
+    Ok(logging::log_files(std::path::Path::new(&log_dir))?
+        .into_iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect())
+}
+
+/// 订阅日志（只包含订阅之后的日志）
+pub fn log_stream(sink: StreamSink<LogEntry>) {
+    forward_stream(logging::subscribe(), sink);
+}
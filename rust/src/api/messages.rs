@@ -0,0 +1,70 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::openim::content::searchable_text;
+use crate::openim::history::HistoryPage;
+use crate::openim::messages::StoredMessage;
+
+/// 聊天消息
+pub struct ChatMessage {
+    pub client_msg_id: String,
+    pub server_msg_id: String,
+    pub conversation_id: String,
+    pub seq: i64,
+    pub send_id: String,
+    pub recv_id: String,
+    pub group_id: String,
+    pub session_type: i32,
+    pub content_type: i32,
+    /// 消息内容（JSON）
+    pub content: Vec<u8>,
+    /// 消息中的文本（不含文本的消息类型为 None）
+    pub text: Option<String>,
+    pub send_time: i64,
+}
+
+impl From<StoredMessage> for ChatMessage {
+    fn from(m: StoredMessage) -> Self {
+        Self {
+            text: searchable_text(m.content_type, &m.content),
+            client_msg_id: m.client_msg_id,
+            server_msg_id: m.server_msg_id,
+            conversation_id: m.conversation_id,
+            seq: m.seq,
+            send_id: m.send_id,
+            recv_id: m.recv_id,
+            group_id: m.group_id,
+            session_type: m.session_type,
+            content_type: m.content_type,
+            content: m.content,
+            send_time: m.send_time,
+        }
+    }
+}
+
+/// 一页历史消息
+pub struct MessageHistoryPage {
+    /// 按 seq 升序（从旧到新）
+    pub messages: Vec<ChatMessage>,
+    /// 是否还有更早的消息，继续加载时以第一条消息的 seq 作为 before_seq
+    pub has_more: bool,
+}
+
+impl From<HistoryPage> for MessageHistoryPage {
+    fn from(page: HistoryPage) -> Self {
+        Self { messages: page.messages.into_iter().map(Into::into).collect(), has_more: page.has_more }
+    }
+}
+
+impl OpenIMClient {
+    /// 加载会话的历史消息：before_seq 之前（不含）的最多 limit 条，before_seq 为 0 时从最新的消息开始
+    ///
+    /// 优先读取本地，缺失的消息自动从服务器拉取；离线或拉取失败时只返回本地已有的消息。
+    pub async fn get_history(
+        &self,
+        conversation_id: String,
+        before_seq: i64,
+        limit: u32,
+    ) -> anyhow::Result<MessageHistoryPage> {
+        let page = self.load_history(&conversation_id, before_seq, limit).await?;
+        Ok(page.into())
+    }
+}
@@ -1,2 +1,15 @@
+pub mod auth;
+pub mod capture;
+pub mod contacts;
+pub mod conversations;
+pub mod groups;
+pub mod logging;
+pub mod messages;
 pub mod simple;
 pub mod openim_client;
+pub mod picture;
+pub mod reactions;
+pub mod sessions;
+mod stream;
+pub mod sync;
+pub mod users;
@@ -1,15 +1,45 @@
-use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
-use futures_util::{StreamExt, SinkExt};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tokio::time::interval;
 use openim_protocol::Message as ProtobufMessage;
-use flate2::read::GzDecoder;
-use std::io::Read;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::api::stream::{forward_stream, forward_stream_with, forward_stream_with_initial};
+use crate::frb_generated::StreamSink;
+use crate::openim::capture::{self, Direction, FrameKind, FrameRecorder};
+use crate::openim::OpenIMError;
+use flutter_rust_bridge::DartFnFuture;
+use futures_util::future::BoxFuture;
+use crate::openim::contacts::Contacts;
+use crate::openim::content::{content_type, is_reaction_change, TextElem, TypingElem};
+use crate::openim::groups::Groups;
+use crate::openim::handle::{ClientHandle, Command, Reply};
+use crate::openim::history::{self, HistoryPage, MAX_HISTORY_PAGE};
+use crate::openim::connection::{self, ConnectionState};
+use crate::openim::conversation::{self, session_type, MSG_FROM_USER};
+use crate::openim::conversations::Conversations;
+use crate::openim::messages::{MessageStore, StoredMessage};
+use crate::openim::msg_id::ClientMsgIdGenerator;
+use crate::openim::ordering::SeqOrdering;
+use crate::openim::rest::ApiClient;
+use crate::openim::presence::Presence;
+use crate::openim::reactions::Reactions;
+use crate::openim::outbox::{Outbox, OutboxEntry, OutboxEvent, OutboxStatus, OutgoingMessage};
+use crate::openim::search::{SearchHit, SearchQuery};
+use crate::openim::store::Store;
+use crate::openim::sync::{self, SyncPhase, SyncProgress, SYNC_MSG_LIMIT};
+use crate::openim::token::{self, TokenEvent, TokenManager, TokenRefresher};
+use crate::openim::transport::{Connector, Frame, Transport, WsConnector};
+use crate::openim::typing::{self, TypingEvent, TypingLimiter, TypingTracker};
+use crate::openim::users::UserProfiles;
+use openim_protocol::sdkws::MsgData;
+use tokio::sync::{broadcast, mpsc};
+use tracing::Instrument;
 
 /// 消息类型标识符（对应服务器常量）
 #[allow(dead_code)]
-mod msg_type {
+pub(crate) mod msg_type {
     pub const WS_GET_NEWEST_SEQ: i32 = 1001;
     pub const WS_PULL_MSG_BY_SEQ_LIST: i32 = 1002;
     pub const WS_SEND_MSG: i32 = 1003;
@@ -21,15 +51,253 @@ mod msg_type {
     pub const WS_KICK_ONLINE_MSG: i32 = 2002;
     pub const WS_LOGOUT_MSG: i32 = 2003;
     pub const WS_SET_BACKGROUND_STATUS: i32 = 2004;
+    pub const WS_SUB_USER_ONLINE_STATUS: i32 = 2005;
 }
 
 /// OpenIM 客户端配置
 pub struct OpenIMClient {
     pub user_id: String,
-    pub token: String,
     pub platform_id: i32,
     pub ws_url: String,
+    /// REST API 地址（friend / group / user / conversation 等接口）
+    pub api_url: String,
+    http: reqwest::Client,
+    connector: Arc<dyn Connector>,
+    tokens: Arc<TokenManager>,
     received_msg_ids: std::sync::Arc<std::sync::Mutex<std::collections::HashSet<String>>>,
+    outbox: Arc<Outbox>,
+    messages: MessageStore,
+    pub(crate) contacts: Contacts,
+    pub(crate) groups: Groups,
+    pub(crate) profiles: UserProfiles,
+    pub(crate) presence: Presence,
+    pub(crate) conversations: Conversations,
+    pub(crate) reactions: Reactions,
+    /// 协议帧录制（默认关闭）
+    pub(crate) recorder: std::sync::RwLock<Option<Arc<FrameRecorder>>>,
+    msg_incr: AtomicU64,
+    msg_id_gen: ClientMsgIdGenerator,
+    typing_limiter: TypingLimiter,
+    typing: TypingTracker,
+    /// 发往连接任务的命令
+    handle: ClientHandle,
+    commands: tokio::sync::Mutex<mpsc::Receiver<Command>>,
+    /// 等待响应的请求，按 msgIncr 对应响应
+    pending: std::sync::Mutex<std::collections::HashMap<String, Reply>>,
+    /// 应用是否在后台（每次连接后同步给服务器）
+    background: AtomicBool,
+    /// 收到被踢下线通知，连接结束后不再重连
+    kicked: AtomicBool,
+    /// 后台同步任务，断开连接时取消
+    tasks: std::sync::Mutex<Vec<tokio::task::AbortHandle>>,
+    store: Arc<Store>,
+    /// 收到的新消息（不含正在输入信令和重复消息，每个会话按 seq 递增）
+    incoming: broadcast::Sender<StoredMessage>,
+    /// 乱序到达的消息按 seq 重新排序
+    pub(crate) ordering: SeqOrdering,
+    /// 连接后是否自动同步
+    pub(crate) sync_on_connect: AtomicBool,
+    pub(crate) sync_progress: SyncProgress,
+}
+
+/// 消息发送状态
+pub enum MessageSendStatus {
+    Sending,
+    Sent,
+    Failed,
+}
+
+/// 消息发送状态变化（推送给 Dart）
+pub struct MessageStatusEvent {
+    /// 发件箱 ID（`send_text_message` 的返回值）
+    pub outbox_id: i64,
+    pub client_msg_id: String,
+    pub conversation_id: String,
+    pub status: MessageSendStatus,
+    pub attempts: i64,
+    pub server_msg_id: String,
+    pub send_time: i64,
+    pub error: String,
+}
+
+/// 连接状态（推送给 Dart）
+pub enum ConnectionStatus {
+    Idle,
+    Connecting,
+    Handshaking,
+    Connected,
+    /// 正在同步离线消息
+    Syncing,
+    /// 连接断开，backoff_ms 后第 attempt 次重连
+    Reconnecting { attempt: u32, backoff_ms: u64 },
+    /// 被踢下线，需要用户确认后重新连接
+    Kicked,
+    /// token 失效，需要重新登录
+    TokenExpired,
+    Closed,
+}
+
+impl From<ConnectionState> for ConnectionStatus {
+    fn from(state: ConnectionState) -> Self {
+        match state {
+            ConnectionState::Idle => Self::Idle,
+            ConnectionState::Connecting => Self::Connecting,
+            ConnectionState::Handshaking => Self::Handshaking,
+            ConnectionState::Connected => Self::Connected,
+            ConnectionState::Syncing => Self::Syncing,
+            ConnectionState::Reconnecting { attempt, backoff } => {
+                Self::Reconnecting { attempt, backoff_ms: backoff.as_millis() as u64 }
+            }
+            ConnectionState::Kicked => Self::Kicked,
+            ConnectionState::TokenExpired => Self::TokenExpired,
+            ConnectionState::Closed => Self::Closed,
+        }
+    }
+}
+
+/// token 状态变化（推送给 Dart）
+pub enum TokenStatus {
+    /// 即将过期，未设置刷新回调时需要重新登录
+    WillExpire { expires_at: i64 },
+    Refreshed { expires_at: i64 },
+    RefreshFailed { error: String },
+}
+
+impl From<TokenEvent> for TokenStatus {
+    fn from(e: TokenEvent) -> Self {
+        match e {
+            TokenEvent::WillExpire { expires_at } => Self::WillExpire { expires_at },
+            TokenEvent::Refreshed { expires_at } => Self::Refreshed { expires_at },
+            TokenEvent::RefreshFailed { error } => Self::RefreshFailed { error },
+        }
+    }
+}
+
+/// 由 Dart 回调实现的 token 刷新
+struct DartTokenRefresher<F> {
+    callback: F,
+}
+
+impl<F> TokenRefresher for DartTokenRefresher<F>
+where
+    F: Fn() -> DartFnFuture<String> + Send + Sync,
+{
+    fn refresh(&self) -> BoxFuture<'static, Result<String, OpenIMError>> {
+        let fut = (self.callback)();
+        Box::pin(async move {
+            let token = fut.await;
+            if token.is_empty() {
+                return Err(OpenIMError::InvalidToken("刷新回调返回了空 token".to_string()));
+            }
+            Ok(token)
+        })
+    }
+}
+
+/// 一次连接结束的原因
+enum SessionEnd {
+    /// 主动断开
+    Closed,
+    /// 连接中断（网络错误、服务器关闭等），稍后重连
+    Lost,
+    /// 被踢下线
+    Kicked,
+    /// token 已刷新，需要用新 token 重连
+    TokenRefreshed,
+    /// 握手时服务器返回 token 错误（token 失效、被踢下线等）
+    TokenRejected { code: i32, msg: String },
+}
+
+/// 正在输入状态（推送给 Dart）
+pub struct TypingStatus {
+    pub conversation_id: String,
+    pub user_id: String,
+    pub typing: bool,
+}
+
+impl From<TypingEvent> for TypingStatus {
+    fn from(e: TypingEvent) -> Self {
+        Self {
+            conversation_id: e.conversation_id,
+            user_id: e.user_id,
+            typing: e.typing,
+        }
+    }
+}
+
+/// 消息搜索条件（关键词为空时只按其余条件过滤）
+pub struct MessageSearchQuery {
+    pub keyword: String,
+    pub conversation_id: Option<String>,
+    pub sender_id: Option<String>,
+    /// 为空表示不限类型
+    pub content_types: Vec<i32>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// 摘要中的命中区间（UTF-16 偏移）
+pub struct SnippetHighlight {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// 消息搜索结果
+pub struct MessageSearchHit {
+    pub client_msg_id: String,
+    pub conversation_id: String,
+    pub send_id: String,
+    pub content_type: i32,
+    pub send_time: i64,
+    pub seq: i64,
+    pub snippet: String,
+    pub highlights: Vec<SnippetHighlight>,
+}
+
+/// 一页搜索结果
+pub struct MessageSearchPage {
+    pub hits: Vec<MessageSearchHit>,
+    pub has_more: bool,
+}
+
+impl From<SearchHit> for MessageSearchHit {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            client_msg_id: hit.message.client_msg_id,
+            conversation_id: hit.message.conversation_id,
+            send_id: hit.message.send_id,
+            content_type: hit.message.content_type,
+            send_time: hit.message.send_time,
+            seq: hit.message.seq,
+            snippet: hit.snippet,
+            highlights: hit
+                .highlights
+                .into_iter()
+                .map(|h| SnippetHighlight { start: h.start, end: h.end })
+                .collect(),
+        }
+    }
+}
+
+impl From<OutboxEvent> for MessageStatusEvent {
+    fn from(e: OutboxEvent) -> Self {
+        Self {
+            outbox_id: e.id,
+            client_msg_id: e.client_msg_id,
+            conversation_id: e.conversation_id,
+            status: match e.status {
+                OutboxStatus::Sending => MessageSendStatus::Sending,
+                OutboxStatus::Sent => MessageSendStatus::Sent,
+                OutboxStatus::Failed => MessageSendStatus::Failed,
+            },
+            attempts: e.attempts,
+            server_msg_id: e.server_msg_id,
+            send_time: e.send_time,
+            error: e.error,
+        }
+    }
 }
 
 /// OpenIM 请求结构（对应服务器的 Req）
@@ -92,21 +360,193 @@ struct ServerResponse {
 }
 
 impl OpenIMClient {
-    pub fn new(user_id: String, token: String, platform_id: i32) -> Self {
+    /// 创建客户端（数据保存在内存中，进程退出后丢失）
+    pub fn new(user_id: String, token: String, platform_id: i32) -> anyhow::Result<Self> {
+        let store = Store::open_in_memory()?;
+        Ok(Self::with_store(user_id, token, platform_id, store))
+    }
+
+    /// 创建客户端，发件箱等数据持久化到 `{data_dir}/openim.db`
+    pub fn open(user_id: String, token: String, platform_id: i32, data_dir: String) -> anyhow::Result<Self> {
+        let store = Store::open(std::path::Path::new(&data_dir).join("openim.db"))?;
+        Ok(Self::with_store(user_id, token, platform_id, store))
+    }
+
+    fn with_store(user_id: String, token: String, platform_id: i32, store: Store) -> Self {
+        let (handle, commands) = ClientHandle::new(32);
+        let store = Arc::new(store);
         Self {
-            user_id,
-            token,
             platform_id,
             ws_url: "ws://localhost:10001".to_string(),
+            api_url: "http://localhost:10002".to_string(),
+            http: reqwest::Client::new(),
+            connector: Arc::new(WsConnector),
+            tokens: Arc::new(TokenManager::new(token)),
             received_msg_ids: std::sync::Arc::new(std::sync::Mutex::new(std::collections::HashSet::new())),
+            outbox: Arc::new(Outbox::new(store.clone())),
+            contacts: Contacts::new(store.clone()),
+            groups: Groups::new(store.clone()),
+            profiles: UserProfiles::new(store.clone()),
+            presence: Presence::new(),
+            conversations: Conversations::new(store.clone()),
+            reactions: Reactions::new(store.clone()),
+            recorder: std::sync::RwLock::new(None),
+            messages: MessageStore::new(store.clone()),
+            store,
+            msg_incr: AtomicU64::new(1),
+            msg_id_gen: ClientMsgIdGenerator::new(&user_id, platform_id),
+            typing_limiter: TypingLimiter::default(),
+            typing: TypingTracker::new(),
+            handle,
+            commands: tokio::sync::Mutex::new(commands),
+            pending: std::sync::Mutex::new(std::collections::HashMap::new()),
+            background: AtomicBool::new(false),
+            kicked: AtomicBool::new(false),
+            tasks: std::sync::Mutex::new(Vec::new()),
+            incoming: broadcast::channel(256).0,
+            ordering: SeqOrdering::default(),
+            sync_on_connect: AtomicBool::new(true),
+            sync_progress: SyncProgress::default(),
+            user_id,
         }
     }
 
+    /// 发送文本消息：先写入发件箱，连接可用时按顺序发送，返回发件箱 ID
+    pub fn send_text_message(&self, recv_id: String, text: String) -> anyhow::Result<i64> {
+        let conversation_id = conversation::single_conversation_id(&self.user_id, &recv_id);
+        let content = serde_json::to_vec(&TextElem { content: text })?;
+        self.enqueue(conversation_id, recv_id, String::new(), session_type::SINGLE_CHAT, content_type::TEXT, content)
+    }
+
+    /// 发送群文本消息，返回发件箱 ID
+    pub fn send_group_text_message(&self, group_id: String, text: String) -> anyhow::Result<i64> {
+        let conversation_id = conversation::group_conversation_id(&group_id);
+        let content = serde_json::to_vec(&TextElem { content: text })?;
+        self.enqueue(conversation_id, String::new(), group_id, session_type::READ_GROUP_CHAT, content_type::TEXT, content)
+    }
+
+    /// 写入发件箱
+    fn enqueue(
+        &self,
+        conversation_id: String,
+        recv_id: String,
+        group_id: String,
+        session_type: i32,
+        content_type: i32,
+        content: Vec<u8>,
+    ) -> anyhow::Result<i64> {
+        let now = chrono::Utc::now().timestamp_millis();
+        let client_msg_id = self.msg_id_gen.next(now);
+        let entry = self.outbox.enqueue(
+            OutgoingMessage {
+                client_msg_id,
+                conversation_id,
+                recv_id,
+                group_id,
+                session_type,
+                content_type,
+                content,
+            },
+            now,
+        )?;
+        Ok(entry.id)
+    }
+
+    /// 重新发送已失败的消息（对方收到的顺序在失败期间发出的消息之后）
+    pub fn retry_message(&self, outbox_id: i64) -> anyhow::Result<()> {
+        self.outbox.retry(outbox_id, chrono::Utc::now().timestamp_millis())?;
+        Ok(())
+    }
+
+    /// 在会话中发送正在输入 / 停止输入状态（单聊或群聊），按会话限流，不保证送达
+    pub fn send_typing(&self, conversation_id: String, typing: bool) -> anyhow::Result<()> {
+        let (session_type, recv_id, group_id) = conversation::parse_conversation_id(&conversation_id, &self.user_id)
+            .ok_or_else(|| OpenIMError::NotFound(format!("会话 {}", conversation_id)))?;
+        let now = chrono::Utc::now().timestamp_millis();
+        if !self.typing_limiter.should_send(&conversation_id, typing, now) {
+            return Ok(());
+        }
+        let tips = if typing { "yes" } else { "no" };
+        let msg = MsgData {
+            send_id: self.user_id.clone(),
+            recv_id,
+            group_id,
+            client_msg_id: self.msg_id_gen.next(now),
+            sender_platform_id: self.platform_id,
+            session_type,
+            msg_from: MSG_FROM_USER,
+            content_type: content_type::TYPING,
+            content: serde_json::to_vec(&TypingElem { msg_tips: tips.to_string() })?,
+            create_time: now,
+            options: typing::signal_options(),
+            ..Default::default()
+        };
+        self.handle.signal(msg);
+        Ok(())
+    }
+
+    /// 订阅某个会话中对方的正在输入状态（超时自动变为停止输入）
+    pub fn typing_stream(&self, conversation_id: String, sink: StreamSink<TypingStatus>) {
+        forward_stream_with(self.typing.subscribe(), sink, move |event| {
+            (event.conversation_id == conversation_id).then(|| event.into())
+        });
+    }
+
+    /// 搜索本地消息（按发送时间倒序分页，摘要中标出命中位置）
+    pub fn search_messages(&self, query: MessageSearchQuery) -> anyhow::Result<MessageSearchPage> {
+        let page = self.messages.search(&SearchQuery {
+            keyword: query.keyword,
+            conversation_id: query.conversation_id,
+            sender_id: query.sender_id,
+            content_types: query.content_types,
+            start_time: query.start_time,
+            end_time: query.end_time,
+            offset: query.offset,
+            limit: query.limit,
+        })?;
+        Ok(MessageSearchPage {
+            hits: page.hits.into_iter().map(Into::into).collect(),
+            has_more: page.has_more,
+        })
+    }
+
+    /// 订阅消息发送状态变化
+    pub fn message_status_stream(&self, sink: StreamSink<MessageStatusEvent>) {
+        forward_stream(self.outbox.subscribe(), sink);
+    }
+
+    /// 当前使用的 token
+    pub fn token(&self) -> String {
+        self.tokens.token()
+    }
+
+    /// 设置 token 刷新回调（返回新的 token），token 即将过期或被服务器拒绝时调用，之后自动重连
+    pub fn set_token_refresher(&self, callback: impl Fn() -> DartFnFuture<String> + Send + Sync + 'static) {
+        self.tokens.set_refresher(Arc::new(DartTokenRefresher { callback }));
+    }
+
+    /// 设置 Rust 端实现的 token 刷新（如 [`crate::openim::token::CredentialsRefresher`]）
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn use_token_refresher(&self, refresher: Arc<dyn TokenRefresher>) {
+        self.tokens.set_refresher(refresher);
+    }
+
+    /// 订阅 token 状态变化
+    pub fn token_status_stream(&self, sink: StreamSink<TokenStatus>) {
+        forward_stream(self.tokens.subscribe(), sink);
+    }
+
+    /// REST API 客户端（使用当前 api_url 和 token）
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn rest_api(&self) -> ApiClient {
+        ApiClient::new(self.api_url.clone(), self.tokens.token(), self.http.clone())
+    }
+
     /// 构建 WebSocket 连接 URL
     fn build_url(&self, operation_id: &str) -> String {
         format!(
             "{}/?token={}&sendID={}&platformID={}&operationID={}&compression=gzip&isBackground=false&isMsgResp=true&sdkType=js",
-            self.ws_url, self.token, self.user_id, self.platform_id, operation_id
+            self.ws_url, self.tokens.token(), self.user_id, self.platform_id, operation_id
         )
     }
 
@@ -116,173 +556,825 @@ impl OpenIMClient {
         !set.insert(msg_id.to_string())
     }
 
-    /// 连接并运行客户端
-    pub async fn connect_and_run(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let operation_id = format!("{}", chrono::Utc::now().timestamp_millis());
-        let url = self.build_url(&operation_id);
+    /// 订阅收到的新消息（Rust 端使用，如命令行工具）
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_messages(&self) -> broadcast::Receiver<StoredMessage> {
+        self.incoming.subscribe()
+    }
 
-        println!("🔗 连接到 OpenIM Server...");
-        println!("   用户: {}", self.user_id);
-        println!("   平台: {}", self.platform_id);
+    /// 订阅消息发送状态变化（Rust 端使用）
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn subscribe_message_status(&self) -> broadcast::Receiver<OutboxEvent> {
+        self.outbox.subscribe()
+    }
 
-        let (ws_stream, response) = connect_async(&url).await?;
-        println!("✅ WebSocket 连接成功! 状态: {}", response.status());
+    /// 连接任务的句柄，可克隆后在其他任务中发送命令（Rust 端使用）
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn handle(&self) -> ClientHandle {
+        self.handle.clone()
+    }
 
-        let (mut write, mut read) = ws_stream.split();
+    /// 切换前后台状态，进入后台后服务器改为离线推送
+    pub async fn set_app_background(&self, background: bool) -> anyhow::Result<()> {
+        self.handle.set_background(background).await?;
+        Ok(())
+    }
 
-        // 等待连接成功响应
-        if let Some(Ok(WsMessage::Text(text))) = read.next().await {
-            if let Ok(resp) = serde_json::from_str::<ServerResponse>(&text) {
-                if resp.err_code == 0 {
-                    println!("✅ 服务器响应成功");
-                } else {
-                    println!("❌ 服务器返回错误: {} - {}", resp.err_code, resp.err_msg);
-                    return Ok(());
+    /// 断开连接：发送 close 帧，取消后台同步任务，等待响应的请求以取消错误结束，`connect_and_run` 随后返回
+    ///
+    /// 发件箱中未确认的消息保留在数据库中，下次连接时重新发送。
+    pub async fn disconnect(&self) -> anyhow::Result<()> {
+        self.cancel_background_tasks();
+        self.handle.disconnect().await?;
+        Ok(())
+    }
+
+    /// 退出登录：通知服务器后断开连接并关闭本地数据库，之后客户端不可再使用
+    ///
+    /// 保存的登录 token 由 auth 模块的 `logout` 清除。
+    pub async fn logout(&self) -> anyhow::Result<()> {
+        if self.handle.is_connected() {
+            if let Err(e) = self.handle.request(msg_type::WS_LOGOUT_MSG, Vec::new()).await {
+                tracing::warn!(error = %e, "通知服务器退出登录失败");
+            }
+        }
+        self.close().await?;
+        tracing::info!("已退出登录");
+        Ok(())
+    }
+
+    /// 断开连接并关闭本地数据库（不通知服务器），之后客户端不可再使用
+    pub(crate) async fn close(&self) -> anyhow::Result<()> {
+        self.disconnect().await?;
+        self.store.close()?;
+        Ok(())
+    }
+
+    /// 启动后台同步任务
+    fn spawn_background(&self, task: impl std::future::Future<Output = ()> + Send + 'static) {
+        let mut tasks = self.tasks.lock().unwrap();
+        tasks.retain(|t| !t.is_finished());
+        tasks.push(tokio::spawn(task).abort_handle());
+    }
+
+    fn cancel_background_tasks(&self) {
+        for task in self.tasks.lock().unwrap().drain(..) {
+            task.abort();
+        }
+    }
+
+    /// 按 seq 区间 [begin, end] 拉取会话消息并写入本地数据库（Rust 端使用），表情回应变化只记录 seq
+    #[flutter_rust_bridge::frb(ignore)]
+    pub async fn pull_messages_by_seq(
+        &self,
+        conversation_id: &str,
+        begin: i64,
+        end: i64,
+    ) -> Result<Vec<StoredMessage>, OpenIMError> {
+        let msgs = self.fetch_messages_by_seq(conversation_id, begin, end).await?;
+        let mut saved = Vec::with_capacity(msgs.len());
+        for message in msgs {
+            // 表情回应变化只记录 seq：拉取的是较早的消息，当前的回应已由之后的通知更新
+            if is_reaction_change(message.content_type) {
+                self.messages.save_skipped_seq(conversation_id, message.seq)?;
+                continue;
+            }
+            self.messages.save(&message)?;
+            saved.push(message);
+        }
+        Ok(saved)
+    }
+
+    /// 按 seq 区间 [begin, end] 从服务器拉取会话消息（不入库）
+    async fn fetch_messages_by_seq(
+        &self,
+        conversation_id: &str,
+        begin: i64,
+        end: i64,
+    ) -> Result<Vec<StoredMessage>, OpenIMError> {
+        use openim_protocol::sdkws;
+
+        let req = sdkws::PullMessageBySeqsReq {
+            user_id: self.user_id.clone(),
+            seq_ranges: vec![sdkws::SeqRange {
+                conversation_id: conversation_id.to_string(),
+                begin,
+                end,
+                num: end - begin + 1,
+            }],
+            order: 0,
+        };
+        let data = self.handle.request(msg_type::WS_PULL_MSG_BY_SEQ_LIST, req.encode_to_vec()).await?;
+        let mut pulled = sdkws::PullMessageBySeqsResp::decode(data.as_slice())
+            .map_err(|e| OpenIMError::Decode(format!("PullMessageBySeqsResp: {}", e)))?;
+        let msgs = pulled
+            .msgs
+            .remove(conversation_id)
+            .or_else(|| pulled.notification_msgs.remove(conversation_id))
+            .map(|m| m.msgs)
+            .unwrap_or_default();
+        Ok(msgs.iter().map(|msg| StoredMessage::from_msg_data(conversation_id, msg)).collect())
+    }
+
+    /// 读取 before_seq 之前（不含，0 表示从最新开始）的一页历史消息，本地缺失的 seq 从服务器补齐（Rust 端使用）
+    ///
+    /// 未连接或查询、拉取失败时只返回本地已有的消息，这时 has_more 以返回的最早一条消息为准。
+    /// 还没有分配 seq 的消息（正在发送）不在历史中。
+    #[flutter_rust_bridge::frb(ignore)]
+    pub async fn load_history(
+        &self,
+        conversation_id: &str,
+        before_seq: i64,
+        limit: u32,
+    ) -> Result<HistoryPage, OpenIMError> {
+        let limit = i64::from(limit.clamp(1, MAX_HISTORY_PAGE));
+        let mut end = if before_seq > 0 { before_seq - 1 } else { self.messages.max_seq(conversation_id)?.unwrap_or(0) };
+        let mut min_seq = 1;
+        let mut span = history::page_span(end, limit, min_seq);
+        let mut messages = match span {
+            Some(span) => self.messages.by_seq_range(conversation_id, span.begin, span.end)?,
+            None => Vec::new(),
+        };
+        // 本地不完整或读取最新一页时向服务器确认 seq 范围并补齐
+        let mut complete = match span {
+            Some(span) => self.messages.present_seqs(conversation_id, span.begin, span.end)?.len() == span.len(),
+            None => false,
+        };
+        if self.handle.is_connected() && (before_seq <= 0 || !complete) {
+            match self.newest_seqs().await {
+                Ok(seqs) => {
+                    min_seq = seqs.min_seqs.get(conversation_id).copied().unwrap_or(1).max(1);
+                    let server_max = seqs.max_seqs.get(conversation_id).copied().unwrap_or(0);
+                    end = if before_seq > 0 { end.min(server_max) } else { end.max(server_max) };
+                    span = history::page_span(end, limit, min_seq);
+                    if let Some(span) = span {
+                        let present = self.messages.present_seqs(conversation_id, span.begin, span.end)?;
+                        complete = true;
+                        for gap in history::missing_spans(span, &present) {
+                            tracing::debug!(conversation_id, begin = gap.begin, end = gap.end, "补齐历史消息");
+                            if let Err(e) = self.pull_messages_by_seq(conversation_id, gap.begin, gap.end).await {
+                                tracing::warn!(conversation_id, error = %e, "拉取历史消息失败，只返回本地消息");
+                                complete = false;
+                                break;
+                            }
+                        }
+                        messages = self.messages.by_seq_range(conversation_id, span.begin, span.end)?;
+                    } else {
+                        messages.clear();
+                    }
                 }
+                Err(e) => tracing::warn!(conversation_id, error = %e, "查询最大 seq 失败，只返回本地消息"),
             }
         }
+        // 不完整的一页之后从返回的最早一条消息继续加载，缺失的 seq 下次再补齐
+        let has_more = if complete {
+            span.is_some_and(|span| span.begin > min_seq)
+        } else {
+            messages.first().is_some_and(|m| m.seq > min_seq)
+        };
+        Ok(HistoryPage { messages, has_more })
+    }
 
-        println!("\n💓 启动心跳...");
-        println!("📥 监听消息...\n");
+    /// 服务器上每个会话的最大和最小 seq
+    pub(crate) async fn newest_seqs(&self) -> Result<openim_protocol::sdkws::GetMaxSeqResp, OpenIMError> {
+        use openim_protocol::sdkws;
 
-        // 启动心跳任务（静默）
-        let heartbeat_task = tokio::spawn(async move {
-            let mut ticker = interval(Duration::from_secs(25));
-            loop {
-                ticker.tick().await;
-                if write.send(WsMessage::Ping(vec![])).await.is_err() {
-                    break;
+        let req = sdkws::GetMaxSeqReq { user_id: self.user_id.clone() };
+        let data = self.handle.request(msg_type::WS_GET_NEWEST_SEQ, req.encode_to_vec()).await?;
+        sdkws::GetMaxSeqResp::decode(data.as_slice()).map_err(|e| OpenIMError::Decode(format!("GetMaxSeqResp: {}", e)))
+    }
+
+    /// 当前连接状态
+    pub fn connection_state(&self) -> ConnectionStatus {
+        self.handle.state().into()
+    }
+
+    /// 订阅连接状态（先推送当前状态），用于显示“连接中…”等提示
+    pub fn connection_state_stream(&self, sink: StreamSink<ConnectionStatus>) {
+        let events = self.handle.state_machine().subscribe();
+        forward_stream_with_initial(events, sink, Some(self.handle.state().into()));
+    }
+
+    /// 设置连接状态，不允许的切换只记录错误
+    fn set_state(&self, state: ConnectionState) {
+        if let Err(e) = self.handle.state_machine().transition(state) {
+            tracing::error!(error = %e, "连接状态切换失败");
+        }
+    }
+
+    /// 替换建立连接的方式（默认 tokio-tungstenite），用于测试或其他网络栈
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn use_connector(&mut self, connector: Arc<dyn Connector>) {
+        self.connector = connector;
+    }
+
+    /// 连接并运行客户端，直到主动断开、被踢下线或 token 失效
+    ///
+    /// 连接中断后按 1s、2s、4s…（最长 30s）的间隔重连；token 即将过期或握手时被拒绝会调用刷新回调，
+    /// 换成新 token 后重连。发件箱中的消息保留到重连后发送。每次连接成功后自动同步（见 [`crate::openim::sync`]）。
+    pub async fn connect_and_run(&self) -> anyhow::Result<()> {
+        let span = tracing::info_span!("openim", user_id = %self.user_id, platform_id = self.platform_id);
+        self.run_until_closed().instrument(span).await
+    }
+
+    async fn run_until_closed(&self) -> anyhow::Result<()> {
+        if self.handle.state().is_active() {
+            anyhow::bail!("客户端已在运行");
+        }
+        self.handle.reset_stop();
+        self.kicked.store(false, Ordering::Relaxed);
+        let result = self.run_sessions().await;
+        // 被踢下线、token 失效时保留对应状态
+        if self.handle.state().is_active() {
+            self.set_state(ConnectionState::Closed);
+        }
+        result
+    }
+
+    async fn run_sessions(&self) -> anyhow::Result<()> {
+        if self.tokens.is_expired(chrono::Utc::now().timestamp()) && self.tokens.has_refresher() {
+            if let Err(e) = self.tokens.refresh().await {
+                self.set_state(ConnectionState::TokenExpired);
+                return Err(e.into());
+            }
+        }
+        // 刷新后仍被拒绝时不再重试，避免死循环
+        let mut refreshed_after_reject = false;
+        let mut attempt = 0;
+        loop {
+            if self.handle.stop_requested() {
+                return Ok(());
+            }
+            self.set_state(ConnectionState::Connecting);
+            let end = match self.run_session().await {
+                Ok(end) => end,
+                Err(e) => {
+                    tracing::warn!(error = %e, "连接失败");
+                    SessionEnd::Lost
                 }
+            };
+            // 断开后不再检查超时，也收不到停止输入信令
+            self.typing.clear();
+            // 握手成功过：重新计数，之后 token 再被拒绝时允许再刷新一次
+            if self.handle.state().is_connected() {
+                attempt = 0;
+                refreshed_after_reject = false;
             }
-        });
+            match end {
+                SessionEnd::Closed => return Ok(()),
+                SessionEnd::Kicked => {
+                    self.set_state(ConnectionState::Kicked);
+                    return Ok(());
+                }
+                SessionEnd::Lost => {
+                    attempt += 1;
+                    let backoff = connection::backoff_delay(attempt);
+                    self.set_state(ConnectionState::Reconnecting { attempt, backoff });
+                    tracing::warn!(attempt, backoff_ms = backoff.as_millis() as u64, "连接中断，稍后重连");
+                    tokio::select! {
+                        _ = tokio::time::sleep(backoff) => {}
+                        _ = self.handle.stopped() => return Ok(()),
+                    }
+                }
+                SessionEnd::TokenRefreshed => tracing::info!("使用新 token 重连"),
+                SessionEnd::TokenRejected { code, msg } => {
+                    if code == token::token_err::KICKED {
+                        self.set_state(ConnectionState::Kicked);
+                        anyhow::bail!("连接被拒绝: {} - {}", code, msg);
+                    }
+                    self.set_state(ConnectionState::TokenExpired);
+                    if refreshed_after_reject || !token::is_refreshable_error(code) {
+                        anyhow::bail!("连接被拒绝: {} - {}", code, msg);
+                    }
+                    tracing::warn!(code, "token 已失效，刷新后重连");
+                    self.tokens.refresh().await?;
+                    refreshed_after_reject = true;
+                }
+            }
+        }
+    }
+
+    /// 建立一次连接并运行到断开
+    async fn run_session(&self) -> anyhow::Result<SessionEnd> {
+        let operation_id = format!("{}", chrono::Utc::now().timestamp_millis());
+        let span = tracing::info_span!("session", operation_id = %operation_id);
+        self.run_connection(&operation_id).instrument(span).await
+    }
+
+    async fn run_connection(&self, operation_id: &str) -> anyhow::Result<SessionEnd> {
+        let url = self.build_url(operation_id);
+
+        tracing::info!(ws_url = %self.ws_url, "连接 OpenIM Server");
+        let transport = self.connector.connect(url).await?;
+        let transport = transport.as_ref();
+        self.set_state(ConnectionState::Handshaking);
+
+        // 等待连接成功响应，没有收到有效的握手响应时按连接中断处理
+        let text = match tokio::time::timeout(connection::HANDSHAKE_TIMEOUT, transport.recv()).await {
+            Ok(Some(Ok(Frame::Text(text)))) => text,
+            Ok(Some(Ok(frame))) => {
+                tracing::warn!(frame = ?frame, "握手响应不是文本帧");
+                let _ = transport.close().await;
+                return Ok(SessionEnd::Lost);
+            }
+            Ok(Some(Err(e))) => {
+                tracing::warn!(error = %e, "接收握手响应失败");
+                return Ok(SessionEnd::Lost);
+            }
+            Ok(None) => {
+                tracing::warn!("握手前连接已关闭");
+                return Ok(SessionEnd::Lost);
+            }
+            Err(_) => {
+                tracing::warn!("等待握手响应超时");
+                let _ = transport.close().await;
+                return Ok(SessionEnd::Lost);
+            }
+        };
+        self.record_frame(Direction::Inbound, FrameKind::Text, text.as_bytes());
+        let resp = match serde_json::from_str::<ServerResponse>(&text) {
+            Ok(resp) => resp,
+            Err(e) => {
+                tracing::warn!(error = %e, text = %text, "无法解析的握手响应");
+                let _ = transport.close().await;
+                return Ok(SessionEnd::Lost);
+            }
+        };
+        if resp.err_code != 0 {
+            // 只有 token 错误需要刷新 token 或停止重连，其他错误（如服务器内部错误）稍后重连
+            if !token::is_token_error(resp.err_code) {
+                tracing::warn!(code = resp.err_code, msg = %resp.err_msg, dlt = %resp.err_dlt, "握手失败");
+                let _ = transport.close().await;
+                return Ok(SessionEnd::Lost);
+            }
+            tracing::error!(code = resp.err_code, msg = %resp.err_msg, dlt = %resp.err_dlt, "握手被拒绝");
+            return Ok(SessionEnd::TokenRejected { code: resp.err_code, msg: resp.err_msg });
+        }
+        tracing::info!("握手成功");
+
+        self.set_state(ConnectionState::Connected);
+        // 连接期间请求了断开
+        if self.handle.stop_requested() {
+            let _ = transport.close().await;
+            return Ok(SessionEnd::Closed);
+        }
+
+        // 写任务：心跳 + 发送发件箱消息，与读循环并发运行
+        let writer = self.run_writer(transport);
+        tokio::pin!(writer);
+        // 同步同样与读循环并发运行（请求经由写任务发出），连接断开时中断，重连后重新同步
+        let initial_sync = self.run_sync();
+        tokio::pin!(initial_sync);
+        let mut syncing = self.sync_on_connect.load(Ordering::Relaxed);
+        // 乱序消息超时后拉取缺失的区间
+        let gap_filler = self.run_gap_filler();
+        tokio::pin!(gap_filler);
 
         // 监听消息循环
-        while let Some(msg_result) = read.next().await {
+        let mut end = SessionEnd::Lost;
+        loop {
+            let msg_result = tokio::select! {
+                msg = transport.recv() => match msg {
+                    Some(m) => m,
+                    None => break,
+                },
+                exit = &mut writer => {
+                    end = exit;
+                    break;
+                }
+                () = &mut initial_sync, if syncing => {
+                    syncing = false;
+                    continue;
+                }
+                () = &mut gap_filler => continue,
+            };
             match msg_result {
-                Ok(WsMessage::Text(text)) => {
-                    println!("\n📨 收到文本消息:");
-                    println!("   {}", text);
-                    
-                    // 尝试解析为 OpenIMResp
-                    if let Ok(resp) = serde_json::from_str::<OpenIMResp>(&text) {
-                        println!("   请求标识: {}", resp.req_identifier);
-                        println!("   错误码: {}", resp.err_code);
-                        if !resp.data.is_empty() {
-                            println!("   数据: {} bytes", resp.data.len());
+                Ok(Frame::Text(text)) => {
+                    self.record_frame(Direction::Inbound, FrameKind::Text, text.as_bytes());
+                    match serde_json::from_str::<OpenIMResp>(&text) {
+                        Ok(resp) => tracing::debug!(
+                            req_identifier = resp.req_identifier,
+                            err_code = resp.err_code,
+                            data_len = resp.data.len(),
+                            "收到文本响应"
+                        ),
+                        Err(_) => tracing::debug!(text = %text, "收到文本消息"),
+                    }
+                }
+                Ok(Frame::Binary(data)) => {
+                    self.record_frame(Direction::Inbound, FrameKind::Binary, &data);
+                    if let Err(e) = self.handle_binary_frame(&data) {
+                        tracing::warn!(error = %e, len = data.len(), "响应解析失败");
+                    }
+                }
+                Ok(Frame::Ping(_) | Frame::Pong(_)) => {
+                    // Ping / Pong 静默处理
+                }
+                Ok(Frame::Close(reason)) => {
+                    tracing::info!(reason = ?reason, "服务器关闭连接");
+                    break;
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "接收消息失败");
+                    break;
+                }
+            }
+        }
+
+        if syncing {
+            self.sync_progress.interrupt();
+        }
+        // 未确认的消息保留在发件箱中，下次连接时重新发送；等待响应的请求直接失败
+        self.outbox.reset_in_flight();
+        self.pending.lock().unwrap().clear();
+        // 等待缺失消息的乱序消息直接入库，缺失的 seq 由历史消息补齐
+        self.deliver(self.ordering.flush());
+        self.ordering.reset();
+        if self.kicked.swap(false, Ordering::Relaxed) {
+            end = SessionEnd::Kicked;
+        }
+
+        tracing::info!("连接已断开");
+        Ok(end)
+    }
+
+    /// 连接后的同步：每个阶段失败只记录错误并继续下一阶段，失败的阶段在下次连接时重试
+    async fn run_sync(&self) {
+        self.set_state(ConnectionState::Syncing);
+        let api = self.rest_api();
+        let mut failed = Vec::new();
+        for phase in SyncPhase::ALL {
+            self.sync_progress.report(phase, 0, 1);
+            let result = match phase {
+                SyncPhase::SelfInfo => self
+                    .profiles
+                    .get(&api, std::slice::from_ref(&self.user_id), true, chrono::Utc::now().timestamp_millis())
+                    .await
+                    .map(drop),
+                SyncPhase::Friends => self.contacts.sync_incremental(&api, &self.user_id).await,
+                SyncPhase::Groups => self.groups.sync_joined_groups_incremental(&api, &self.user_id).await,
+                SyncPhase::Conversations => self.conversations.sync_incremental(&api, &self.user_id).await,
+                SyncPhase::Messages => self.sync_messages().await,
+            };
+            if let Err(e) = result {
+                tracing::warn!(phase = ?phase, error = %e, "同步失败");
+                failed.push(phase);
+            }
+        }
+        tracing::info!(failed = ?failed, "同步完成");
+        self.sync_progress.finish(failed);
+        self.set_state(ConnectionState::Connected);
+    }
+
+    /// 补齐缺失的消息：比较服务器和本地每个会话的最大 seq，逐个会话拉取
+    async fn sync_messages(&self) -> Result<(), OpenIMError> {
+        let seqs = self.newest_seqs().await?;
+        let gaps = sync::missing_ranges(&seqs.max_seqs, &seqs.min_seqs, &self.messages.max_seqs()?, SYNC_MSG_LIMIT);
+        tracing::debug!(conversations = gaps.len(), "补齐缺失的消息");
+        for (i, gap) in gaps.iter().enumerate() {
+            let msgs = self.fetch_messages_by_seq(&gap.conversation_id, gap.begin, gap.end).await?;
+            self.deliver(self.ordering.accept_range(&gap.conversation_id, gap.begin, gap.end, msgs));
+            self.sync_progress.report(SyncPhase::Messages, i + 1, gaps.len());
+        }
+        Ok(())
+    }
+
+    /// 等待乱序消息到期，拉取缺失的区间后按顺序投递（失败时到期后重试）
+    async fn run_gap_filler(&self) {
+        loop {
+            tokio::select! {
+                _ = Self::sleep_until(self.ordering.next_deadline()) => {}
+                _ = self.ordering.notified() => continue,
+            }
+            for gap in self.ordering.expired_gaps(tokio::time::Instant::now(), SYNC_MSG_LIMIT) {
+                tracing::debug!(conversation_id = %gap.conversation_id, begin = gap.begin, end = gap.end, "拉取缺失的消息");
+                match self.fetch_messages_by_seq(&gap.conversation_id, gap.begin, gap.end).await {
+                    Ok(msgs) => self.deliver(self.ordering.accept_range(&gap.conversation_id, gap.begin, gap.end, msgs)),
+                    Err(e) => tracing::warn!(conversation_id = %gap.conversation_id, error = %e, "拉取缺失的消息失败"),
+                }
+            }
+        }
+    }
+
+    /// 解析服务器下发的二进制帧（gzip 压缩的 JSON）并按消息类型处理
+    pub(crate) fn handle_binary_frame(&self, data: &[u8]) -> Result<(), OpenIMError> {
+        let decompressed = capture::decompress(data).map_err(|e| OpenIMError::Decode(format!("gzip 解压失败: {}", e)))?;
+        let resp = serde_json::from_slice::<OpenIMResp>(&decompressed)?;
+        tracing::trace!(
+            req_identifier = resp.req_identifier,
+            msg_incr = %resp.msg_incr,
+            err_code = resp.err_code,
+            "收到响应"
+        );
+        let pending = self.pending.lock().unwrap().remove(&resp.msg_incr);
+        if let Some(reply) = pending {
+            let result = match resp.err_code {
+                0 => Ok(resp.data),
+                code => Err(OpenIMError::Api { code, msg: resp.err_msg, dlt: String::new() }),
+            };
+            let _ = reply.send(result);
+            return Ok(());
+        }
+        match resp.req_identifier {
+            msg_type::WS_SEND_MSG => self.handle_send_msg_resp(&resp),
+            msg_type::WS_SEND_SIGNAL_MSG => {
+                if resp.err_code != 0 {
+                    tracing::warn!(code = resp.err_code, msg = %resp.err_msg, "信令发送失败");
+                }
+            }
+            msg_type::WS_PUSH_MSG => self.handle_push_message(&resp.data)?,
+            msg_type::WS_SUB_USER_ONLINE_STATUS => self.handle_online_status(&resp),
+            msg_type::WS_SET_BACKGROUND_STATUS => {
+                if resp.err_code != 0 {
+                    tracing::warn!(code = resp.err_code, msg = %resp.err_msg, "前后台状态设置失败");
+                }
+            }
+            msg_type::WS_KICK_ONLINE_MSG => {
+                tracing::warn!("被踢下线");
+                self.kicked.store(true, Ordering::Relaxed);
+            }
+            msg_type::WS_LOGOUT_MSG => tracing::info!("收到登出消息"),
+            _ => tracing::debug!(req_identifier = resp.req_identifier, "未知消息类型"),
+        }
+        Ok(())
+    }
+
+    /// 开启录制时写入抓包文件
+    fn record_frame(&self, direction: Direction, kind: FrameKind, raw: &[u8]) {
+        let recorder = self.recorder.read().unwrap().clone();
+        if let Some(recorder) = recorder {
+            if let Err(e) = recorder.record(direction, kind, raw, chrono::Utc::now().timestamp_millis()) {
+                tracing::error!(error = %e, "写入抓包文件失败");
+            }
+        }
+    }
+
+    /// 写任务：定时心跳、执行命令，并按顺序发送发件箱中的消息（发送失败即退出）
+    ///
+    /// token 即将过期时刷新，成功后关闭连接并退出，以便用新 token 重连。
+    async fn run_writer<T: Transport + ?Sized>(&self, transport: &T) -> SessionEnd {
+        let mut refresh_at = self
+            .tokens
+            .refresh_delay(chrono::Utc::now().timestamp())
+            .map(|delay| tokio::time::Instant::now() + delay);
+        let mut heartbeat = interval(Duration::from_secs(25));
+        let mut retry_ticker = interval(Duration::from_secs(1));
+        let mut commands = self.commands.lock().await;
+        // 断线期间积压的信令和断开命令已过时，直接丢弃，其余的连接后执行
+        let backlog: Vec<_> = std::iter::from_fn(|| commands.try_recv().ok()).filter(|c| !c.is_stale()).collect();
+        self.presence.resubscribe_all();
+        if self.background.load(Ordering::Relaxed) {
+            if let Err(e) = self.send_background_status(transport, true).await {
+                tracing::error!(error = %e, "前后台状态发送失败");
+                return SessionEnd::Lost;
+            }
+        }
+        for command in backlog {
+            match self.run_command(transport, command).await {
+                Ok(None) => {}
+                Ok(Some(end)) => return end,
+                Err(e) => {
+                    tracing::error!(error = %e, "命令执行失败");
+                    return SessionEnd::Lost;
+                }
+            }
+        }
+        loop {
+            tokio::select! {
+                _ = heartbeat.tick() => {
+                    if transport.send(Frame::Ping(vec![])).await.is_err() {
+                        break;
+                    }
+                }
+                Some(command) = commands.recv() => {
+                    match self.run_command(transport, command).await {
+                        Ok(None) => {}
+                        Ok(Some(end)) => return end,
+                        Err(e) => {
+                            tracing::error!(error = %e, "命令执行失败");
+                            break;
                         }
                     }
                 }
-                Ok(WsMessage::Binary(data)) => {
-                    // 步骤 1: 解压 gzip
-                    let decompressed_data = if data.len() >= 2 && data[0] == 0x1f && data[1] == 0x8b {
-                        match Self::decompress_gzip(&data) {
-                            Ok(d) => d,
-                            Err(e) => {
-                                println!("\n❌ Gzip 解压失败: {}", e);
-                                println!("   原始数据 ({} bytes): {:?}", data.len(), &data[..data.len().min(40)]);
-                                continue;
+                _ = retry_ticker.tick() => {
+                    self.typing.expire(chrono::Utc::now().timestamp_millis());
+                }
+                _ = self.outbox.notified() => {}
+                _ = self.presence.notified() => {}
+                _ = Self::sleep_until(refresh_at) => {
+                    self.tokens.warn_expiring();
+                    if !self.tokens.has_refresher() {
+                        refresh_at = None;
+                        continue;
+                    }
+                    match self.tokens.refresh().await {
+                        Ok(()) => {
+                            // 用新 token 重连前正常关闭当前连接
+                            if let Err(e) = transport.close().await {
+                                tracing::warn!(error = %e, "关闭连接失败");
                             }
+                            return SessionEnd::TokenRefreshed;
                         }
-                    } else {
-                        data.to_vec()
-                    };
-                    
-                    // 步骤 2: 解析 JSON
-                    let resp = match serde_json::from_slice::<OpenIMResp>(&decompressed_data) {
-                        Ok(r) => r,
                         Err(e) => {
-                            println!("\n❌ JSON 解析失败: {}", e);
-                            if let Ok(json_str) = String::from_utf8(decompressed_data.clone()) {
-                                println!("   JSON 内容: {}", &json_str[..json_str.len().min(200)]);
-                            } else {
-                                println!("   数据 ({} bytes): {:?}", decompressed_data.len(), &decompressed_data[..decompressed_data.len().min(40)]);
-                            }
-                            continue;
-                        }
-                    };
-                    
-                    // 步骤 3: 根据消息类型处理
-                    match resp.req_identifier {
-                        msg_type::WS_PUSH_MSG => {
-                            self.handle_push_message(&resp.data);
-                        }
-                        msg_type::WS_KICK_ONLINE_MSG => {
-                            println!("\n⚠️ 踢下线消息");
-                        }
-                        msg_type::WS_LOGOUT_MSG => {
-                            println!("\n🚪 登出消息");
-                        }
-                        _ => {
-                            println!("\n📨 未知消息类型: {}", resp.req_identifier);
+                            tracing::error!(error = %e, "token 刷新失败");
+                            refresh_at = Some(tokio::time::Instant::now() + Duration::from_secs(token::REFRESH_RETRY_SECS));
                         }
                     }
                 }
-                Ok(WsMessage::Ping(_)) => {
-                    // Ping 静默处理
+            }
+            if let Err(e) = self.flush_outbox(transport).await {
+                tracing::error!(error = %e, "发件箱发送失败");
+                break;
+            }
+            if let Err(e) = self.flush_presence(transport).await {
+                tracing::error!(error = %e, "在线状态订阅发送失败");
+                break;
+            }
+        }
+        SessionEnd::Lost
+    }
+
+    /// 执行一条命令，需要结束连接时返回 Some
+    async fn run_command<T: Transport + ?Sized>(
+        &self,
+        transport: &T,
+        command: Command,
+    ) -> Result<Option<SessionEnd>, OpenIMError> {
+        match command {
+            Command::Signal(msg) => {
+                self.send_request(transport, msg_type::WS_SEND_SIGNAL_MSG, msg.encode_to_vec()).await?;
+            }
+            Command::Request { req_identifier, data, reply } => {
+                // 调用方已超时
+                if reply.is_closed() {
+                    return Ok(None);
                 }
-                Ok(WsMessage::Pong(_)) => {
-                    // Pong 静默处理
+                let msg_incr = self.send_request(transport, req_identifier, data).await?;
+                self.pending.lock().unwrap().insert(msg_incr, reply);
+            }
+            Command::SetBackground(background) => {
+                if self.background.swap(background, Ordering::Relaxed) != background {
+                    self.send_background_status(transport, background).await?;
                 }
-                Ok(WsMessage::Close(frame)) => {
-                    println!("\n👋 服务器关闭连接: {:?}", frame);
-                    break;
+            }
+            Command::Disconnect(done) => {
+                tracing::info!("主动断开连接");
+                self.cancel_background_tasks();
+                for (_, reply) in self.pending.lock().unwrap().drain() {
+                    let _ = reply.send(Err(OpenIMError::Cancelled));
                 }
-                Err(e) => {
-                    println!("\n❌ 接收消息错误: {}", e);
-                    break;
+                if let Err(e) = transport.close().await {
+                    tracing::warn!(error = %e, "关闭连接失败");
                 }
-                _ => {}
+                self.set_state(ConnectionState::Closed);
+                let _ = done.send(());
+                return Ok(Some(SessionEnd::Closed));
             }
         }
+        Ok(None)
+    }
 
-        // 取消心跳任务
-        heartbeat_task.abort();
-        
-        println!("\n✅ 客户端已断开");
+    async fn send_background_status<T: Transport + ?Sized>(&self, transport: &T, background: bool) -> Result<(), OpenIMError> {
+        let req = openim_protocol::sdkws::SetAppBackgroundStatusReq {
+            user_id: self.user_id.clone(),
+            is_background: background,
+        };
+        self.send_request(transport, msg_type::WS_SET_BACKGROUND_STATUS, req.encode_to_vec()).await?;
+        Ok(())
+    }
+
+    /// 发送在线状态订阅的变化
+    async fn flush_presence<T: Transport + ?Sized>(&self, transport: &T) -> Result<(), OpenIMError> {
+        if let Some((subscribe, unsubscribe)) = self.presence.take_pending() {
+            let req = openim_protocol::sdkws::SubUserOnlineStatus {
+                subscribe_user_id: subscribe,
+                unsubscribe_user_id: unsubscribe,
+            };
+            self.send_request(transport, msg_type::WS_SUB_USER_ONLINE_STATUS, req.encode_to_vec()).await?;
+        }
+        Ok(())
+    }
+
+    async fn sleep_until(deadline: Option<tokio::time::Instant>) {
+        match deadline {
+            Some(deadline) => tokio::time::sleep_until(deadline).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// 发送发件箱中到期的消息
+    async fn flush_outbox<T: Transport + ?Sized>(&self, transport: &T) -> Result<(), OpenIMError> {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.outbox.expire_in_flight(now)?;
+        for entry in self.outbox.due(now)? {
+            // 发出前登记（包括上次运行留在发件箱中的消息），服务器回推的自己的消息不会重复显示
+            self.received_msg_ids.lock().unwrap().insert(entry.client_msg_id.clone());
+            let data = self.build_msg_data(&entry).encode_to_vec();
+            let msg_incr = self.send_request(transport, msg_type::WS_SEND_MSG, data).await?;
+            self.outbox.mark_in_flight(entry.id, msg_incr, now);
+        }
         Ok(())
     }
 
+    /// 由发件箱记录构造 MsgData
+    fn build_msg_data(&self, entry: &OutboxEntry) -> MsgData {
+        MsgData {
+            send_id: self.user_id.clone(),
+            recv_id: entry.recv_id.clone(),
+            client_msg_id: entry.client_msg_id.clone(),
+            group_id: entry.group_id.clone(),
+            sender_platform_id: self.platform_id,
+            session_type: entry.session_type,
+            msg_from: MSG_FROM_USER,
+            content_type: entry.content_type,
+            content: entry.content.clone(),
+            create_time: entry.created_at,
+            ..Default::default()
+        }
+    }
+
+    /// 处理发送消息的响应，更新发件箱状态
+    fn handle_send_msg_resp(&self, resp: &OpenIMResp) {
+        let result = if resp.err_code == 0 {
+            openim_protocol::msg::SendMsgResp::decode(resp.data.as_slice())
+                .map(|r| (r.server_msg_id, r.send_time))
+                .map_err(|e| format!("SendMsgResp 解析失败: {}", e))
+        } else {
+            Err(format!("{} - {}", resp.err_code, resp.err_msg))
+        };
+        match self.outbox.ack(&resp.msg_incr, result) {
+            Ok(Some(entry)) if entry.status == OutboxStatus::Sent => {
+                tracing::debug!(
+                    msg_incr = %resp.msg_incr,
+                    client_msg_id = %entry.client_msg_id,
+                    server_msg_id = %entry.server_msg_id,
+                    "消息发送成功"
+                );
+                // 保存自己发送的消息，seq 由之后的推送补充
+                let mut msg = self.build_msg_data(&entry);
+                msg.server_msg_id = entry.server_msg_id.clone();
+                msg.send_time = entry.send_time;
+                let stored = StoredMessage::from_msg_data(&entry.conversation_id, &msg);
+                if let Err(e) = self.messages.save(&stored) {
+                    tracing::error!(error = %e, "保存消息失败");
+                }
+                if let Err(e) = self.conversations.on_message(&stored, &self.user_id) {
+                    tracing::error!(error = %e, "更新会话失败");
+                }
+            }
+            Ok(Some(entry)) => tracing::warn!(
+                msg_incr = %resp.msg_incr,
+                client_msg_id = %entry.client_msg_id,
+                status = ?entry.status,
+                error = %entry.last_error,
+                "消息发送失败"
+            ),
+            Ok(None) => tracing::debug!(msg_incr = %resp.msg_incr, "未知的发送响应"),
+            Err(e) => tracing::error!(error = %e, "更新发件箱失败"),
+        }
+    }
+
     /// 处理推送消息（使用 protocol 中的数据结构）
-    fn handle_push_message(&self, data: &[u8]) {
+    fn handle_push_message(&self, data: &[u8]) -> Result<(), OpenIMError> {
         use openim_protocol::sdkws;
         
         if data.is_empty() {
-            println!("⚠️ 推送消息数据为空");
-            return;
+            tracing::warn!("推送消息数据为空");
+            return Ok(());
         }
 
-        // 解析为 PushMessages
-        let push_msg = match sdkws::PushMessages::decode(data) {
-            Ok(pm) => pm,
-            Err(e) => {
-                println!("\n❌ [Protobuf 解析失败] {}", e);
-                println!("════════════════════════════════════");
-                println!("数据长度: {} bytes", data.len());
-                use base64::Engine;
-                println!("Base64: {}", base64::engine::general_purpose::STANDARD.encode(data));
-                println!("十六进制（前60字节）:");
-                let hex: String = data.iter()
-                    .take(60)
-                    .map(|b| format!("{:02x}", b))
-                    .collect::<Vec<_>>()
-                    .chunks(20)
-                    .map(|chunk| chunk.join(" "))
-                    .collect::<Vec<_>>()
-                    .join("\n  ");
-                println!("  {}", hex);
-                println!("════════════════════════════════════\n");
-                return;
-            }
-        };
-        
+        // 解析为 PushMessages（开启抓包录制可保存原始数据用于回放）
+        let push_msg = sdkws::PushMessages::decode(data)
+            .map_err(|e| OpenIMError::Decode(format!("PushMessages: {}", e)))?;
+        tracing::debug!(
+            conversations = push_msg.msgs.len(),
+            msgs = push_msg.msgs.values().map(|m| m.msgs.len()).sum::<usize>(),
+            notifications = push_msg.notification_msgs.values().map(|m| m.msgs.len()).sum::<usize>(),
+            "推送已解析"
+        );
+
         // 处理普通消息
         for (conv_id, pull_msgs) in &push_msg.msgs {
             for msg in &pull_msgs.msgs {
-                // 去重检查
-                if self.is_duplicate_message(&msg.client_msg_id) {
+                // 正在输入信令不展示、不去重
+                if msg.content_type == content_type::TYPING {
+                    self.handle_typing_signal(conv_id, msg);
                     continue;
                 }
-                self.print_msg_data(conv_id, msg, false);
+                self.log_msg_data(conv_id, msg, false);
+                let stored = StoredMessage::from_msg_data(conv_id, msg);
+                // 没有 seq 的消息无法排序，直接投递
+                if stored.seq <= 0 {
+                    self.deliver(vec![stored]);
+                    continue;
+                }
+                let ready = self.ordering.push(stored, tokio::time::Instant::now(), || {
+                    self.messages.max_seq(conv_id).unwrap_or_else(|e| {
+                        tracing::error!(error = %e, "读取最大 seq 失败");
+                        None
+                    })
+                });
+                self.deliver(ready);
             }
         }
         
@@ -293,101 +1385,1013 @@ impl OpenIMClient {
                 if self.is_duplicate_message(&msg.client_msg_id) {
                     continue;
                 }
-                self.print_msg_data(conv_id, msg, true);
+                self.log_msg_data(conv_id, msg, true);
+                self.handle_notification(msg);
             }
         }
+        Ok(())
     }
 
-    /// 打印消息详情（详细版，带去重）
-    fn print_msg_data(&self, conv_id: &str, msg: &openim_protocol::sdkws::MsgData, is_notification: bool) {
-        // 时间格式化
-        let time_str = chrono::DateTime::from_timestamp_millis(msg.send_time)
-            .map(|dt| dt.format("%H:%M:%S").to_string())
-            .unwrap_or_else(|| "??:??:??".to_string());
-        
-        // 消息类型标识
-        let msg_icon = if is_notification { "🔔" } else { "💬" };
-        
-        println!("\n{} ═══════════════════════════════════", msg_icon);
-        println!("时间: {}", time_str);
-        println!("会话: {}", conv_id);
-        println!("发送者: {} (平台:{})", msg.send_id, msg.sender_platform_id);
-        
-        // 内容类型
-        let content_type = match msg.content_type {
-            101 => "文本", 102 => "图片", 103 => "语音", 104 => "视频",
-            105 => "文件", 106 => "@消息", 107 => "合并", 108 => "名片",
-            109 => "位置", 110 => "自定义", 111 => "撤回", 113 => "引用",
-            _ => "未知",
-        };
-        println!("类型: {} ({})", content_type, msg.content_type);
-        
-        // 解析并显示消息内容
-        println!("\n【消息内容】:");
-        if msg.content.is_empty() {
-            println!("  (空)");
-        } else if let Ok(content_str) = String::from_utf8(msg.content.clone()) {
-            // 尝试解析 JSON
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content_str) {
-                // 格式化 JSON
-                if let Ok(pretty) = serde_json::to_string_pretty(&json) {
-                    for line in pretty.lines() {
-                        println!("  {}", line);
+    /// 按顺序入库、更新会话并通知订阅者（自己发送的消息在此补上 seq）
+    pub(crate) fn deliver(&self, msgs: Vec<StoredMessage>) {
+        for stored in msgs {
+            // 表情回应变化占用会话的 seq，按顺序处理但不作为消息展示
+            if is_reaction_change(stored.content_type) {
+                if let Err(e) = self.reactions.apply_notification(stored.content_type, &stored.content) {
+                    tracing::error!(error = %e, "处理表情回应通知失败");
+                }
+                if let Err(e) = self.messages.save_skipped_seq(&stored.conversation_id, stored.seq) {
+                    tracing::error!(error = %e, "保存 seq 失败");
+                }
+                continue;
+            }
+            // 入库按 clientMsgID 去重
+            if let Err(e) = self.messages.save(&stored) {
+                tracing::error!(error = %e, "保存消息失败");
+            }
+            if let Err(e) = self.conversations.on_message(&stored, &self.user_id) {
+                tracing::error!(error = %e, "更新会话失败");
+            }
+            if !self.is_duplicate_message(&stored.client_msg_id) {
+                let _ = self.incoming.send(stored);
+            }
+        }
+    }
+
+    /// 根据通知更新本地缓存，无法直接更新的列表在后台重新拉取
+    fn handle_notification(&self, msg: &MsgData) {
+        if let Err(e) = self.groups.apply_notification(&self.user_id, msg.content_type, &msg.content) {
+            tracing::error!(error = %e, "处理群通知失败");
+        }
+        if let Err(e) = self.profiles.apply_notification(msg.content_type, &msg.content) {
+            tracing::error!(error = %e, "处理资料变更通知失败");
+        }
+        if let Err(e) = self.reactions.apply_notification(msg.content_type, &msg.content) {
+            tracing::error!(error = %e, "处理表情回应通知失败");
+        }
+        match self.conversations.apply_notification(msg.content_type, &msg.content) {
+            Ok(true) => {
+                let conversations = self.conversations.clone();
+                let api = self.rest_api();
+                let user_id = self.user_id.clone();
+                self.spawn_background(async move {
+                    if let Err(e) = conversations.sync(&api, &user_id).await {
+                        tracing::error!(error = %e, "同步会话失败");
                     }
-                } else {
-                    println!("  {}", content_str);
+                });
+            }
+            Ok(false) => {}
+            Err(e) => tracing::error!(error = %e, "处理会话通知失败"),
+        }
+        let stale = match self.contacts.apply_notification(&self.user_id, msg.content_type, &msg.content) {
+            Ok(stale) => stale,
+            Err(e) => {
+                tracing::error!(error = %e, "处理好友通知失败");
+                return;
+            }
+        };
+        for list in stale {
+            let contacts = self.contacts.clone();
+            let api = self.rest_api();
+            let user_id = self.user_id.clone();
+            self.spawn_background(async move {
+                if let Err(e) = contacts.sync(&api, &user_id, list).await {
+                    tracing::error!(list = ?list, error = %e, "同步联系人失败");
                 }
-                
-                // 如果有 content 字段，单独突出显示
-                if let Some(text) = json.get("content").and_then(|v| v.as_str()) {
-                    println!("\n💬 文本: \"{}\"", text);
+            });
+        }
+    }
+
+    /// 处理订阅响应和在线状态推送
+    fn handle_online_status(&self, resp: &OpenIMResp) {
+        if resp.err_code != 0 {
+            tracing::warn!(code = resp.err_code, msg = %resp.err_msg, "在线状态订阅失败");
+            return;
+        }
+        match openim_protocol::sdkws::SubUserOnlineStatusTips::decode(resp.data.as_slice()) {
+            Ok(tips) => {
+                for elem in tips.subscribers {
+                    self.presence.apply(&elem.user_id, elem.online_platform_i_ds);
                 }
-            } else {
-                // 纯文本
-                println!("  {}", content_str);
             }
-        } else {
-            // 二进制内容
-            println!("  [二进制数据 {} bytes]", msg.content.len());
-            println!("  十六进制: {:02x?}", &msg.content[..msg.content.len().min(40)]);
+            Err(e) => tracing::error!(error = %e, "在线状态解析失败"),
         }
-        
-        println!("═══════════════════════════════════════\n");
     }
 
-    /// 解压 gzip 数据
-    fn decompress_gzip(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
-        let mut decoder = GzDecoder::new(data);
-        let mut decompressed = Vec::new();
-        decoder.read_to_end(&mut decompressed)?;
-        Ok(decompressed)
+    /// 处理对方的正在输入信令
+    fn handle_typing_signal(&self, conv_id: &str, msg: &MsgData) {
+        if msg.send_id == self.user_id {
+            return;
+        }
+        let typing = serde_json::from_slice::<TypingElem>(&msg.content)
+            .map(|elem| elem.msg_tips == "yes")
+            .unwrap_or(false);
+        self.typing.on_signal(conv_id, &msg.send_id, typing, chrono::Utc::now().timestamp_millis());
     }
 
-    /// 发送请求到服务器（使用 protocol 中的数据结构）
-    #[allow(dead_code)]
-    pub async fn send_request(
+    /// 记录收到的消息（文本内容只在 debug 级别输出）
+    fn log_msg_data(&self, conv_id: &str, msg: &MsgData, is_notification: bool) {
+        let text = serde_json::from_slice::<serde_json::Value>(&msg.content)
+            .ok()
+            .and_then(|json| json.get("content").and_then(|v| v.as_str()).map(str::to_string));
+        tracing::debug!(
+            conversation_id = %conv_id,
+            client_msg_id = %msg.client_msg_id,
+            send_id = %msg.send_id,
+            sender_platform_id = msg.sender_platform_id,
+            content_type = msg.content_type,
+            seq = msg.seq,
+            send_time = msg.send_time,
+            notification = is_notification,
+            text = text.as_deref(),
+            "收到消息"
+        );
+    }
+
+    /// 发送请求到服务器（使用 protocol 中的数据结构），返回本次请求的 msgIncr
+    async fn send_request<T: Transport + ?Sized>(
         &self,
-        write: &mut futures_util::stream::SplitSink<
-            tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
-            WsMessage,
-        >,
+        transport: &T,
         req_identifier: i32,
         data: Vec<u8>,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<String, OpenIMError> {
+        let msg_incr = self.msg_incr.fetch_add(1, Ordering::Relaxed).to_string();
         let req = OpenIMReq {
             req_identifier,
-            token: self.token.clone(),
+            token: self.tokens.token(),
             send_id: self.user_id.clone(),
             operation_id: format!("{}", chrono::Utc::now().timestamp_millis()),
-            msg_incr: "1".to_string(),
+            msg_incr: msg_incr.clone(),
             data,
         };
 
         let json = serde_json::to_vec(&req)?;
-        write.send(WsMessage::Binary(json)).await?;
+        self.record_frame(Direction::Outbound, FrameKind::Binary, &json);
+        transport.send(Frame::Binary(json)).await?;
         
-        println!("📤 请求已发送 (类型: {})", req_identifier);
-        Ok(())
+        tracing::debug!(req_identifier, msg_incr = %msg_incr, "请求已发送");
+        Ok(msg_incr)
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openim::mock_gateway::MockGateway;
+
+    /// 轮询直到条件满足（客户端在后台任务中处理消息）
+    async fn eventually<T>(mut check: impl FnMut() -> Option<T>) -> T {
+        for _ in 0..200 {
+            if let Some(value) = check() {
+                return value;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("等待超时");
+    }
+
+    /// 测试用客户端（不自动同步，避免额外的请求）
+    fn new_client() -> OpenIMClient {
+        let client = OpenIMClient::new("u1".to_string(), "tok".to_string(), 5).unwrap();
+        client.set_sync_on_connect(false);
+        client
+    }
+
+    #[tokio::test]
+    async fn send_receive_and_kick_with_mock_gateway() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let query = &gateway.handshakes()[0];
+            assert_eq!(query["token"], "tok");
+            assert_eq!(query["sendID"], "u1");
+
+            // 发送：网关应答后消息带上服务器 ID 入库
+            client.send_text_message("u2".to_string(), "你好".to_string()).unwrap();
+            let req = gateway.wait_request(msg_type::WS_SEND_MSG, 0).await;
+            let sent = MsgData::decode(req.data.as_slice()).unwrap();
+            assert_eq!(sent.recv_id, "u2");
+            let saved = eventually(|| client.messages.get(&sent.client_msg_id).unwrap()).await;
+            assert_eq!(saved.server_msg_id, "srv_1");
+
+            // 接收推送：入库并出现在会话列表中
+            let conversation_id = conversation::single_conversation_id("u1", "u2");
+            let seq = gateway.push_message(
+                &conversation_id,
+                MsgData {
+                    send_id: "u2".to_string(),
+                    recv_id: "u1".to_string(),
+                    client_msg_id: "m2".to_string(),
+                    session_type: session_type::SINGLE_CHAT,
+                    content_type: content_type::TEXT,
+                    content: r#"{"content":"在吗"}"#.as_bytes().to_vec(),
+                    send_time: saved.send_time + 1,
+                    ..Default::default()
+                },
+            );
+            assert_eq!(seq, 2);
+            let received = eventually(|| client.messages.get("m2").unwrap()).await;
+            assert_eq!(received.seq, 2);
+            let conversations = client.conversations.list().unwrap();
+            assert_eq!(conversations[0].1.as_ref().unwrap().client_msg_id, "m2");
+
+            // 被踢下线后连接结束
+            gateway.kick();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+        assert!(matches!(client.connection_state(), ConnectionStatus::Kicked));
+    }
+
+    #[tokio::test]
+    async fn echo_of_message_queued_before_restart_is_not_delivered() {
+        let dir = std::env::temp_dir().join(format!("openim-outbox-{}", uuid::Uuid::new_v4()));
+        let data_dir = dir.to_string_lossy().to_string();
+        // 上次运行时入队但未发出
+        let client_msg_id = {
+            let client = OpenIMClient::open("u1".to_string(), "tok".to_string(), 5, data_dir.clone()).unwrap();
+            let id = client.send_text_message("u2".to_string(), "你好".to_string()).unwrap();
+            client.outbox.get(id).unwrap().unwrap().client_msg_id
+        };
+
+        let gateway = MockGateway::start().await;
+        let mut client = OpenIMClient::open("u1".to_string(), "tok".to_string(), 5, data_dir).unwrap();
+        client.set_sync_on_connect(false);
+        client.ws_url = gateway.ws_url();
+        let mut incoming = client.subscribe_messages();
+
+        let script = async {
+            let req = gateway.wait_request(msg_type::WS_SEND_MSG, 0).await;
+            assert_eq!(MsgData::decode(req.data.as_slice()).unwrap().client_msg_id, client_msg_id);
+
+            // 服务器回推自己发送的消息，之后对方回复
+            let conversation_id = conversation::single_conversation_id("u1", "u2");
+            let msg = |send_id: &str, recv_id: &str, client_msg_id: &str| MsgData {
+                send_id: send_id.to_string(),
+                recv_id: recv_id.to_string(),
+                client_msg_id: client_msg_id.to_string(),
+                session_type: session_type::SINGLE_CHAT,
+                content_type: content_type::TEXT,
+                content: br#"{"content":"hi"}"#.to_vec(),
+                ..Default::default()
+            };
+            gateway.push_message(&conversation_id, msg("u1", "u2", &client_msg_id));
+            gateway.push_message(&conversation_id, msg("u2", "u1", "m2"));
+            eventually(|| client.messages.get("m2").unwrap()).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+        let delivered: Vec<_> = std::iter::from_fn(|| incoming.try_recv().ok()).map(|m| m.client_msg_id).collect();
+        assert_eq!(delivered, ["m2"]);
+        drop(client);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[tokio::test]
+    async fn send_typing_by_conversation() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            assert!(client.send_typing("sn_admin_u1".to_string(), true).is_err());
+
+            client.send_typing(conversation::single_conversation_id("u1", "u2"), true).unwrap();
+            let req = gateway.wait_request(msg_type::WS_SEND_SIGNAL_MSG, 0).await;
+            let single = MsgData::decode(req.data.as_slice()).unwrap();
+            assert_eq!((single.session_type, single.recv_id.as_str(), single.group_id.as_str()), (session_type::SINGLE_CHAT, "u2", ""));
+
+            client.send_typing(conversation::group_conversation_id("g1"), true).unwrap();
+            let req = gateway.wait_request(msg_type::WS_SEND_SIGNAL_MSG, 1).await;
+            let group = MsgData::decode(req.data.as_slice()).unwrap();
+            assert_eq!((group.session_type, group.recv_id.as_str(), group.group_id.as_str()), (session_type::READ_GROUP_CHAT, "", "g1"));
+            assert_eq!(group.content_type, content_type::TYPING);
+
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn reconnects_after_connection_lost() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let mut states = client.handle.state_machine().subscribe();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            gateway.disconnect();
+            gateway.wait_handshakes(2).await;
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+
+        use ConnectionState::*;
+        let seen: Vec<_> = std::iter::from_fn(|| states.try_recv().ok()).collect();
+        assert_eq!(
+            seen,
+            [
+                Connecting,
+                Handshaking,
+                Connected,
+                Reconnecting { attempt: 1, backoff: Duration::from_secs(1) },
+                Connecting,
+                Handshaking,
+                Connected,
+                Closed,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn refreshes_again_after_reconnecting_with_new_token() {
+        use std::sync::atomic::AtomicUsize;
+
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let counter = refreshes.clone();
+        client.set_token_refresher(move || {
+            let n = counter.fetch_add(1, Ordering::SeqCst) + 2;
+            Box::pin(async move { format!("tok{}", n) })
+        });
+        gateway.revoke_token("tok");
+
+        let script = async {
+            // 第一次握手被拒绝，刷新后用 tok2 连接成功
+            gateway.wait_handshakes(1).await;
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            // 断开后 tok2 也被拒绝，应能再刷新一次
+            gateway.revoke_token("tok2");
+            gateway.disconnect();
+            gateway.wait_handshakes(2).await;
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+        assert_eq!(refreshes.load(Ordering::SeqCst), 2);
+        let tokens: Vec<_> = gateway.handshakes().iter().map(|query| query["token"].clone()).collect();
+        assert_eq!(tokens, ["tok2", "tok3"]);
+    }
+
+    #[tokio::test]
+    async fn syncs_after_connect_and_reports_progress() {
+        use crate::openim::rest::tests::serve;
+        use crate::openim::sync::{version_key, SyncEvent};
+
+        let (api_url, server) = serve(&[
+            r#"{"errCode":0,"data":{"usersInfo":[{"userID":"u1","nickname":"我"}]}}"#,
+            r#"{"errCode":0,"data":{"versionID":"vf","version":2,"full":true,"delete":null,"insert":[{"ownerUserID":"u1","friendUser":{"userID":"u2","nickname":"Bob"}}]}}"#,
+            r#"{"errCode":0,"data":{"blacks":[]}}"#,
+            r#"{"errCode":0,"data":{"friendRequests":[]}}"#,
+            r#"{"errCode":0,"data":{"friendRequests":[]}}"#,
+            r#"{"errCode":0,"data":{"versionID":"vg","version":1,"full":true,"insert":[{"groupID":"g1","groupName":"周末爬山"}]}}"#,
+            r#"{"errCode":0,"data":{"versionID":"vc","version":5,"full":false,"update":[{"conversationID":"si_u1_u2","conversationType":1,"userID":"u2","isPinned":true}]}}"#,
+        ])
+        .await;
+        let gateway = MockGateway::start().await;
+        let mut client = OpenIMClient::new("u1".to_string(), "tok".to_string(), 5).unwrap();
+        client.ws_url = gateway.ws_url();
+        client.api_url = api_url;
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        for i in 1..=3 {
+            gateway.add_message(
+                &conversation_id,
+                MsgData {
+                    send_id: "u2".to_string(),
+                    recv_id: "u1".to_string(),
+                    client_msg_id: format!("h{}", i),
+                    session_type: session_type::SINGLE_CHAT,
+                    content_type: content_type::TEXT,
+                    content: br#"{"content":"hi"}"#.to_vec(),
+                    send_time: i,
+                    ..Default::default()
+                },
+            );
+        }
+        let mut states = client.handle.state_machine().subscribe();
+        let mut progress = client.sync_progress.subscribe();
+        let mut incoming = client.subscribe_messages();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            eventually(|| matches!(client.sync_progress.current(), Some(SyncEvent::Finished { .. })).then_some(())).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+
+        let seen: Vec<_> = std::iter::from_fn(|| progress.try_recv().ok()).collect();
+        let progress_at = |phase, percent| SyncEvent::Progress { phase, percent };
+        assert_eq!(
+            seen,
+            [
+                progress_at(SyncPhase::SelfInfo, 0),
+                progress_at(SyncPhase::Friends, 20),
+                progress_at(SyncPhase::Groups, 40),
+                progress_at(SyncPhase::Conversations, 60),
+                progress_at(SyncPhase::Messages, 80),
+                progress_at(SyncPhase::Messages, 100),
+                SyncEvent::Finished { failed: vec![] },
+            ]
+        );
+        use ConnectionState::*;
+        let seen: Vec<_> = std::iter::from_fn(|| states.try_recv().ok()).collect();
+        assert_eq!(seen, [Connecting, Handshaking, Connected, Syncing, Connected, Closed]);
+
+        // 首次同步不带版本号，之后保存服务器返回的版本号
+        let requests = server.await.unwrap();
+        assert!(requests[1].starts_with("POST /friend/get_incremental_friends "));
+        assert!(requests[1].ends_with(r#"{"userID":"u1","versionID":"","version":0}"#));
+        let version = sync::load_version(&client.store.conn().unwrap(), version_key::CONVERSATIONS).unwrap();
+        assert_eq!((version.version_id.as_str(), version.version), ("vc", 5));
+
+        assert_eq!(client.contacts.friends().unwrap()[0].user_id, "u2");
+        assert_eq!(client.groups.joined_groups().unwrap()[0].group_name, "周末爬山");
+        let conversations = client.conversations.list().unwrap();
+        assert!(conversations[0].0.is_pinned);
+        assert_eq!(conversations[0].1.as_ref().unwrap().client_msg_id, "h3");
+        let pulled: Vec<_> = std::iter::from_fn(|| incoming.try_recv().ok()).map(|m| m.seq).collect();
+        assert_eq!(pulled, [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn runs_over_memory_transport() {
+        use crate::openim::transport::MemoryConnector;
+        use base64::Engine;
+
+        let (connector, mut incoming) = MemoryConnector::new();
+        let mut client = new_client();
+        client.use_connector(Arc::new(connector));
+
+        let server = async {
+            let (url, transport) = incoming.recv().await.unwrap();
+            assert!(url.contains("sendID=u1"));
+            transport.send(Frame::Text(r#"{"errCode":0,"errMsg":"","errDlt":""}"#.to_string())).await.unwrap();
+
+            // 读循环之外发送的请求经由同一个连接发出
+            client.send_text_message("u2".to_string(), "hi".to_string()).unwrap();
+            let req = loop {
+                if let Some(Ok(Frame::Binary(data))) = transport.recv().await {
+                    break serde_json::from_slice::<serde_json::Value>(&data).unwrap();
+                }
+            };
+            assert_eq!(req["reqIdentifier"], msg_type::WS_SEND_MSG);
+
+            // 未压缩的推送同样可以解析
+            let push = openim_protocol::sdkws::PushMessages {
+                msgs: [(
+                    conversation::single_conversation_id("u1", "u2"),
+                    openim_protocol::sdkws::PullMsgs {
+                        msgs: vec![MsgData {
+                            send_id: "u2".to_string(),
+                            recv_id: "u1".to_string(),
+                            client_msg_id: "m1".to_string(),
+                            session_type: session_type::SINGLE_CHAT,
+                            content_type: content_type::TEXT,
+                            content: br#"{"content":"hi"}"#.to_vec(),
+                            seq: 1,
+                            ..Default::default()
+                        }],
+                        is_end: true,
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            };
+            let resp = serde_json::json!({
+                "reqIdentifier": msg_type::WS_PUSH_MSG,
+                "msgIncr": "",
+                "operationID": "",
+                "errCode": 0,
+                "errMsg": "",
+                "data": base64::engine::general_purpose::STANDARD.encode(push.encode_to_vec()),
+            });
+            transport.send(Frame::Binary(serde_json::to_vec(&resp).unwrap())).await.unwrap();
+            eventually(|| client.messages.get("m1").unwrap()).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), server)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn pull_messages_by_seq_range() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        for i in 1..=3 {
+            gateway.add_message(
+                &conversation_id,
+                MsgData {
+                    send_id: "u2".to_string(),
+                    recv_id: "u1".to_string(),
+                    client_msg_id: format!("h{}", i),
+                    session_type: session_type::SINGLE_CHAT,
+                    content_type: content_type::TEXT,
+                    content: br#"{"content":"hi"}"#.to_vec(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let pulled = client.pull_messages_by_seq(&conversation_id, 2, 3).await.unwrap();
+            assert_eq!(pulled.iter().map(|m| m.seq).collect::<Vec<_>>(), [2, 3]);
+            assert!(client.messages.get("h3").unwrap().is_some());
+            assert!(client.messages.get("h1").unwrap().is_none());
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn out_of_order_push_waits_for_gap_pull() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        let msg = |id: &str| MsgData {
+            send_id: "u2".to_string(),
+            recv_id: "u1".to_string(),
+            client_msg_id: id.to_string(),
+            session_type: session_type::SINGLE_CHAT,
+            content_type: content_type::TEXT,
+            content: br#"{"content":"hi"}"#.to_vec(),
+            ..Default::default()
+        };
+        let mut incoming = client.subscribe_messages();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            gateway.push_message(&conversation_id, msg("m1"));
+            assert_eq!(incoming.recv().await.unwrap().seq, 1);
+            // seq 2 没有推送，seq 3 先缓存，等待超时后拉取
+            gateway.add_message(&conversation_id, msg("m2"));
+            gateway.push_message(&conversation_id, msg("m3"));
+            let req = gateway.wait_request(msg_type::WS_PULL_MSG_BY_SEQ_LIST, 0).await;
+            let pull = openim_protocol::sdkws::PullMessageBySeqsReq::decode(req.data.as_slice()).unwrap();
+            assert_eq!((pull.seq_ranges[0].begin, pull.seq_ranges[0].end), (2, 2));
+            assert_eq!(incoming.recv().await.unwrap().client_msg_id, "m2");
+            assert_eq!(incoming.recv().await.unwrap().client_msg_id, "m3");
+            assert_eq!(client.messages.max_seq(&conversation_id).unwrap(), Some(3));
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn buffered_messages_are_saved_on_disconnect() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        let msg = |id: &str| MsgData {
+            send_id: "u2".to_string(),
+            recv_id: "u1".to_string(),
+            client_msg_id: id.to_string(),
+            session_type: session_type::SINGLE_CHAT,
+            content_type: content_type::TEXT,
+            content: br#"{"content":"hi"}"#.to_vec(),
+            ..Default::default()
+        };
+        let mut incoming = client.subscribe_messages();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            gateway.push_message(&conversation_id, msg("m1"));
+            assert_eq!(incoming.recv().await.unwrap().seq, 1);
+            gateway.add_message(&conversation_id, msg("m2"));
+            gateway.push_message(&conversation_id, msg("m3"));
+            // seq 3 等待 seq 2 期间断开连接
+            eventually(|| client.ordering.next_deadline()).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+        assert_eq!(incoming.recv().await.unwrap().client_msg_id, "m3");
+        assert!(client.messages.get("m3").unwrap().is_some());
+        assert_eq!(client.messages.max_seq(&conversation_id).unwrap(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn history_fills_missing_seqs_from_server() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        for i in 1..=6 {
+            let msg = MsgData {
+                send_id: "u2".to_string(),
+                recv_id: "u1".to_string(),
+                client_msg_id: format!("h{}", i),
+                session_type: session_type::SINGLE_CHAT,
+                content_type: content_type::TEXT,
+                content: br#"{"content":"hi"}"#.to_vec(),
+                send_time: i,
+                ..Default::default()
+            };
+            let seq = gateway.add_message(&conversation_id, msg.clone());
+            // 本地只有 seq 2 和 5
+            if seq == 2 || seq == 5 {
+                client.messages.save(&StoredMessage::from_msg_data(&conversation_id, &MsgData { seq, ..msg })).unwrap();
+            }
+        }
+
+        // 离线时只返回本地的消息
+        let page = client.load_history(&conversation_id, 0, 3).await.unwrap();
+        assert_eq!(page.messages.iter().map(|m| m.seq).collect::<Vec<_>>(), [5]);
+        assert!(page.has_more);
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let page = client.load_history(&conversation_id, 0, 3).await.unwrap();
+            assert_eq!(page.messages.iter().map(|m| m.seq).collect::<Vec<_>>(), [4, 5, 6]);
+            assert!(page.has_more);
+
+            let page = client.load_history(&conversation_id, 4, 3).await.unwrap();
+            assert_eq!(page.messages.iter().map(|m| m.client_msg_id.as_str()).collect::<Vec<_>>(), ["h1", "h2", "h3"]);
+            assert!(!page.has_more);
+
+            // [1, 3] 已补齐，再次读取不再请求服务器
+            let pulls = gateway.requests().iter().filter(|r| r.req_identifier == msg_type::WS_PULL_MSG_BY_SEQ_LIST).count();
+            assert_eq!(pulls, 4);
+            let page = client.load_history(&conversation_id, 4, 3).await.unwrap();
+            assert_eq!(page.messages.len(), 3);
+            let requests = gateway.requests();
+            assert_eq!(requests.iter().filter(|r| r.req_identifier == msg_type::WS_PULL_MSG_BY_SEQ_LIST).count(), 4);
+            assert_eq!(requests.iter().filter(|r| r.req_identifier == msg_type::WS_GET_NEWEST_SEQ).count(), 2);
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn history_returns_local_messages_when_pull_fails() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        for i in 1..=6 {
+            let msg = MsgData {
+                send_id: "u2".to_string(),
+                recv_id: "u1".to_string(),
+                client_msg_id: format!("h{}", i),
+                session_type: session_type::SINGLE_CHAT,
+                content_type: content_type::TEXT,
+                content: br#"{"content":"hi"}"#.to_vec(),
+                ..Default::default()
+            };
+            let seq = gateway.add_message(&conversation_id, msg.clone());
+            if seq >= 5 {
+                client.messages.save(&StoredMessage::from_msg_data(&conversation_id, &MsgData { seq, ..msg })).unwrap();
+            }
+        }
+        gateway.set_request_error(msg_type::WS_PULL_MSG_BY_SEQ_LIST, Some((500, "ServerInternalError")));
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let page = client.load_history(&conversation_id, 0, 3).await.unwrap();
+            assert_eq!(page.messages.iter().map(|m| m.seq).collect::<Vec<_>>(), [5, 6]);
+            assert!(page.has_more);
+
+            // 恢复后从返回的最早一条消息继续加载，补齐缺失的 seq
+            gateway.set_request_error(msg_type::WS_PULL_MSG_BY_SEQ_LIST, None);
+            let page = client.load_history(&conversation_id, 5, 3).await.unwrap();
+            assert_eq!(page.messages.iter().map(|m| m.seq).collect::<Vec<_>>(), [2, 3, 4]);
+            assert!(page.has_more);
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn history_skips_reaction_changes() {
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        let kinds = [content_type::TEXT, content_type::REACTION_MESSAGE_MODIFIER, content_type::TEXT];
+        for (i, kind) in kinds.into_iter().enumerate() {
+            gateway.add_message(
+                &conversation_id,
+                MsgData {
+                    send_id: "u2".to_string(),
+                    recv_id: "u1".to_string(),
+                    client_msg_id: format!("h{}", i + 1),
+                    session_type: session_type::SINGLE_CHAT,
+                    content_type: kind,
+                    content: br#"{"content":"hi"}"#.to_vec(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let page = client.load_history(&conversation_id, 0, 10).await.unwrap();
+            assert_eq!(page.messages.iter().map(|m| m.seq).collect::<Vec<_>>(), [1, 3]);
+            assert!(!page.has_more);
+            assert!(client.messages.get("h2").unwrap().is_none());
+            assert_eq!(client.messages.max_seq(&conversation_id).unwrap(), Some(3));
+
+            // 表情回应占用的 seq 视为已有，再次读取不再拉取
+            client.load_history(&conversation_id, 4, 10).await.unwrap();
+            let pulls = gateway.requests().iter().filter(|r| r.req_identifier == msg_type::WS_PULL_MSG_BY_SEQ_LIST).count();
+            assert_eq!(pulls, 1);
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn commands_from_cloned_handles() {
+        use openim_protocol::sdkws;
+
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        let conversation_id = conversation::single_conversation_id("u1", "u2");
+        for i in 1..=2 {
+            gateway.add_message(&conversation_id, MsgData { client_msg_id: format!("h{}", i), ..Default::default() });
+        }
+        let handle = client.handle();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            handle.set_background(true).await.unwrap();
+            let req = gateway.wait_request(msg_type::WS_SET_BACKGROUND_STATUS, 0).await;
+            assert!(sdkws::SetAppBackgroundStatusReq::decode(req.data.as_slice()).unwrap().is_background);
+
+            // 多个任务同时请求，各自收到自己的响应
+            let pulls: Vec<_> = (1..=2)
+                .map(|seq| {
+                    let handle = handle.clone();
+                    let req = sdkws::PullMessageBySeqsReq {
+                        user_id: "u1".to_string(),
+                        seq_ranges: vec![sdkws::SeqRange {
+                            conversation_id: conversation_id.clone(),
+                            begin: seq,
+                            end: seq,
+                            num: 1,
+                        }],
+                        order: 0,
+                    };
+                    tokio::spawn(async move { handle.request(msg_type::WS_PULL_MSG_BY_SEQ_LIST, req.encode_to_vec()).await })
+                })
+                .collect();
+            for (seq, pull) in (1..=2).zip(pulls) {
+                let data = pull.await.unwrap().unwrap();
+                let resp = sdkws::PullMessageBySeqsResp::decode(data.as_slice()).unwrap();
+                assert_eq!(resp.msgs[&conversation_id].msgs[0].seq, seq);
+            }
+            handle.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn invalid_handshake_reconnects() {
+        use crate::openim::transport::MemoryConnector;
+
+        let (connector, mut incoming) = MemoryConnector::new();
+        let mut client = new_client();
+        client.use_connector(Arc::new(connector));
+        let mut states = client.handle.state_machine().subscribe();
+
+        let server = async {
+            // 第一帧不是握手响应
+            let (_, first) = incoming.recv().await.unwrap();
+            first.send(Frame::Binary(b"{}".to_vec())).await.unwrap();
+            // 无法解析的握手响应
+            let (_, second) = incoming.recv().await.unwrap();
+            second.send(Frame::Text("hello".to_string())).await.unwrap();
+            let (_, third) = incoming.recv().await.unwrap();
+            third.send(Frame::Text(r#"{"errCode":0,"errMsg":"","errDlt":""}"#.to_string())).await.unwrap();
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), server)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+
+        let seen: Vec<ConnectionState> = std::iter::from_fn(|| states.try_recv().ok()).collect();
+        assert!(matches!(seen[..3], [ConnectionState::Connecting, ConnectionState::Handshaking, ConnectionState::Reconnecting { attempt: 1, .. }]));
+        assert!(matches!(seen[5], ConnectionState::Reconnecting { attempt: 2, .. }));
+        assert_eq!(seen.iter().filter(|s| **s == ConnectionState::Connected).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn non_token_handshake_error_reconnects() {
+        use crate::openim::transport::MemoryConnector;
+
+        let (connector, mut incoming) = MemoryConnector::new();
+        let mut client = new_client();
+        client.use_connector(Arc::new(connector));
+        let mut states = client.handle.state_machine().subscribe();
+
+        let server = async {
+            // 与 token 无关的错误码
+            let (_, first) = incoming.recv().await.unwrap();
+            first.send(Frame::Text(r#"{"errCode":500,"errMsg":"internal error","errDlt":""}"#.to_string())).await.unwrap();
+            let (_, second) = incoming.recv().await.unwrap();
+            second.send(Frame::Text(r#"{"errCode":0,"errMsg":"","errDlt":""}"#.to_string())).await.unwrap();
+            eventually(|| client.handle.is_connected().then_some(())).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), server)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+
+        let seen: Vec<ConnectionState> = std::iter::from_fn(|| states.try_recv().ok()).collect();
+        assert!(matches!(seen[..3], [ConnectionState::Connecting, ConnectionState::Handshaking, ConnectionState::Reconnecting { attempt: 1, .. }]));
+        assert!(!seen.contains(&ConnectionState::TokenExpired));
+        assert_eq!(seen.last(), Some(&ConnectionState::Closed));
+    }
+
+    #[tokio::test]
+    async fn logout_cancels_requests_and_closes_store() {
+        use crate::openim::transport::{MemoryConnector, MemoryTransport};
+
+        /// 读取客户端发来的下一个请求，连接关闭时返回 None
+        async fn next_request(transport: &MemoryTransport) -> Option<serde_json::Value> {
+            loop {
+                match transport.recv().await? {
+                    Ok(Frame::Binary(data)) => return Some(serde_json::from_slice(&data).unwrap()),
+                    _ => continue,
+                }
+            }
+        }
+
+        let (connector, mut incoming) = MemoryConnector::new();
+        let mut client = new_client();
+        client.use_connector(Arc::new(connector));
+        let handle = client.handle();
+
+        let server = async {
+            let (_, transport) = incoming.recv().await.unwrap();
+            transport.send(Frame::Text(r#"{"errCode":0,"errMsg":"","errDlt":""}"#.to_string())).await.unwrap();
+
+            // 服务器不应答的请求
+            let pull = tokio::spawn({
+                let handle = handle.clone();
+                async move { handle.request(msg_type::WS_PULL_MSG_BY_SEQ_LIST, Vec::new()).await }
+            });
+            assert_eq!(next_request(&transport).await.unwrap()["reqIdentifier"], msg_type::WS_PULL_MSG_BY_SEQ_LIST);
+
+            let logout = client.logout();
+            let respond = async {
+                let req = next_request(&transport).await.unwrap();
+                assert_eq!(req["reqIdentifier"], msg_type::WS_LOGOUT_MSG);
+                let resp = serde_json::json!({
+                    "reqIdentifier": msg_type::WS_LOGOUT_MSG,
+                    "msgIncr": req["msgIncr"],
+                    "operationID": "",
+                    "errCode": 0,
+                    "errMsg": "",
+                });
+                transport.send(Frame::Binary(serde_json::to_vec(&resp).unwrap())).await.unwrap();
+                // 客户端随后关闭连接
+                assert!(next_request(&transport).await.is_none());
+            };
+            let (result, ()) = tokio::join!(logout, respond);
+            result.unwrap();
+
+            assert!(matches!(pull.await.unwrap(), Err(OpenIMError::Cancelled)));
+            assert!(!handle.is_connected());
+            assert!(matches!(client.messages.get("m1"), Err(OpenIMError::StoreClosed)));
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(client.connect_and_run(), server)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn replay_recorded_capture() {
+        let path = std::env::temp_dir().join(format!("openim-replay-{}.jsonl", uuid::Uuid::new_v4()));
+        let gateway = MockGateway::start().await;
+        let mut client = new_client();
+        client.ws_url = gateway.ws_url();
+        client.start_frame_capture(path.to_string_lossy().into_owned()).unwrap();
+
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            let msg = MsgData {
+                send_id: "u2".to_string(),
+                recv_id: "u1".to_string(),
+                client_msg_id: "m1".to_string(),
+                session_type: session_type::SINGLE_CHAT,
+                content_type: content_type::TEXT,
+                content: br#"{"content":"hi"}"#.to_vec(),
+                ..Default::default()
+            };
+            gateway.push_message(&conversation::single_conversation_id("u1", "u2"), msg);
+            eventually(|| client.messages.get("m1").unwrap()).await;
+            client.disconnect().await.unwrap();
+        };
+        let (result, ()) = tokio::join!(client.connect_and_run(), script);
+        assert!(result.is_ok());
+        client.stop_frame_capture();
+        // 现场收到的无法解析的帧
+        FrameRecorder::create(&path)
+            .unwrap()
+            .record(Direction::Inbound, FrameKind::Binary, b"garbage", 0)
+            .unwrap();
+
+        let frames = capture::read_capture(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let replayed = OpenIMClient::new("u1".to_string(), String::new(), 5).unwrap();
+        let report = replayed.replay_frames(&frames);
+        assert_eq!(report.frames, 3); // 握手、推送、无法解析的帧
+        assert_eq!(report.replayed, 2);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].index, 2);
+        assert_eq!(replayed.messages.get("m1").unwrap().unwrap().seq, 1);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::openim::picture;
+
+/// 预处理后的图片消息（上传三个尺寸后将 url 写回 PictureElem 再发送）
+pub struct PreparedPicture {
+    pub source: Vec<u8>,
+    pub big: Vec<u8>,
+    pub snapshot: Vec<u8>,
+    /// PictureElem JSON（url 为空）
+    pub picture_elem_json: String,
+}
+
+/// 解码图片，生成大图和缩略图，并清除 EXIF GPS 信息
+pub fn prepare_picture(data: Vec<u8>, source_path: String) -> anyhow::Result<PreparedPicture> {
+    let processed = picture::process_picture(&data)?;
+    let elem = processed.picture_elem(&source_path);
+    Ok(PreparedPicture {
+        picture_elem_json: serde_json::to_string(&elem)?,
+        source: processed.source.data,
+        big: processed.big.data,
+        snapshot: processed.snapshot.data,
+    })
+}
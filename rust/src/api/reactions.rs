@@ -0,0 +1,83 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream_with;
+use crate::frb_generated::StreamSink;
+use crate::openim::reactions::ReactionSummary;
+
+/// 消息上一个表情的回应
+pub struct MessageReaction {
+    pub emoji: String,
+    pub count: u32,
+    /// 回应过的用户（按回应时间）
+    pub user_ids: Vec<String>,
+    /// 自己是否回应过
+    pub reacted_by_me: bool,
+}
+
+impl MessageReaction {
+    fn new(s: ReactionSummary, me: &str) -> Self {
+        Self {
+            count: s.user_ids.len() as u32,
+            reacted_by_me: s.user_ids.iter().any(|id| id == me),
+            emoji: s.emoji,
+            user_ids: s.user_ids,
+        }
+    }
+}
+
+/// 消息的表情回应变化（reactions 为变化后的全部回应）
+pub struct ReactionChange {
+    pub conversation_id: String,
+    pub client_msg_id: String,
+    pub reactions: Vec<MessageReaction>,
+}
+
+impl OpenIMClient {
+    /// 对消息添加表情回应
+    pub async fn add_reaction(&self, conversation_id: String, client_msg_id: String, emoji: String) -> anyhow::Result<()> {
+        self.reactions
+            .add(
+                &self.rest_api(),
+                &self.user_id,
+                &conversation_id,
+                &client_msg_id,
+                &emoji,
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// 取消自己对消息的表情回应
+    pub async fn remove_reaction(&self, conversation_id: String, client_msg_id: String, emoji: String) -> anyhow::Result<()> {
+        self.reactions
+            .remove(&self.rest_api(), &self.user_id, &conversation_id, &client_msg_id, &emoji)
+            .await?;
+        Ok(())
+    }
+
+    /// 消息的表情回应（按每个表情最早的回应时间排序）
+    pub fn get_message_reactions(&self, client_msg_id: String) -> anyhow::Result<Vec<MessageReaction>> {
+        let summary = self.reactions.summary(&client_msg_id)?;
+        Ok(summary.into_iter().map(|s| MessageReaction::new(s, &self.user_id)).collect())
+    }
+
+    /// 订阅表情回应变化
+    pub fn reaction_change_stream(&self, sink: StreamSink<ReactionChange>) {
+        let reactions = self.reactions.clone();
+        let me = self.user_id.clone();
+        forward_stream_with(self.reactions.subscribe(), sink, move |event| {
+            let summary = match reactions.summary(&event.client_msg_id) {
+                Ok(summary) => summary,
+                Err(e) => {
+                    tracing::error!(error = %e, "读取表情回应失败");
+                    return None;
+                }
+            };
+            Some(ReactionChange {
+                conversation_id: event.conversation_id,
+                client_msg_id: event.client_msg_id,
+                reactions: summary.into_iter().map(|s| MessageReaction::new(s, &me)).collect(),
+            })
+        });
+    }
+}
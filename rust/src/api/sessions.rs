@@ -0,0 +1,163 @@
+use std::sync::OnceLock;
+
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream;
+use crate::frb_generated::{RustAutoOpaque, StreamSink};
+use crate::openim::sessions::{self, SessionEvent, SessionRegistry};
+
+/// 已打开的会话
+pub struct SessionInfo {
+    pub session_id: String,
+    pub user_id: String,
+    pub platform_id: i32,
+    /// 数据目录，None 表示数据保存在内存中
+    pub data_dir: Option<String>,
+    pub is_active: bool,
+}
+
+/// 会话变化
+pub enum SessionChange {
+    Opened { session_id: String },
+    Closed { session_id: String },
+    /// 当前会话切换，None 表示已没有会话
+    Activated { session_id: Option<String> },
+}
+
+impl From<SessionEvent> for SessionChange {
+    fn from(e: SessionEvent) -> Self {
+        match e {
+            SessionEvent::Opened(session_id) => Self::Opened { session_id },
+            SessionEvent::Closed(session_id) => Self::Closed { session_id },
+            SessionEvent::Activated(session_id) => Self::Activated { session_id },
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Session {
+    client: RustAutoOpaque<OpenIMClient>,
+    user_id: String,
+    platform_id: i32,
+    data_dir: Option<String>,
+}
+
+fn registry() -> &'static SessionRegistry<Session> {
+    static SESSIONS: OnceLock<SessionRegistry<Session>> = OnceLock::new();
+    SESSIONS.get_or_init(SessionRegistry::new)
+}
+
+/// 打开会话并返回会话 ID，每个会话的数据保存在 `{root_dir}/{session_id}` 下
+///
+/// root_dir 为 None 时数据保存在内存中。第一个打开的会话自动成为当前会话。
+pub fn open_session(
+    user_id: String,
+    token: String,
+    platform_id: i32,
+    root_dir: Option<String>,
+) -> anyhow::Result<String> {
+    let session_id = sessions::session_id(&user_id, platform_id);
+    if registry().get(&session_id).is_some() {
+        anyhow::bail!("会话 {} 已打开", session_id);
+    }
+    let data_dir = root_dir.map(|root| std::path::Path::new(&root).join(&session_id).to_string_lossy().into_owned());
+    let client = match &data_dir {
+        Some(dir) => OpenIMClient::open(user_id.clone(), token, platform_id, dir.clone())?,
+        None => OpenIMClient::new(user_id.clone(), token, platform_id)?,
+    };
+    let session = Session { client: RustAutoOpaque::new(client), user_id, platform_id, data_dir };
+    registry().insert(&session_id, session)?;
+    Ok(session_id)
+}
+
+/// 按会话 ID 获取客户端
+pub fn get_session(session_id: String) -> anyhow::Result<RustAutoOpaque<OpenIMClient>> {
+    registry()
+        .get(&session_id)
+        .map(|s| s.client)
+        .ok_or_else(|| anyhow::anyhow!("会话 {} 不存在", session_id))
+}
+
+/// 当前会话的客户端
+pub fn active_session() -> Option<RustAutoOpaque<OpenIMClient>> {
+    registry().active().map(|(_, s)| s.client)
+}
+
+/// 切换当前会话
+pub fn set_active_session(session_id: String) -> anyhow::Result<()> {
+    registry().set_active(&session_id)?;
+    Ok(())
+}
+
+/// 已打开的全部会话
+pub fn list_sessions() -> Vec<SessionInfo> {
+    let active = registry().active().map(|(id, _)| id);
+    registry()
+        .ids()
+        .into_iter()
+        .filter_map(|session_id| {
+            let s = registry().get(&session_id)?;
+            Some(SessionInfo {
+                is_active: active.as_deref() == Some(session_id.as_str()),
+                session_id,
+                user_id: s.user_id,
+                platform_id: s.platform_id,
+                data_dir: s.data_dir,
+            })
+        })
+        .collect()
+}
+
+/// 关闭会话：断开连接、关闭本地数据库后从会话表中移除，关闭的是当前会话时切换到其他会话
+///
+/// 只关闭本地的会话，不通知服务器退出登录（退出登录先调用客户端的 `logout`）。
+pub async fn close_session(session_id: String) -> anyhow::Result<()> {
+    let session = registry()
+        .get(&session_id)
+        .ok_or_else(|| anyhow::anyhow!("会话 {} 不存在", session_id))?;
+    session.client.read().await.close().await?;
+    registry().remove(&session_id);
+    Ok(())
+}
+
+/// 订阅会话的打开、关闭和切换
+pub fn session_change_stream(sink: StreamSink<SessionChange>) {
+    forward_stream(registry().subscribe(), sink);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::api::openim_client::ConnectionStatus;
+    use crate::openim::mock_gateway::MockGateway;
+
+    #[tokio::test]
+    async fn close_session_disconnects_client() {
+        let gateway = MockGateway::start().await;
+        let session_id = open_session("close_u1".to_string(), "tok".to_string(), 5, None).unwrap();
+        let client = get_session(session_id.clone()).unwrap();
+        {
+            let mut client = client.write().await;
+            client.ws_url = gateway.ws_url();
+            client.set_sync_on_connect(false);
+        }
+
+        let running = client.read().await;
+        let script = async {
+            gateway.wait_handshakes(1).await;
+            close_session(session_id.clone()).await.unwrap();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(running.connect_and_run(), script)
+        })
+        .await
+        .unwrap();
+        assert!(result.is_ok());
+        assert!(matches!(running.connection_state(), ConnectionStatus::Closed));
+        // 数据库已关闭
+        assert!(running.get_message_reactions("m1".to_string()).is_err());
+        assert!(get_session(session_id.clone()).is_err());
+        assert!(close_session(session_id).await.is_err());
+    }
+}
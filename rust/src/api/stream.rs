@@ -0,0 +1,65 @@
+//! 把广播的事件转发到 Dart 的 Stream
+
+use tokio::sync::broadcast;
+
+use crate::frb_generated::{SseEncode, StreamSink};
+
+/// 转发事件，Dart 端取消订阅或事件源关闭时结束，处理不过来时跳过落后的事件
+pub(crate) fn forward_stream<E, T>(events: broadcast::Receiver<E>, sink: StreamSink<T>)
+where
+    E: Clone + Send + 'static,
+    T: From<E> + SseEncode + Send + 'static,
+{
+    spawn_forward(events, sink, None, |event| Some(event.into()));
+}
+
+/// 同 [`forward_stream`]，先推送 initial（如当前状态）
+pub(crate) fn forward_stream_with_initial<E, T>(events: broadcast::Receiver<E>, sink: StreamSink<T>, initial: Option<T>)
+where
+    E: Clone + Send + 'static,
+    T: From<E> + SseEncode + Send + 'static,
+{
+    spawn_forward(events, sink, initial, |event| Some(event.into()));
+}
+
+/// 同 [`forward_stream`]，map 返回 None 的事件不推送
+pub(crate) fn forward_stream_with<E, T>(
+    events: broadcast::Receiver<E>,
+    sink: StreamSink<T>,
+    map: impl FnMut(E) -> Option<T> + Send + 'static,
+) where
+    E: Clone + Send + 'static,
+    T: SseEncode + Send + 'static,
+{
+    spawn_forward(events, sink, None, map);
+}
+
+fn spawn_forward<E, T>(
+    mut events: broadcast::Receiver<E>,
+    sink: StreamSink<T>,
+    initial: Option<T>,
+    mut map: impl FnMut(E) -> Option<T> + Send + 'static,
+) where
+    E: Clone + Send + 'static,
+    T: SseEncode + Send + 'static,
+{
+    flutter_rust_bridge::spawn(async move {
+        if let Some(initial) = initial {
+            if sink.add(initial).is_err() {
+                return;
+            }
+        }
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    let Some(item) = map(event) else { continue };
+                    if sink.add(item).is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
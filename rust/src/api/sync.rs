@@ -0,0 +1,62 @@
+use std::sync::atomic::Ordering;
+
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream_with_initial;
+use crate::frb_generated::StreamSink;
+use crate::openim::sync::{SyncEvent, SyncPhase};
+
+/// 同步阶段
+pub enum SyncStage {
+    SelfInfo,
+    /// 好友、黑名单和好友申请
+    Friends,
+    Groups,
+    Conversations,
+    /// 补齐离线期间的消息
+    Messages,
+}
+
+impl From<SyncPhase> for SyncStage {
+    fn from(phase: SyncPhase) -> Self {
+        match phase {
+            SyncPhase::SelfInfo => Self::SelfInfo,
+            SyncPhase::Friends => Self::Friends,
+            SyncPhase::Groups => Self::Groups,
+            SyncPhase::Conversations => Self::Conversations,
+            SyncPhase::Messages => Self::Messages,
+        }
+    }
+}
+
+/// 同步状态（用于显示“同步中…”提示）
+pub enum SyncStatus {
+    /// 正在同步，percent 为整体进度（0-100）
+    Syncing { stage: SyncStage, percent: u8 },
+    /// 同步结束，failed 为失败的阶段（下次连接时重试）
+    Done { failed: Vec<SyncStage> },
+    /// 连接断开，重连后重新同步
+    Interrupted,
+}
+
+impl From<SyncEvent> for SyncStatus {
+    fn from(e: SyncEvent) -> Self {
+        match e {
+            SyncEvent::Progress { phase, percent } => Self::Syncing { stage: phase.into(), percent },
+            SyncEvent::Finished { failed } => Self::Done { failed: failed.into_iter().map(Into::into).collect() },
+            SyncEvent::Interrupted => Self::Interrupted,
+        }
+    }
+}
+
+impl OpenIMClient {
+    /// 连接（包括重连）后是否自动同步资料、联系人、群、会话和缺失的消息，默认开启
+    pub fn set_sync_on_connect(&self, enabled: bool) {
+        self.sync_on_connect.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 订阅同步进度（先推送最近一次的状态，尚未同步过时不推送）
+    pub fn sync_status_stream(&self, sink: StreamSink<SyncStatus>) {
+        let events = self.sync_progress.subscribe();
+        forward_stream_with_initial(events, sink, self.sync_progress.current().map(Into::into));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::api::openim_client::OpenIMClient;
+use crate::api::stream::forward_stream;
+use crate::frb_generated::StreamSink;
+use crate::openim::presence::PresenceEvent;
+use crate::openim::rest::user::UserInfo;
+
+/// 用户资料
+pub struct UserProfile {
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub ex: String,
+    pub create_time: i64,
+}
+
+impl From<UserInfo> for UserProfile {
+    fn from(u: UserInfo) -> Self {
+        Self {
+            user_id: u.user_id,
+            nickname: u.nickname,
+            face_url: u.face_url,
+            ex: u.ex,
+            create_time: u.create_time,
+        }
+    }
+}
+
+/// 用户在线状态
+pub struct UserStatus {
+    pub user_id: String,
+    pub online: bool,
+    /// 在线的平台（1 iOS，2 Android，3 Windows，4 macOS，5 Web ...）
+    pub platform_ids: Vec<i32>,
+}
+
+impl From<PresenceEvent> for UserStatus {
+    fn from(e: PresenceEvent) -> Self {
+        Self {
+            online: !e.platform_ids.is_empty(),
+            user_id: e.user_id,
+            platform_ids: e.platform_ids,
+        }
+    }
+}
+
+impl OpenIMClient {
+    /// 获取自己的资料
+    pub async fn get_self_info(&self) -> anyhow::Result<UserProfile> {
+        let mut users = self.get_users_info(vec![self.user_id.clone()], true).await?;
+        users.pop().ok_or_else(|| anyhow::anyhow!("用户 {} 不存在", self.user_id))
+    }
+
+    /// 修改自己的资料，None 表示不修改
+    pub async fn set_self_info(
+        &self,
+        nickname: Option<String>,
+        face_url: Option<String>,
+        ex: Option<String>,
+    ) -> anyhow::Result<UserProfile> {
+        let user = self
+            .profiles
+            .update_self(
+                &self.rest_api(),
+                &self.user_id,
+                nickname.as_deref(),
+                face_url.as_deref(),
+                ex.as_deref(),
+                chrono::Utc::now().timestamp_millis(),
+            )
+            .await?;
+        Ok(user.into())
+    }
+
+    /// 批量获取用户资料（优先使用缓存，force_refresh 为 true 时从服务器获取）
+    pub async fn get_users_info(&self, user_ids: Vec<String>, force_refresh: bool) -> anyhow::Result<Vec<UserProfile>> {
+        let users = self
+            .profiles
+            .get(&self.rest_api(), &user_ids, force_refresh, chrono::Utc::now().timestamp_millis())
+            .await?;
+        Ok(users.into_iter().map(Into::into).collect())
+    }
+
+    /// 订阅用户在线状态（断线重连后自动重新订阅）
+    pub fn subscribe_users_status(&self, user_ids: Vec<String>) {
+        self.presence.subscribe(&user_ids);
+    }
+
+    /// 取消订阅用户在线状态
+    pub fn unsubscribe_users_status(&self, user_ids: Vec<String>) {
+        self.presence.unsubscribe(&user_ids);
+    }
+
+    /// 已订阅用户的当前在线状态
+    pub fn get_user_status(&self, user_id: String) -> UserStatus {
+        let platform_ids = self.presence.platforms(&user_id);
+        UserStatus { user_id, online: !platform_ids.is_empty(), platform_ids }
+    }
+
+    /// 订阅在线状态变化
+    pub fn user_status_stream(&self, sink: StreamSink<UserStatus>) {
+        forward_stream(self.presence.subscribe_events(), sink);
+    }
+}
@@ -0,0 +1,293 @@
+//! OpenIM 命令行客户端，用于调试
+//!
+//! 连接参数可以通过命令行或环境变量（`OPENIM_*`）指定，例如：
+//!
+//! ```sh
+//! OPENIM_USER_ID=u1 OPENIM_TOKEN=... cargo run --features cli --bin openim-cli -- tail --format json
+//! ```
+
+use std::sync::Arc;
+
+use base64::Engine;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use openim_protocol::{msg, sdkws, Message as _};
+use rust_lib_flutter_rust_demo::api::openim_client::OpenIMClient;
+use rust_lib_flutter_rust_demo::openim::auth::{Authenticator, Credentials, MemoryTokenStorage};
+use rust_lib_flutter_rust_demo::openim::capture;
+use rust_lib_flutter_rust_demo::openim::content::searchable_text;
+use rust_lib_flutter_rust_demo::openim::messages::StoredMessage;
+use rust_lib_flutter_rust_demo::openim::outbox::OutboxStatus;
+use tokio::sync::broadcast::error::RecvError;
+
+#[derive(Parser)]
+#[command(name = "openim-cli", about = "OpenIM 调试客户端")]
+struct Cli {
+    #[command(flatten)]
+    config: Config,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// 连接配置
+#[derive(Args)]
+struct Config {
+    #[arg(long, env = "OPENIM_WS_URL", default_value = "ws://localhost:10001", global = true)]
+    ws_url: String,
+    #[arg(long, env = "OPENIM_API_URL", default_value = "http://localhost:10002", global = true)]
+    api_url: String,
+    #[arg(long, env = "OPENIM_USER_ID", default_value = "", global = true)]
+    user_id: String,
+    /// 用户 token，未指定时使用 --secret 换取
+    #[arg(long, env = "OPENIM_TOKEN", global = true)]
+    token: Option<String>,
+    /// 管理员密钥（仅用于测试环境）
+    #[arg(long, env = "OPENIM_SECRET", global = true)]
+    secret: Option<String>,
+    #[arg(long, env = "OPENIM_ADMIN_USER_ID", default_value = "imAdmin", global = true)]
+    admin_user_id: String,
+    #[arg(long, env = "OPENIM_PLATFORM_ID", default_value_t = 5, global = true)]
+    platform_id: i32,
+    /// 本地数据目录，未指定时数据只保存在内存中
+    #[arg(long, env = "OPENIM_DATA_DIR", global = true)]
+    data_dir: Option<String>,
+    /// 日志级别（输出到 stderr）
+    #[arg(long, env = "OPENIM_LOG", default_value = "warn", global = true)]
+    log_level: tracing::Level,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 连接并持续输出收到的消息
+    Tail {
+        #[arg(long, value_enum, default_value_t = Format::Pretty)]
+        format: Format,
+    },
+    /// 发送文本消息，等待服务器确认后退出
+    Send {
+        /// 接收者用户 ID
+        #[arg(long, conflicts_with = "group", required_unless_present = "group")]
+        to: Option<String>,
+        /// 群 ID
+        #[arg(long)]
+        group: Option<String>,
+        text: String,
+    },
+    /// 按 seq 区间拉取会话历史消息
+    History {
+        #[arg(long)]
+        conversation: String,
+        #[arg(long, default_value_t = 1)]
+        begin: i64,
+        #[arg(long)]
+        end: i64,
+        #[arg(long, value_enum, default_value_t = Format::Pretty)]
+        format: Format,
+    },
+    /// 输出本地会话列表
+    Conversations {
+        /// 先从服务器同步
+        #[arg(long)]
+        sync: bool,
+    },
+    /// 解码 base64 编码的协议数据
+    Decode {
+        #[arg(long = "type", value_enum)]
+        kind: DecodeKind,
+        data: String,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// 便于阅读的单行文本
+    Pretty,
+    /// 每行一个 JSON 对象
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DecodeKind {
+    /// MsgData
+    Msg,
+    /// PushMessages
+    Push,
+    /// PullMessageBySeqsResp
+    Pull,
+    /// GetMaxSeqResp
+    MaxSeq,
+    /// SendMsgResp
+    SendResp,
+    /// WebSocket 帧（gzip 解压后输出文本）
+    Frame,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    tracing_subscriber::fmt()
+        .with_max_level(cli.config.log_level)
+        .with_writer(std::io::stderr)
+        .init();
+
+    match cli.command {
+        Command::Decode { kind, data } => decode(kind, &data),
+        Command::Conversations { sync } => {
+            let client = build_client(&cli.config).await?;
+            if sync {
+                client.sync_conversations().await?;
+            }
+            for c in client.get_conversation_list()? {
+                let line = serde_json::json!({
+                    "conversationID": c.conversation_id,
+                    "conversationType": c.conversation_type,
+                    "userID": c.user_id,
+                    "groupID": c.group_id,
+                    "isPinned": c.is_pinned,
+                    "draftText": c.draft_text,
+                    "latestMsgTime": c.latest_msg_time,
+                    "latestMsgText": c.latest_msg_text,
+                });
+                println!("{}", line);
+            }
+            Ok(())
+        }
+        Command::Tail { format } => {
+            let client = build_client(&cli.config).await?;
+            let mut messages = client.subscribe_messages();
+            let print = async {
+                loop {
+                    match messages.recv().await {
+                        Ok(msg) => print_message(&msg, format),
+                        Err(RecvError::Lagged(n)) => tracing::warn!(skipped = n, "输出跟不上，跳过部分消息"),
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            };
+            tokio::select! {
+                result = client.connect_and_run() => result.map_err(|e| anyhow::anyhow!("连接结束: {}", e)),
+                () = print => Ok(()),
+            }
+        }
+        Command::Send { to, group, text } => {
+            let client = build_client(&cli.config).await?;
+            let mut status = client.subscribe_message_status();
+            let id = match (to, group) {
+                (_, Some(group_id)) => client.send_group_text_message(group_id, text)?,
+                (Some(recv_id), None) => client.send_text_message(recv_id, text)?,
+                (None, None) => unreachable!("clap 已校验"),
+            };
+            let wait = async {
+                loop {
+                    match status.recv().await {
+                        Ok(event) if event.id == id && event.status == OutboxStatus::Sent => {
+                            println!("{} {} {}", event.client_msg_id, event.server_msg_id, event.send_time);
+                            return Ok(());
+                        }
+                        Ok(event) if event.id == id && event.status == OutboxStatus::Failed => {
+                            anyhow::bail!("发送失败: {}", event.error);
+                        }
+                        Ok(_) | Err(RecvError::Lagged(_)) => continue,
+                        Err(RecvError::Closed) => anyhow::bail!("客户端已关闭"),
+                    }
+                }
+            };
+            tokio::select! {
+                result = client.connect_and_run() => result.map_err(|e| anyhow::anyhow!("连接结束: {}", e)),
+                result = wait => result,
+            }
+        }
+        Command::History { conversation, begin, end, format } => {
+            let client = build_client(&cli.config).await?;
+            let pull = async {
+                // 请求在连接建立后发出
+                let msgs = client.pull_messages_by_seq(&conversation, begin, end).await?;
+                for msg in &msgs {
+                    print_message(msg, format);
+                }
+                anyhow::Ok(())
+            };
+            tokio::select! {
+                result = client.connect_and_run() => result.map_err(|e| anyhow::anyhow!("连接结束: {}", e)),
+                result = pull => result,
+            }
+        }
+    }
+}
+
+/// 按配置创建客户端（需要时先用管理员密钥换取 token）
+async fn build_client(config: &Config) -> anyhow::Result<OpenIMClient> {
+    if config.user_id.is_empty() {
+        anyhow::bail!("缺少用户 ID（--user-id 或 OPENIM_USER_ID）");
+    }
+    let token = match (&config.token, &config.secret) {
+        (Some(token), _) => token.clone(),
+        (None, Some(secret)) => {
+            let auth = Authenticator::new(reqwest::Client::new(), Arc::new(MemoryTokenStorage::default()));
+            let credentials = Credentials::AdminSecret {
+                api_url: config.api_url.clone(),
+                secret: secret.clone(),
+                user_id: config.user_id.clone(),
+                admin_user_id: config.admin_user_id.clone(),
+            };
+            auth.login(&credentials, config.platform_id).await?.token
+        }
+        (None, None) => anyhow::bail!("缺少 token（--token / OPENIM_TOKEN 或 --secret / OPENIM_SECRET）"),
+    };
+    let mut client = match &config.data_dir {
+        Some(dir) => OpenIMClient::open(config.user_id.clone(), token, config.platform_id, dir.clone())?,
+        None => OpenIMClient::new(config.user_id.clone(), token, config.platform_id)?,
+    };
+    client.ws_url = config.ws_url.clone();
+    client.api_url = config.api_url.clone();
+    Ok(client)
+}
+
+fn print_message(msg: &StoredMessage, format: Format) {
+    let text = searchable_text(msg.content_type, &msg.content);
+    match format {
+        Format::Pretty => {
+            let time = chrono::DateTime::from_timestamp_millis(msg.send_time)
+                .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                .unwrap_or_default();
+            let body = text.unwrap_or_else(|| format!("<content_type={}>", msg.content_type));
+            println!("[{}] {} #{} {}: {}", time, msg.conversation_id, msg.seq, msg.send_id, body);
+        }
+        Format::Json => {
+            let line = serde_json::json!({
+                "clientMsgID": msg.client_msg_id,
+                "serverMsgID": msg.server_msg_id,
+                "conversationID": msg.conversation_id,
+                "seq": msg.seq,
+                "sendID": msg.send_id,
+                "recvID": msg.recv_id,
+                "groupID": msg.group_id,
+                "sessionType": msg.session_type,
+                "contentType": msg.content_type,
+                "content": String::from_utf8_lossy(&msg.content),
+                "text": text,
+                "sendTime": msg.send_time,
+            });
+            println!("{}", line);
+        }
+    }
+}
+
+/// 解码并输出协议数据
+fn decode(kind: DecodeKind, data: &str) -> anyhow::Result<()> {
+    let bytes = base64::engine::general_purpose::STANDARD.decode(data.trim())?;
+    match kind {
+        DecodeKind::Msg => println!("{:#?}", sdkws::MsgData::decode(bytes.as_slice())?),
+        DecodeKind::Push => println!("{:#?}", sdkws::PushMessages::decode(bytes.as_slice())?),
+        DecodeKind::Pull => println!("{:#?}", sdkws::PullMessageBySeqsResp::decode(bytes.as_slice())?),
+        DecodeKind::MaxSeq => println!("{:#?}", sdkws::GetMaxSeqResp::decode(bytes.as_slice())?),
+        DecodeKind::SendResp => println!("{:#?}", msg::SendMsgResp::decode(bytes.as_slice())?),
+        DecodeKind::Frame => {
+            let decompressed = capture::decompress(&bytes)?;
+            match String::from_utf8(decompressed) {
+                Ok(text) => println!("{}", text),
+                Err(e) => println!("{:02x?}", e.into_bytes()),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! 协议帧录制与回放
+//!
+//! 录制默认关闭。开启后每个收发的 WebSocket 帧按 JSON Lines 写入抓包文件：原始数据（base64）、
+//! 解压后的内容和时间戳。回放时把抓包中收到的帧重新交给解码流程，用于离线复现现场问题。
+//! 抓包文件会随问题反馈发出，发出的帧在写入前去掉 token。
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use base64::Engine;
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+
+use super::OpenIMError;
+
+/// 帧方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Inbound,
+    Outbound,
+}
+
+/// WebSocket 帧类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameKind {
+    Text,
+    Binary,
+}
+
+/// 抓包中的一帧
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CapturedFrame {
+    pub time_ms: i64,
+    pub direction: Direction,
+    pub kind: FrameKind,
+    /// 原始数据（base64）
+    pub raw: String,
+    /// 解压后的文本（JSON），无法解压或不是 UTF-8 时为 None
+    #[serde(default)]
+    pub decoded: Option<String>,
+}
+
+impl CapturedFrame {
+    pub fn raw_bytes(&self) -> Result<Vec<u8>, OpenIMError> {
+        base64::engine::general_purpose::STANDARD
+            .decode(&self.raw)
+            .map_err(|e| OpenIMError::Decode(format!("抓包数据不是 base64: {}", e)))
+    }
+}
+
+/// 解压帧数据（gzip 魔数开头时解压，否则原样返回）
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, std::io::Error> {
+    if data.starts_with(&[0x1f, 0x8b]) {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(data).read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    } else {
+        Ok(data.to_vec())
+    }
+}
+
+/// 发出的请求中需要去掉的字段
+const REDACTED_FIELDS: &[&str] = &["token"];
+
+/// 去掉请求中的 token（不是 JSON 对象或不含这些字段时返回 None）
+fn redact(raw: &[u8]) -> Option<Vec<u8>> {
+    let mut json: serde_json::Value = serde_json::from_slice(&decompress(raw).ok()?).ok()?;
+    let object = json.as_object_mut()?;
+    let mut redacted = false;
+    for field in REDACTED_FIELDS {
+        if let Some(value) = object.get_mut(*field) {
+            *value = serde_json::Value::String(String::new());
+            redacted = true;
+        }
+    }
+    redacted.then(|| serde_json::to_vec(&json).ok()).flatten()
+}
+
+/// 抓包录制器
+pub struct FrameRecorder {
+    file: Mutex<BufWriter<File>>,
+}
+
+impl FrameRecorder {
+    /// 创建抓包文件（已存在时追加）
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, OpenIMError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file: Mutex::new(BufWriter::new(file)) })
+    }
+
+    /// 写入一帧（每帧立即落盘，进程崩溃时不丢失）
+    ///
+    /// 发出的帧去掉 token 后再写入（原始数据和解压后的内容都不包含 token）。
+    pub fn record(&self, direction: Direction, kind: FrameKind, raw: &[u8], now_ms: i64) -> Result<(), OpenIMError> {
+        let redacted = match direction {
+            Direction::Outbound => redact(raw),
+            Direction::Inbound => None,
+        };
+        let raw = redacted.as_deref().unwrap_or(raw);
+        let frame = CapturedFrame {
+            time_ms: now_ms,
+            direction,
+            kind,
+            raw: base64::engine::general_purpose::STANDARD.encode(raw),
+            decoded: decompress(raw).ok().and_then(|data| String::from_utf8(data).ok()),
+        };
+        let mut file = self.file.lock().unwrap();
+        serde_json::to_writer(&mut *file, &frame)?;
+        file.write_all(b"\n")?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// 读取抓包文件
+pub fn read_capture(path: impl AsRef<Path>) -> Result<Vec<CapturedFrame>, OpenIMError> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut frames = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        frames.push(serde_json::from_str(&line)?);
+    }
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+
+    #[test]
+    fn record_and_read_back() {
+        let path = std::env::temp_dir().join(format!("openim-capture-{}.jsonl", uuid::Uuid::new_v4()));
+        let recorder = FrameRecorder::create(&path).unwrap();
+        let mut gzip = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(br#"{"reqIdentifier":2001}"#).unwrap();
+        let compressed = gzip.finish().unwrap();
+        recorder.record(Direction::Inbound, FrameKind::Binary, &compressed, 1_000).unwrap();
+        recorder.record(Direction::Outbound, FrameKind::Binary, &[0xff, 0x00], 2_000).unwrap();
+
+        let frames = read_capture(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].direction, Direction::Inbound);
+        assert_eq!(frames[0].raw_bytes().unwrap(), compressed);
+        assert_eq!(frames[0].decoded.as_deref(), Some(r#"{"reqIdentifier":2001}"#));
+        assert_eq!(frames[1].time_ms, 2_000);
+        assert_eq!(frames[1].decoded, None);
+    }
+
+    #[test]
+    fn outbound_token_is_redacted() {
+        let path = std::env::temp_dir().join(format!("openim-capture-{}.jsonl", uuid::Uuid::new_v4()));
+        let recorder = FrameRecorder::create(&path).unwrap();
+        let req = br#"{"reqIdentifier":1003,"token":"secret-token-123","sendID":"u1","msgIncr":"1","data":[]}"#;
+        recorder.record(Direction::Outbound, FrameKind::Binary, req, 1_000).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let frames = read_capture(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!content.contains("secret-token-123"));
+        let raw = String::from_utf8(frames[0].raw_bytes().unwrap()).unwrap();
+        assert!(!raw.contains("secret-token-123"));
+        assert!(!frames[0].decoded.as_deref().unwrap().contains("secret-token-123"));
+        assert!(raw.contains(r#""sendID":"u1""#));
+    }
+}
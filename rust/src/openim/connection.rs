@@ -0,0 +1,152 @@
+//! 连接状态机
+//!
+//! ```text
+//! Idle ─► Connecting ─► Handshaking ─► Connected ◄─► Syncing
+//!            ▲   │           │              │
+//!            │   └───────────┴──────────────┴─► Reconnecting ─┐
+//!            └────────────────────────────────────────────────┘
+//! ```
+//!
+//! 握手被拒绝进入 TokenExpired（刷新后重新 Connecting），连接期间刷新 token 后直接重新 Connecting，
+//! 被踢下线进入 Kicked，主动断开进入 Closed。
+//! 任何状态都可以进入 Closed；Closed、Kicked、TokenExpired 可以重新 Connecting。
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+
+use super::OpenIMError;
+
+/// 重连间隔上限
+pub const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 建立连接后等待握手响应的时间，超时后按连接中断重连
+pub const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 连接状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// 尚未连接
+    Idle,
+    /// 建立 WebSocket 连接
+    Connecting,
+    /// 等待服务器握手响应
+    Handshaking,
+    Connected,
+    /// 已连接，正在同步离线消息
+    Syncing,
+    /// 连接断开，等待 backoff 后第 attempt 次重连
+    Reconnecting { attempt: u32, backoff: Duration },
+    /// 被踢下线（其他设备登录），不再自动重连
+    Kicked,
+    /// token 失效且无法刷新
+    TokenExpired,
+    /// 已断开，不再重连
+    Closed,
+}
+
+impl ConnectionState {
+    /// 是否允许从当前状态切换到 next
+    pub fn can_transition_to(&self, next: &ConnectionState) -> bool {
+        use ConnectionState::*;
+        match (self, next) {
+            (_, Closed) => !matches!(self, Closed),
+            (Idle | Reconnecting { .. } | Kicked | TokenExpired | Closed, Connecting) => true,
+            (Connecting, Handshaking | Reconnecting { .. }) => true,
+            (_, TokenExpired) => self.is_active() || matches!(self, Idle),
+            (Handshaking, Connected | Reconnecting { .. } | Kicked) => true,
+            (Connected, Syncing) | (Syncing, Connected) => true,
+            // token 刷新后用新 token 重连
+            (Connected | Syncing, Connecting) => true,
+            (Connected | Syncing, Reconnecting { .. } | Kicked) => true,
+            _ => false,
+        }
+    }
+
+    /// 已连接（可以发送请求）
+    pub fn is_connected(&self) -> bool {
+        matches!(self, Self::Connected | Self::Syncing)
+    }
+
+    /// 连接任务是否在运行（包括等待重连）
+    pub fn is_active(&self) -> bool {
+        !matches!(self, Self::Idle | Self::Kicked | Self::TokenExpired | Self::Closed)
+    }
+}
+
+/// 第 attempt 次（从 1 开始）重连前的等待时间：1s、2s、4s…，最长 [`MAX_BACKOFF`]
+pub fn backoff_delay(attempt: u32) -> Duration {
+    let exp = attempt.saturating_sub(1).min(16);
+    Duration::from_secs(1 << exp).min(MAX_BACKOFF)
+}
+
+/// 带校验的状态机，状态变化广播给订阅者
+pub struct StateMachine {
+    state: Mutex<ConnectionState>,
+    events: broadcast::Sender<ConnectionState>,
+}
+
+impl Default for StateMachine {
+    fn default() -> Self {
+        Self { state: Mutex::new(ConnectionState::Idle), events: broadcast::channel(32).0 }
+    }
+}
+
+impl StateMachine {
+    pub fn current(&self) -> ConnectionState {
+        *self.state.lock().unwrap()
+    }
+
+    /// 切换状态，不允许的切换返回错误且状态不变；与当前状态相同时忽略
+    pub fn transition(&self, next: ConnectionState) -> Result<(), OpenIMError> {
+        let mut state = self.state.lock().unwrap();
+        if *state == next {
+            return Ok(());
+        }
+        if !state.can_transition_to(&next) {
+            return Err(OpenIMError::InvalidState(format!("{:?} -> {:?}", *state, next)));
+        }
+        tracing::info!(from = ?*state, to = ?next, "连接状态变化");
+        *state = next;
+        let _ = self.events.send(next);
+        Ok(())
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ConnectionState> {
+        self.events.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ConnectionState::*;
+
+    #[test]
+    fn validates_transitions_and_broadcasts() {
+        let machine = StateMachine::default();
+        let mut events = machine.subscribe();
+        let reconnecting = Reconnecting { attempt: 1, backoff: backoff_delay(1) };
+        for state in [Connecting, Handshaking, Connected, Syncing, Connected, reconnecting, Connecting, Closed] {
+            machine.transition(state).unwrap();
+        }
+        // 相同状态不重复通知
+        machine.transition(Closed).unwrap();
+        let seen: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+        assert_eq!(seen, [Connecting, Handshaking, Connected, Syncing, Connected, reconnecting, Connecting, Closed]);
+
+        assert!(matches!(machine.transition(Connected), Err(OpenIMError::InvalidState(_))));
+        assert_eq!(machine.current(), Closed);
+        assert!(!Connecting.can_transition_to(&Connected));
+        assert!(!Kicked.can_transition_to(&Reconnecting { attempt: 1, backoff: Duration::ZERO }));
+        assert!(TokenExpired.can_transition_to(&Connecting));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_limit() {
+        let delays: Vec<_> = (1..=7).map(|n| backoff_delay(n).as_secs()).collect();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(backoff_delay(u32::MAX), MAX_BACKOFF);
+    }
+}
@@ -0,0 +1,493 @@
+//! 联系人（好友、黑名单、好友申请）及本地缓存
+//!
+//! 修改操作先调用 REST 接口，成功后更新缓存；好友相关通知同样更新缓存。
+//! 缓存变化通过 [`ContactList`] 事件通知，收到后重新读取对应列表即可。
+
+use std::sync::Arc;
+
+use rusqlite::{params, Row, Transaction};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use super::content::{content_type, notification_detail, FromToUserID};
+use super::rest::friend::{handle_result, BlackInfo, FriendInfo, FriendRequest};
+use super::rest::{self, ApiClient};
+use super::store::Store;
+use super::sync::{self, version_key, SyncVersion};
+use super::OpenIMError;
+
+/// 联系人列表类型（也用作缓存变化事件）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactList {
+    Friends,
+    Blacklist,
+    FriendRequests,
+}
+
+/// 好友
+#[derive(Debug, Clone, PartialEq)]
+pub struct Friend {
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub remark: String,
+    pub ex: String,
+    pub create_time: i64,
+}
+
+impl From<FriendInfo> for Friend {
+    fn from(info: FriendInfo) -> Self {
+        Self {
+            user_id: info.friend_user.user_id,
+            nickname: info.friend_user.nickname,
+            face_url: info.friend_user.face_url,
+            remark: info.remark,
+            ex: info.ex,
+            create_time: info.create_time,
+        }
+    }
+}
+
+/// 黑名单用户
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlackUser {
+    pub user_id: String,
+    pub nickname: String,
+    pub face_url: String,
+    pub create_time: i64,
+}
+
+impl From<BlackInfo> for BlackUser {
+    fn from(info: BlackInfo) -> Self {
+        Self {
+            user_id: info.black_user_info.user_id,
+            nickname: info.black_user_info.nickname,
+            face_url: info.black_user_info.face_url,
+            create_time: info.create_time,
+        }
+    }
+}
+
+/// FriendAddedTips
+#[derive(Deserialize)]
+struct FriendAddedTips {
+    #[serde(default)]
+    friend: Option<FriendInfo>,
+}
+
+/// 其余好友通知只需要操作双方
+#[derive(Deserialize)]
+struct FromToTips {
+    #[serde(rename = "fromToUserID", default)]
+    from_to_user_id: FromToUserID,
+}
+
+impl FromToUserID {
+    /// 操作的另一方
+    fn other(&self, me: &str) -> &str {
+        if self.from_user_id == me {
+            &self.to_user_id
+        } else {
+            &self.from_user_id
+        }
+    }
+}
+
+/// 联系人服务
+#[derive(Clone)]
+pub struct Contacts {
+    store: Arc<Store>,
+    events: broadcast::Sender<ContactList>,
+}
+
+impl Contacts {
+    pub fn new(store: Arc<Store>) -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self { store, events }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<ContactList> {
+        self.events.subscribe()
+    }
+
+    fn notify(&self, list: ContactList) {
+        let _ = self.events.send(list);
+    }
+
+    /// 缓存的好友（按备注 / 昵称排序）
+    pub fn friends(&self) -> Result<Vec<Friend>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT user_id, nickname, face_url, remark, ex, create_time FROM friends \
+             ORDER BY CASE remark WHEN '' THEN nickname ELSE remark END, user_id",
+        )?;
+        let friends = stmt
+            .query_map([], |row| {
+                Ok(Friend {
+                    user_id: row.get(0)?,
+                    nickname: row.get(1)?,
+                    face_url: row.get(2)?,
+                    remark: row.get(3)?,
+                    ex: row.get(4)?,
+                    create_time: row.get(5)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(friends)
+    }
+
+    /// 缓存的黑名单（最近加入的在前）
+    pub fn blacklist(&self) -> Result<Vec<BlackUser>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT user_id, nickname, face_url, create_time FROM blacklist ORDER BY create_time DESC, user_id",
+        )?;
+        let users = stmt
+            .query_map([], |row| {
+                Ok(BlackUser {
+                    user_id: row.get(0)?,
+                    nickname: row.get(1)?,
+                    face_url: row.get(2)?,
+                    create_time: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(users)
+    }
+
+    /// 缓存的好友申请（收到和发出的，最新的在前）
+    pub fn friend_requests(&self) -> Result<Vec<FriendRequest>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT from_user_id, from_nickname, from_face_url, to_user_id, to_nickname, to_face_url, \
+             handle_result, req_msg, create_time, handle_msg, handle_time FROM friend_requests \
+             ORDER BY create_time DESC",
+        )?;
+        let requests = stmt.query_map([], request_from_row)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(requests)
+    }
+
+    /// 从服务器拉取完整列表并替换缓存
+    pub async fn sync(&self, api: &ApiClient, me: &str, list: ContactList) -> Result<(), OpenIMError> {
+        match list {
+            ContactList::Friends => {
+                let friends = api.get_friend_list(me).await?;
+                self.replace(list, |tx| {
+                    for friend in friends {
+                        upsert_friend(tx, &friend.into())?;
+                    }
+                    Ok(())
+                })
+            }
+            ContactList::Blacklist => {
+                let blacks = api.get_black_list(me).await?;
+                self.replace(list, |tx| {
+                    for black in blacks {
+                        let user = BlackUser::from(black);
+                        tx.execute(
+                            "INSERT OR REPLACE INTO blacklist (user_id, nickname, face_url, create_time) \
+                             VALUES (?1, ?2, ?3, ?4)",
+                            params![user.user_id, user.nickname, user.face_url, user.create_time],
+                        )?;
+                    }
+                    Ok(())
+                })
+            }
+            ContactList::FriendRequests => {
+                let mut requests = api.get_friend_apply_list(me).await?;
+                requests.extend(api.get_self_friend_apply_list(me).await?);
+                self.replace(list, |tx| {
+                    for request in &requests {
+                        insert_request(tx, request)?;
+                    }
+                    Ok(())
+                })
+            }
+        }
+    }
+
+    /// 同步全部列表
+    pub async fn sync_all(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        for list in [ContactList::Friends, ContactList::Blacklist, ContactList::FriendRequests] {
+            self.sync(api, me, list).await?;
+        }
+        Ok(())
+    }
+
+    /// 登录和重连后同步：好友按版本增量同步（服务器不支持时拉取完整列表），黑名单和好友申请拉取完整列表
+    pub async fn sync_incremental(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        let since = sync::load_version(&*self.store.conn()?, version_key::FRIENDS)?;
+        match api.get_incremental_friends(me, &since.version_id, since.version).await {
+            Ok(inc) => {
+                let version = SyncVersion { version_id: inc.version_id.clone(), version: inc.version };
+                let full = inc.full;
+                let (deleted, upserts) = inc.into_changes();
+                let changed = full || !deleted.is_empty() || !upserts.is_empty();
+                {
+                    let mut conn = self.store.conn()?;
+                    let tx = conn.transaction()?;
+                    if full {
+                        tx.execute("DELETE FROM friends", [])?;
+                    }
+                    for user_id in &deleted {
+                        tx.execute("DELETE FROM friends WHERE user_id = ?1", params![user_id])?;
+                    }
+                    for friend in upserts {
+                        upsert_friend(&tx, &friend.into())?;
+                    }
+                    sync::save_version(&tx, version_key::FRIENDS, &version)?;
+                    tx.commit()?;
+                }
+                if changed {
+                    self.notify(ContactList::Friends);
+                }
+            }
+            Err(e) if rest::is_not_supported(&e) => self.sync(api, me, ContactList::Friends).await?,
+            Err(e) => return Err(e),
+        }
+        self.sync(api, me, ContactList::Blacklist).await?;
+        self.sync(api, me, ContactList::FriendRequests).await
+    }
+
+    /// 在事务中清空并重新写入某个列表
+    fn replace(
+        &self,
+        list: ContactList,
+        fill: impl FnOnce(&Transaction) -> Result<(), OpenIMError>,
+    ) -> Result<(), OpenIMError> {
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            tx.execute(&format!("DELETE FROM {}", table(list)), [])?;
+            fill(&tx)?;
+            tx.commit()?;
+        }
+        self.notify(list);
+        Ok(())
+    }
+
+    /// 删除缓存中的一条记录（好友或黑名单），有变化时通知
+    fn remove(&self, list: ContactList, user_id: &str) -> Result<(), OpenIMError> {
+        let removed = self
+            .store
+            .conn()?
+            .execute(&format!("DELETE FROM {} WHERE user_id = ?1", table(list)), params![user_id])?;
+        if removed > 0 {
+            self.notify(list);
+        }
+        Ok(())
+    }
+
+    /// 发送好友申请
+    pub async fn add_friend(&self, api: &ApiClient, me: &str, user_id: &str, req_msg: &str) -> Result<(), OpenIMError> {
+        api.add_friend(me, user_id, req_msg).await?;
+        self.sync(api, me, ContactList::FriendRequests).await
+    }
+
+    /// 同意或拒绝好友申请
+    pub async fn respond_friend_request(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        from_user_id: &str,
+        accept: bool,
+        handle_msg: &str,
+    ) -> Result<(), OpenIMError> {
+        let result = if accept { handle_result::ACCEPT } else { handle_result::REJECT };
+        api.add_friend_response(from_user_id, me, result, handle_msg).await?;
+        self.sync(api, me, ContactList::FriendRequests).await?;
+        if accept {
+            self.sync(api, me, ContactList::Friends).await?;
+        }
+        Ok(())
+    }
+
+    /// 删除好友
+    pub async fn delete_friend(&self, api: &ApiClient, me: &str, user_id: &str) -> Result<(), OpenIMError> {
+        api.delete_friend(me, user_id).await?;
+        self.remove(ContactList::Friends, user_id)
+    }
+
+    /// 设置好友备注
+    pub async fn set_friend_remark(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        user_id: &str,
+        remark: &str,
+    ) -> Result<(), OpenIMError> {
+        api.set_friend_remark(me, user_id, remark).await?;
+        self.store
+            .conn()?
+            .execute("UPDATE friends SET remark = ?2 WHERE user_id = ?1", params![user_id, remark])?;
+        self.notify(ContactList::Friends);
+        Ok(())
+    }
+
+    /// 加入黑名单（需要对方的资料，加入后重新拉取黑名单）
+    pub async fn add_black(&self, api: &ApiClient, me: &str, user_id: &str) -> Result<(), OpenIMError> {
+        api.add_black(me, user_id).await?;
+        self.sync(api, me, ContactList::Blacklist).await
+    }
+
+    /// 移出黑名单
+    pub async fn remove_black(&self, api: &ApiClient, me: &str, user_id: &str) -> Result<(), OpenIMError> {
+        api.remove_black(me, user_id).await?;
+        self.remove(ContactList::Blacklist, user_id)
+    }
+
+    /// 处理好友相关通知：能直接更新缓存的立即更新，其余返回需要从服务器重新拉取的列表
+    pub fn apply_notification(
+        &self,
+        me: &str,
+        content_type: i32,
+        content: &[u8],
+    ) -> Result<Vec<ContactList>, OpenIMError> {
+        let stale = match content_type {
+            content_type::FRIEND_APPLICATION | content_type::FRIEND_APPLICATION_REJECTED => {
+                vec![ContactList::FriendRequests]
+            }
+            content_type::FRIEND_APPLICATION_APPROVED => vec![ContactList::FriendRequests, ContactList::Friends],
+            content_type::FRIEND_ADDED => {
+                let tips: FriendAddedTips = notification_detail(content)?;
+                match tips.friend {
+                    // 通知发给双方，只有 owner 是自己的那份可以直接写入
+                    Some(friend) if friend.owner_user_id == me => {
+                        upsert_friend(&*self.store.conn()?, &friend.into())?;
+                        self.notify(ContactList::Friends);
+                        vec![]
+                    }
+                    _ => vec![ContactList::Friends],
+                }
+            }
+            content_type::FRIEND_DELETED => {
+                let tips: FromToTips = notification_detail(content)?;
+                self.remove(ContactList::Friends, tips.from_to_user_id.other(me))?;
+                vec![]
+            }
+            content_type::BLACK_DELETED => {
+                let tips: FromToTips = notification_detail(content)?;
+                self.remove(ContactList::Blacklist, tips.from_to_user_id.other(me))?;
+                vec![]
+            }
+            content_type::FRIEND_REMARK_SET | content_type::FRIEND_INFO_UPDATED => vec![ContactList::Friends],
+            content_type::BLACK_ADDED => vec![ContactList::Blacklist],
+            _ => vec![],
+        };
+        Ok(stale)
+    }
+}
+
+fn table(list: ContactList) -> &'static str {
+    match list {
+        ContactList::Friends => "friends",
+        ContactList::Blacklist => "blacklist",
+        ContactList::FriendRequests => "friend_requests",
+    }
+}
+
+fn upsert_friend(conn: &rusqlite::Connection, friend: &Friend) -> Result<(), OpenIMError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO friends (user_id, nickname, face_url, remark, ex, create_time) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![friend.user_id, friend.nickname, friend.face_url, friend.remark, friend.ex, friend.create_time],
+    )?;
+    Ok(())
+}
+
+fn insert_request(conn: &rusqlite::Connection, r: &FriendRequest) -> Result<(), OpenIMError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO friend_requests (from_user_id, from_nickname, from_face_url, to_user_id, \
+         to_nickname, to_face_url, handle_result, req_msg, create_time, handle_msg, handle_time) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            r.from_user_id,
+            r.from_nickname,
+            r.from_face_url,
+            r.to_user_id,
+            r.to_nickname,
+            r.to_face_url,
+            r.handle_result,
+            r.req_msg,
+            r.create_time,
+            r.handle_msg,
+            r.handle_time,
+        ],
+    )?;
+    Ok(())
+}
+
+fn request_from_row(row: &Row) -> rusqlite::Result<FriendRequest> {
+    Ok(FriendRequest {
+        from_user_id: row.get(0)?,
+        from_nickname: row.get(1)?,
+        from_face_url: row.get(2)?,
+        to_user_id: row.get(3)?,
+        to_nickname: row.get(4)?,
+        to_face_url: row.get(5)?,
+        handle_result: row.get(6)?,
+        req_msg: row.get(7)?,
+        create_time: row.get(8)?,
+        handle_msg: row.get(9)?,
+        handle_time: row.get(10)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rest::tests::serve;
+    use super::*;
+
+    fn notification(detail: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({ "detail": detail })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sync_then_apply_notifications() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"data":{"total":2,"friendsInfo":[{"ownerUserID":"me","remark":"","createTime":1,"friendUser":{"userID":"u2","nickname":"Bob"}},{"ownerUserID":"me","remark":"阿黄","createTime":2,"friendUser":{"userID":"u3","nickname":"Carl"}}]}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let contacts = Contacts::new(Arc::new(Store::open_in_memory().unwrap()));
+        let mut events = contacts.subscribe();
+
+        contacts.sync(&api, "me", ContactList::Friends).await.unwrap();
+        server.await.unwrap();
+        assert_eq!(events.try_recv().unwrap(), ContactList::Friends);
+        let names: Vec<_> = contacts.friends().unwrap().into_iter().map(|f| f.user_id).collect();
+        assert_eq!(names, ["u2", "u3"]);
+
+        // 对方删除了我
+        let stale = contacts
+            .apply_notification(
+                "me",
+                content_type::FRIEND_DELETED,
+                &notification(r#"{"fromToUserID":{"fromUserID":"u2","toUserID":"me"}}"#),
+            )
+            .unwrap();
+        assert!(stale.is_empty());
+        assert_eq!(events.try_recv().unwrap(), ContactList::Friends);
+        assert_eq!(contacts.friends().unwrap().len(), 1);
+
+        // 新好友直接写入
+        contacts
+            .apply_notification(
+                "me",
+                content_type::FRIEND_ADDED,
+                &notification(r#"{"friend":{"ownerUserID":"me","friendUser":{"userID":"u4","nickname":"Ann"}}}"#),
+            )
+            .unwrap();
+        assert_eq!(contacts.friends().unwrap()[0].nickname, "Ann");
+
+        // 申请类通知需要重新拉取
+        let stale = contacts
+            .apply_notification(
+                "me",
+                content_type::FRIEND_APPLICATION_APPROVED,
+                &notification(r#"{"fromToUserID":{"fromUserID":"me","toUserID":"u5"}}"#),
+            )
+            .unwrap();
+        assert_eq!(stale, [ContactList::FriendRequests, ContactList::Friends]);
+    }
+}
@@ -0,0 +1,166 @@
+//! 消息内容结构（对应 OpenIM SDK 中各 contentType 的 JSON 内容）
+
+use serde::{Deserialize, Serialize};
+
+/// 消息内容类型（对应服务器常量）
+#[allow(dead_code)]
+pub mod content_type {
+    pub const TEXT: i32 = 101;
+    pub const PICTURE: i32 = 102;
+    pub const VOICE: i32 = 103;
+    pub const VIDEO: i32 = 104;
+    pub const FILE: i32 = 105;
+    pub const AT_TEXT: i32 = 106;
+    pub const MERGER: i32 = 107;
+    pub const CARD: i32 = 108;
+    pub const LOCATION: i32 = 109;
+    pub const CUSTOM: i32 = 110;
+    pub const REVOKE: i32 = 111;
+    pub const TYPING: i32 = 113;
+    pub const QUOTE: i32 = 114;
+
+    // 好友通知
+    pub const FRIEND_APPLICATION: i32 = 1201;
+    pub const FRIEND_APPLICATION_APPROVED: i32 = 1202;
+    pub const FRIEND_APPLICATION_REJECTED: i32 = 1203;
+    pub const FRIEND_ADDED: i32 = 1204;
+    pub const FRIEND_DELETED: i32 = 1205;
+    pub const FRIEND_REMARK_SET: i32 = 1206;
+    pub const BLACK_ADDED: i32 = 1207;
+    pub const BLACK_DELETED: i32 = 1208;
+    pub const FRIEND_INFO_UPDATED: i32 = 1209;
+
+    // 会话通知
+    pub const CONVERSATION_CHANGED: i32 = 1300;
+    pub const CLEAR_CONVERSATION: i32 = 1307;
+
+    // 用户通知
+    pub const USER_INFO_UPDATED: i32 = 1303;
+
+    // 群通知
+    pub const GROUP_CREATED: i32 = 1501;
+    pub const GROUP_INFO_SET: i32 = 1502;
+    pub const JOIN_GROUP_APPLICATION: i32 = 1503;
+    pub const MEMBER_QUIT: i32 = 1504;
+    pub const GROUP_APPLICATION_ACCEPTED: i32 = 1505;
+    pub const GROUP_APPLICATION_REJECTED: i32 = 1506;
+    pub const GROUP_OWNER_TRANSFERRED: i32 = 1507;
+    pub const MEMBER_KICKED: i32 = 1508;
+    pub const MEMBER_INVITED: i32 = 1509;
+    pub const MEMBER_ENTER: i32 = 1510;
+    pub const GROUP_DISMISSED: i32 = 1511;
+    pub const GROUP_MEMBER_MUTED: i32 = 1512;
+    pub const GROUP_MEMBER_CANCEL_MUTED: i32 = 1513;
+    pub const GROUP_MUTED: i32 = 1514;
+    pub const GROUP_CANCEL_MUTED: i32 = 1515;
+    pub const GROUP_MEMBER_INFO_SET: i32 = 1516;
+    pub const GROUP_MEMBER_SET_TO_ADMIN: i32 = 1517;
+    pub const GROUP_MEMBER_SET_TO_ORDINARY: i32 = 1518;
+    pub const GROUP_INFO_SET_ANNOUNCEMENT: i32 = 1519;
+    pub const GROUP_INFO_SET_NAME: i32 = 1520;
+
+    // 消息扩展（表情回应）通知
+    pub const REACTION_MESSAGE_MODIFIER: i32 = 2301;
+    pub const REACTION_MESSAGE_DELETER: i32 = 2302;
+}
+
+/// 消息选项（MsgData.options 的键，对应服务器常量）
+#[allow(dead_code)]
+pub mod msg_option {
+    pub const IS_HISTORY: &str = "history";
+    pub const IS_PERSISTENT: &str = "persistent";
+    pub const IS_OFFLINE_PUSH: &str = "offlinePush";
+    pub const IS_UNREAD_COUNT: &str = "unreadCount";
+    pub const IS_CONVERSATION_UPDATE: &str = "conversationUpdate";
+    pub const IS_SENDER_SYNC: &str = "senderSync";
+}
+
+/// 图片基础信息（原图 / 大图 / 缩略图）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PictureBaseInfo {
+    #[serde(default)]
+    pub uuid: String,
+    #[serde(rename = "type", default)]
+    pub mime_type: String,
+    #[serde(default)]
+    pub size: i64,
+    #[serde(default)]
+    pub width: i32,
+    #[serde(default)]
+    pub height: i32,
+    #[serde(default)]
+    pub url: String,
+}
+
+/// 图片消息内容（contentType = 102）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PictureElem {
+    #[serde(rename = "sourcePath", default)]
+    pub source_path: String,
+    #[serde(rename = "sourcePicture", default)]
+    pub source_picture: PictureBaseInfo,
+    #[serde(rename = "bigPicture", default)]
+    pub big_picture: PictureBaseInfo,
+    #[serde(rename = "snapshotPicture", default)]
+    pub snapshot_picture: PictureBaseInfo,
+}
+
+/// 文本消息内容（contentType = 101）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextElem {
+    pub content: String,
+}
+
+/// 正在输入信令内容（contentType = 113），msgTips 为 "yes" / "no"
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypingElem {
+    #[serde(rename = "msgTips", default)]
+    pub msg_tips: String,
+}
+
+/// 通知消息内容，detail 为具体通知（各种 Tips）的 JSON 字符串
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotificationElem {
+    #[serde(default)]
+    pub detail: String,
+}
+
+/// 通知中的操作双方
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FromToUserID {
+    #[serde(rename = "fromUserID", default)]
+    pub from_user_id: String,
+    #[serde(rename = "toUserID", default)]
+    pub to_user_id: String,
+}
+
+/// 是否为表情回应变化（占用会话的 seq，但不作为消息展示）
+pub fn is_reaction_change(content_type: i32) -> bool {
+    matches!(
+        content_type,
+        content_type::REACTION_MESSAGE_MODIFIER | content_type::REACTION_MESSAGE_DELETER
+    )
+}
+
+/// 解析通知消息的 detail
+pub fn notification_detail<T: serde::de::DeserializeOwned>(content: &[u8]) -> Result<T, serde_json::Error> {
+    let elem: NotificationElem = serde_json::from_slice(content)?;
+    serde_json::from_str(&elem.detail)
+}
+
+/// 提取消息中可供搜索的文本，不含文本的类型返回 None
+pub fn searchable_text(content_type: i32, content: &[u8]) -> Option<String> {
+    let key = match content_type {
+        content_type::TEXT => "content",
+        content_type::AT_TEXT | content_type::QUOTE => "text",
+        content_type::FILE => "fileName",
+        content_type::CARD => "nickname",
+        content_type::LOCATION => "description",
+        _ => return None,
+    };
+    let json: serde_json::Value = serde_json::from_slice(content).ok()?;
+    json.get(key)
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
@@ -0,0 +1,57 @@
+//! 会话相关常量与会话 ID 规则（与 OpenIM SDK 保持一致）
+
+/// 会话类型（对应服务器常量）
+#[allow(dead_code)]
+pub mod session_type {
+    pub const SINGLE_CHAT: i32 = 1;
+    pub const WRITE_GROUP_CHAT: i32 = 2;
+    pub const READ_GROUP_CHAT: i32 = 3;
+    pub const NOTIFICATION_CHAT: i32 = 4;
+}
+
+/// 消息来源：用户发送
+pub const MSG_FROM_USER: i32 = 100;
+
+/// 单聊会话 ID：`si_` + 两个用户 ID 按字典序拼接
+pub fn single_conversation_id(user_a: &str, user_b: &str) -> String {
+    let (first, second) = if user_a <= user_b { (user_a, user_b) } else { (user_b, user_a) };
+    format!("si_{}_{}", first, second)
+}
+
+/// 群聊会话 ID
+pub fn group_conversation_id(group_id: &str) -> String {
+    format!("sg_{}", group_id)
+}
+
+/// 由会话 ID 解析出会话类型和对方（单聊为对方用户 ID，群聊为群 ID），无法识别时返回 None
+pub fn parse_conversation_id(conversation_id: &str, me: &str) -> Option<(i32, String, String)> {
+    if let Some(users) = conversation_id.strip_prefix("si_") {
+        let peer = users
+            .strip_prefix(me)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .or_else(|| users.strip_suffix(me).and_then(|rest| rest.strip_suffix('_')))?;
+        return Some((session_type::SINGLE_CHAT, peer.to_string(), String::new()));
+    }
+    conversation_id
+        .strip_prefix("sg_")
+        .map(|group_id| (session_type::READ_GROUP_CHAT, String::new(), group_id.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ids() {
+        let id = single_conversation_id("u2", "u1");
+        assert_eq!(id, "si_u1_u2");
+        assert_eq!(parse_conversation_id(&id, "u1"), Some((session_type::SINGLE_CHAT, "u2".to_string(), String::new())));
+        assert_eq!(parse_conversation_id(&id, "u2"), Some((session_type::SINGLE_CHAT, "u1".to_string(), String::new())));
+        assert_eq!(parse_conversation_id(&id, "u3"), None);
+        assert_eq!(
+            parse_conversation_id(&group_conversation_id("g1"), "u1"),
+            Some((session_type::READ_GROUP_CHAT, String::new(), "g1".to_string()))
+        );
+        assert_eq!(parse_conversation_id("sn_admin_u1", "u1"), None);
+    }
+}
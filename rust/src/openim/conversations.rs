@@ -0,0 +1,378 @@
+//! 会话列表与会话设置
+//!
+//! 收发消息时自动创建会话；置顶、免打扰通过服务器的会话接口同步，草稿和隐藏只保存在本地。
+//! 会话列表按置顶、最新消息（或草稿）时间排序，变化时通过事件通知会话 ID。
+
+use std::sync::Arc;
+
+use rusqlite::{params, OptionalExtension, Row};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use super::content::{content_type, notification_detail};
+use super::conversation::parse_conversation_id;
+use super::messages::{MessageStore, StoredMessage};
+use super::rest::conversation::{ConversationUpdate, ServerConversation};
+use super::rest::{self, ApiClient};
+use super::store::Store;
+use super::sync::{self, version_key, SyncVersion};
+use super::OpenIMError;
+
+const CONVERSATION_COLUMNS: &str = "conversation_id, conversation_type, user_id, group_id, recv_msg_opt, \
+     is_pinned, draft_text, draft_text_time, latest_msg_time, ex";
+
+/// 消息接收选项（对应服务器常量）
+pub mod recv_msg_opt {
+    pub const NORMAL: i32 = 0;
+    pub const NOT_RECEIVE: i32 = 1;
+    pub const NOT_NOTIFY: i32 = 2;
+}
+
+/// 会话
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversation {
+    pub conversation_id: String,
+    pub conversation_type: i32,
+    pub user_id: String,
+    pub group_id: String,
+    /// 见 [`recv_msg_opt`]
+    pub recv_msg_opt: i32,
+    pub is_pinned: bool,
+    pub draft_text: String,
+    pub draft_text_time: i64,
+    pub latest_msg_time: i64,
+    pub ex: String,
+}
+
+impl Conversation {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            conversation_id: row.get(0)?,
+            conversation_type: row.get(1)?,
+            user_id: row.get(2)?,
+            group_id: row.get(3)?,
+            recv_msg_opt: row.get(4)?,
+            is_pinned: row.get(5)?,
+            draft_text: row.get(6)?,
+            draft_text_time: row.get(7)?,
+            latest_msg_time: row.get(8)?,
+            ex: row.get(9)?,
+        })
+    }
+}
+
+/// ClearConversationTips
+#[derive(Deserialize)]
+struct ClearConversationTips {
+    #[serde(rename = "conversationIDs", default)]
+    conversation_ids: Option<Vec<String>>,
+}
+
+/// 会话服务
+#[derive(Clone)]
+pub struct Conversations {
+    store: Arc<Store>,
+    events: broadcast::Sender<String>,
+}
+
+impl Conversations {
+    pub fn new(store: Arc<Store>) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self { store, events }
+    }
+
+    /// 订阅会话变化（会话 ID）
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.events.subscribe()
+    }
+
+    fn notify(&self, conversation_id: &str) {
+        let _ = self.events.send(conversation_id.to_string());
+    }
+
+    fn messages(&self) -> MessageStore {
+        MessageStore::new(self.store.clone())
+    }
+
+    /// 会话列表（不含隐藏的会话），置顶在前，其余按最新消息或草稿时间倒序
+    pub fn list(&self) -> Result<Vec<(Conversation, Option<StoredMessage>)>, OpenIMError> {
+        let conversations = {
+            let conn = self.store.conn()?;
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {} FROM conversations WHERE hidden = 0 \
+                 ORDER BY is_pinned DESC, MAX(latest_msg_time, draft_text_time) DESC, conversation_id",
+                CONVERSATION_COLUMNS
+            ))?;
+            let rows = stmt.query_map([], Conversation::from_row)?.collect::<Result<Vec<_>, _>>()?;
+            rows
+        };
+        let messages = self.messages();
+        conversations
+            .into_iter()
+            .map(|c| {
+                let latest = messages.latest(&c.conversation_id)?;
+                Ok((c, latest))
+            })
+            .collect()
+    }
+
+    /// 单个会话（包括隐藏的）
+    pub fn get(&self, conversation_id: &str) -> Result<Option<Conversation>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let conversation = conn
+            .query_row(
+                &format!("SELECT {} FROM conversations WHERE conversation_id = ?1", CONVERSATION_COLUMNS),
+                params![conversation_id],
+                Conversation::from_row,
+            )
+            .optional()?;
+        Ok(conversation)
+    }
+
+    /// 收到或发出消息：创建会话、更新最新消息时间，并取消隐藏
+    pub fn on_message(&self, msg: &StoredMessage, me: &str) -> Result<(), OpenIMError> {
+        let user_id = if msg.group_id.is_empty() {
+            if msg.send_id == me { &msg.recv_id } else { &msg.send_id }
+        } else {
+            ""
+        };
+        self.store.conn()?.execute(
+            "INSERT INTO conversations (conversation_id, conversation_type, user_id, group_id, latest_msg_time) \
+             VALUES (?1, ?2, ?3, ?4, ?5) \
+             ON CONFLICT(conversation_id) DO UPDATE SET \
+             latest_msg_time = MAX(latest_msg_time, excluded.latest_msg_time), hidden = 0",
+            params![msg.conversation_id, msg.session_type, user_id, msg.group_id, msg.send_time],
+        )?;
+        self.notify(&msg.conversation_id);
+        Ok(())
+    }
+
+    /// 从服务器同步置顶、免打扰设置
+    pub async fn sync(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        let conversations = api.get_owner_conversations(me).await?;
+        for c in &conversations {
+            self.save_server(c)?;
+            self.notify(&c.conversation_id);
+        }
+        Ok(())
+    }
+
+    /// 按版本增量同步会话设置，服务器不支持时拉取完整列表
+    ///
+    /// 会话中还有草稿等本地数据，完整同步时只覆盖服务器设置，不删除本地会话；服务器删除的会话从本地删除。
+    pub async fn sync_incremental(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        let since = sync::load_version(&*self.store.conn()?, version_key::CONVERSATIONS)?;
+        let inc = match api.get_incremental_conversations(me, &since.version_id, since.version).await {
+            Ok(inc) => inc,
+            Err(e) if rest::is_not_supported(&e) => return self.sync(api, me).await,
+            Err(e) => return Err(e),
+        };
+        let version = SyncVersion { version_id: inc.version_id.clone(), version: inc.version };
+        let (deleted, upserts) = inc.into_changes();
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            for conversation_id in &deleted {
+                tx.execute("DELETE FROM conversations WHERE conversation_id = ?1", params![conversation_id])?;
+            }
+            for c in &upserts {
+                upsert_server(&tx, c)?;
+            }
+            sync::save_version(&tx, version_key::CONVERSATIONS, &version)?;
+            tx.commit()?;
+        }
+        for conversation_id in deleted.iter().chain(upserts.iter().map(|c| &c.conversation_id)) {
+            self.notify(conversation_id);
+        }
+        Ok(())
+    }
+
+    fn save_server(&self, c: &ServerConversation) -> Result<(), OpenIMError> {
+        upsert_server(&*self.store.conn()?, c)
+    }
+
+    /// 服务器请求需要的会话信息：优先使用本地会话，否则由会话 ID 推断
+    fn server_ref(&self, conversation_id: &str, me: &str) -> Result<ServerConversation, OpenIMError> {
+        if let Some(c) = self.get(conversation_id)? {
+            return Ok(ServerConversation {
+                conversation_id: c.conversation_id,
+                conversation_type: c.conversation_type,
+                user_id: c.user_id,
+                group_id: c.group_id,
+                recv_msg_opt: c.recv_msg_opt,
+                is_pinned: c.is_pinned,
+                ex: c.ex,
+            });
+        }
+        let (conversation_type, user_id, group_id) = parse_conversation_id(conversation_id, me)
+            .ok_or_else(|| OpenIMError::NotFound(format!("会话 {}", conversation_id)))?;
+        Ok(ServerConversation {
+            conversation_id: conversation_id.to_string(),
+            conversation_type,
+            user_id,
+            group_id,
+            ..Default::default()
+        })
+    }
+
+    /// 修改置顶 / 免打扰：先更新服务器，再写入本地
+    pub async fn update(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        conversation_id: &str,
+        update: ConversationUpdate,
+    ) -> Result<(), OpenIMError> {
+        let mut c = self.server_ref(conversation_id, me)?;
+        api.set_conversation(me, &c, &update).await?;
+        if let Some(opt) = update.recv_msg_opt {
+            c.recv_msg_opt = opt;
+        }
+        if let Some(pinned) = update.is_pinned {
+            c.is_pinned = pinned;
+        }
+        self.save_server(&c)?;
+        self.notify(conversation_id);
+        Ok(())
+    }
+
+    /// 保存草稿（空字符串表示清除），有草稿的会话按草稿时间排序
+    pub fn set_draft(&self, me: &str, conversation_id: &str, draft: &str, now_ms: i64) -> Result<(), OpenIMError> {
+        let c = self.server_ref(conversation_id, me)?;
+        let draft_time = if draft.is_empty() { 0 } else { now_ms };
+        self.store.conn()?.execute(
+            "INSERT INTO conversations (conversation_id, conversation_type, user_id, group_id, draft_text, draft_text_time) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6) \
+             ON CONFLICT(conversation_id) DO UPDATE SET \
+             draft_text = excluded.draft_text, draft_text_time = excluded.draft_text_time",
+            params![c.conversation_id, c.conversation_type, c.user_id, c.group_id, draft, draft_time],
+        )?;
+        self.notify(conversation_id);
+        Ok(())
+    }
+
+    /// 从列表中隐藏会话，收到新消息时重新显示
+    pub fn hide(&self, conversation_id: &str) -> Result<(), OpenIMError> {
+        self.store.conn()?.execute(
+            "UPDATE conversations SET hidden = 1 WHERE conversation_id = ?1",
+            params![conversation_id],
+        )?;
+        self.notify(conversation_id);
+        Ok(())
+    }
+
+    /// 清空会话消息（服务器和本地），会话保留在列表中
+    pub async fn clear_messages(&self, api: &ApiClient, me: &str, conversation_id: &str) -> Result<(), OpenIMError> {
+        api.clear_conversation_msg(me, &[conversation_id.to_string()]).await?;
+        self.messages().clear_conversation(conversation_id)?;
+        self.notify(conversation_id);
+        Ok(())
+    }
+
+    /// 删除会话：清空消息并从本地删除会话（包括草稿等本地设置）
+    pub async fn delete(&self, api: &ApiClient, me: &str, conversation_id: &str) -> Result<(), OpenIMError> {
+        api.clear_conversation_msg(me, &[conversation_id.to_string()]).await?;
+        self.messages().clear_conversation(conversation_id)?;
+        self.store.conn()?.execute(
+            "DELETE FROM conversations WHERE conversation_id = ?1",
+            params![conversation_id],
+        )?;
+        self.notify(conversation_id);
+        Ok(())
+    }
+
+    /// 处理会话通知，返回是否需要从服务器重新同步会话设置
+    pub fn apply_notification(&self, content_type: i32, content: &[u8]) -> Result<bool, OpenIMError> {
+        match content_type {
+            // 其他设备修改了会话设置
+            content_type::CONVERSATION_CHANGED => Ok(true),
+            // 其他设备清空了会话消息
+            content_type::CLEAR_CONVERSATION => {
+                let tips: ClearConversationTips = notification_detail(content)?;
+                let messages = self.messages();
+                for conversation_id in tips.conversation_ids.unwrap_or_default() {
+                    messages.clear_conversation(&conversation_id)?;
+                    self.notify(&conversation_id);
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+fn upsert_server(conn: &rusqlite::Connection, c: &ServerConversation) -> Result<(), OpenIMError> {
+    conn.execute(
+        "INSERT INTO conversations (conversation_id, conversation_type, user_id, group_id, recv_msg_opt, is_pinned, ex) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) \
+         ON CONFLICT(conversation_id) DO UPDATE SET \
+         recv_msg_opt = excluded.recv_msg_opt, is_pinned = excluded.is_pinned, ex = excluded.ex",
+        params![c.conversation_id, c.conversation_type, c.user_id, c.group_id, c.recv_msg_opt, c.is_pinned, c.ex],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(conversation_id: &str, client_msg_id: &str, send_time: i64) -> StoredMessage {
+        StoredMessage {
+            client_msg_id: client_msg_id.to_string(),
+            server_msg_id: String::new(),
+            conversation_id: conversation_id.to_string(),
+            seq: 0,
+            send_id: "u2".to_string(),
+            recv_id: "u1".to_string(),
+            group_id: String::new(),
+            session_type: 1,
+            content_type: content_type::TEXT,
+            content: br#"{"content":"hi"}"#.to_vec(),
+            send_time,
+        }
+    }
+
+    #[test]
+    fn list_order_draft_and_hide() {
+        let store = Arc::new(Store::open_in_memory().unwrap());
+        let conversations = Conversations::new(store.clone());
+        let messages = MessageStore::new(store);
+        for (id, time) in [("si_u1_u2", 100), ("si_u1_u3", 200), ("si_u1_u4", 300)] {
+            let msg = message(id, &format!("m{}", time), time);
+            messages.save(&msg).unwrap();
+            conversations.on_message(&msg, "u1").unwrap();
+        }
+        let order = |c: &Conversations| -> Vec<String> {
+            c.list().unwrap().into_iter().map(|(c, _)| c.conversation_id).collect()
+        };
+        assert_eq!(order(&conversations), ["si_u1_u4", "si_u1_u3", "si_u1_u2"]);
+        assert_eq!(conversations.get("si_u1_u2").unwrap().unwrap().user_id, "u2");
+
+        // 置顶（模拟服务器同步结果）和草稿都会改变顺序
+        conversations
+            .save_server(&ServerConversation {
+                conversation_id: "si_u1_u2".to_string(),
+                conversation_type: 1,
+                user_id: "u2".to_string(),
+                is_pinned: true,
+                ..Default::default()
+            })
+            .unwrap();
+        conversations.set_draft("u1", "si_u1_u3", "写了一半", 400).unwrap();
+        assert_eq!(order(&conversations), ["si_u1_u2", "si_u1_u3", "si_u1_u4"]);
+
+        // 隐藏后收到新消息重新出现
+        conversations.hide("si_u1_u4").unwrap();
+        assert_eq!(order(&conversations), ["si_u1_u2", "si_u1_u3"]);
+        conversations.on_message(&message("si_u1_u4", "m500", 500), "u1").unwrap();
+        assert_eq!(order(&conversations), ["si_u1_u2", "si_u1_u4", "si_u1_u3"]);
+
+        // 其他设备清空消息
+        let tips = serde_json::to_vec(&serde_json::json!({ "detail": r#"{"userID":"u1","conversationIDs":["si_u1_u2"]}"# })).unwrap();
+        assert!(!conversations.apply_notification(content_type::CLEAR_CONVERSATION, &tips).unwrap());
+        let list = conversations.list().unwrap();
+        assert_eq!(list[0].0.conversation_id, "si_u1_u2");
+        assert!(list[0].1.is_none());
+        assert_eq!(list[2].1.as_ref().unwrap().client_msg_id, "m200");
+    }
+}
@@ -0,0 +1,71 @@
+/// OpenIM 客户端错误类型
+#[derive(Debug, thiserror::Error)]
+pub enum OpenIMError {
+    /// 图片解码或编码失败
+    #[error("图片处理失败: {0}")]
+    Image(#[from] image::ImageError),
+
+    /// 不支持的图片格式
+    #[error("不支持的图片格式: {0}")]
+    UnsupportedImage(String),
+
+    /// JSON 序列化失败
+    #[error("JSON 序列化失败: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// 本地数据库错误
+    #[error("本地数据库错误: {0}")]
+    Store(#[from] rusqlite::Error),
+
+    /// 本地数据库已关闭（已退出登录）
+    #[error("本地数据库已关闭")]
+    StoreClosed,
+
+    /// 文件读写错误
+    #[error("文件读写错误: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// HTTP 请求失败
+    #[error("HTTP 请求失败: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// 服务器返回错误码
+    #[error("服务器返回错误: {code} - {msg} ({dlt})")]
+    Api { code: i32, msg: String, dlt: String },
+
+    /// token 无效
+    #[error("token 无效: {0}")]
+    InvalidToken(String),
+
+    /// 记录不存在
+    #[error("记录不存在: {0}")]
+    NotFound(String),
+
+    /// 会话已存在
+    #[error("会话已存在: {0}")]
+    SessionExists(String),
+
+    /// 连接或收发帧失败
+    #[error("连接错误: {0}")]
+    Transport(String),
+
+    /// WebSocket 请求失败（未连接、连接断开或超时）
+    #[error("请求失败: {0}")]
+    RequestFailed(String),
+
+    /// 操作已取消（主动断开连接或退出登录）
+    #[error("操作已取消")]
+    Cancelled,
+
+    /// 不允许的连接状态切换
+    #[error("连接状态错误: {0}")]
+    InvalidState(String),
+
+    /// 协议数据解析失败
+    #[error("协议解析失败: {0}")]
+    Decode(String),
+
+    /// 日志初始化失败
+    #[error("日志初始化失败: {0}")]
+    Logging(String),
+}
@@ -0,0 +1,592 @@
+//! 群组及本地缓存
+//!
+//! 与联系人相同：修改操作先调用 REST 接口再更新缓存，群通知中带有最新的群资料和相关成员，
+//! 直接写入缓存。缓存变化通过 [`GroupChange`] 事件通知。
+
+use std::sync::Arc;
+
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use super::content::{content_type, notification_detail};
+use super::rest::friend::handle_result;
+use super::rest::group::{role_level, GroupInfo, GroupMemberInfo};
+use super::rest::{self, ApiClient};
+use super::store::Store;
+use super::sync::{self, version_key, SyncVersion};
+use super::OpenIMError;
+
+const GROUP_COLUMNS: &str = "group_id, group_name, notification, introduction, face_url, owner_user_id, \
+     creator_user_id, member_count, status, group_type, need_verification, ex, create_time";
+
+const MEMBER_COLUMNS: &str = "group_id, user_id, nickname, face_url, role_level, join_time, join_source, \
+     inviter_user_id, mute_end_time, ex";
+
+/// 群缓存变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupChange {
+    /// 已加入的群列表变化（加入、退出、被踢、解散）
+    JoinedGroups,
+    /// 群资料变化
+    Info { group_id: String },
+    /// 群成员变化
+    Members { group_id: String },
+}
+
+/// 群通知的公共结构（各种群 Tips 的字段并集，Go 端空列表序列化为 null）
+#[derive(Default, Deserialize)]
+struct GroupTips {
+    #[serde(default)]
+    group: Option<GroupInfo>,
+    #[serde(rename = "memberList", default)]
+    member_list: Option<Vec<GroupMemberInfo>>,
+    #[serde(rename = "invitedUserList", default)]
+    invited_user_list: Option<Vec<GroupMemberInfo>>,
+    #[serde(rename = "kickedUserList", default)]
+    kicked_user_list: Option<Vec<GroupMemberInfo>>,
+    #[serde(rename = "quitUser", default)]
+    quit_user: Option<GroupMemberInfo>,
+    #[serde(rename = "entrantUser", default)]
+    entrant_user: Option<GroupMemberInfo>,
+    #[serde(rename = "mutedUser", default)]
+    muted_user: Option<GroupMemberInfo>,
+    #[serde(rename = "changedUser", default)]
+    changed_user: Option<GroupMemberInfo>,
+    #[serde(rename = "newGroupOwner", default)]
+    new_group_owner: Option<GroupMemberInfo>,
+}
+
+/// 群组服务
+#[derive(Clone)]
+pub struct Groups {
+    store: Arc<Store>,
+    events: broadcast::Sender<GroupChange>,
+}
+
+impl Groups {
+    pub fn new(store: Arc<Store>) -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self { store, events }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<GroupChange> {
+        self.events.subscribe()
+    }
+
+    fn notify(&self, change: GroupChange) {
+        let _ = self.events.send(change);
+    }
+
+    /// 已加入的群（本地缓存）
+    pub fn joined_groups(&self) -> Result<Vec<GroupInfo>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM groups ORDER BY group_name, group_id",
+            GROUP_COLUMNS
+        ))?;
+        let groups = stmt.query_map([], group_from_row)?.collect::<Result<Vec<_>, _>>()?;
+        Ok(groups)
+    }
+
+    /// 群资料（本地缓存）
+    pub fn group(&self, group_id: &str) -> Result<Option<GroupInfo>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let group = conn
+            .query_row(
+                &format!("SELECT {} FROM groups WHERE group_id = ?1", GROUP_COLUMNS),
+                params![group_id],
+                group_from_row,
+            )
+            .optional()?;
+        Ok(group)
+    }
+
+    /// 分页读取群成员（群主、管理员在前，其余按入群时间）
+    pub fn members(&self, group_id: &str, offset: u32, limit: u32) -> Result<Vec<GroupMemberInfo>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM group_members WHERE group_id = ?1 \
+             ORDER BY role_level DESC, join_time, user_id LIMIT ?2 OFFSET ?3",
+            MEMBER_COLUMNS
+        ))?;
+        let members = stmt
+            .query_map(params![group_id, limit, offset], member_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(members)
+    }
+
+    /// 单个群成员（本地缓存）
+    pub fn member(&self, group_id: &str, user_id: &str) -> Result<Option<GroupMemberInfo>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let member = conn
+            .query_row(
+                &format!("SELECT {} FROM group_members WHERE group_id = ?1 AND user_id = ?2", MEMBER_COLUMNS),
+                params![group_id, user_id],
+                member_from_row,
+            )
+            .optional()?;
+        Ok(member)
+    }
+
+    /// 从服务器同步已加入的群，不再加入的群连同成员一起删除
+    pub async fn sync_joined_groups(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        let groups = api.get_joined_group_list(me).await?;
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            tx.execute("DELETE FROM groups", [])?;
+            for group in &groups {
+                upsert_group(&tx, group)?;
+            }
+            tx.execute("DELETE FROM group_members WHERE group_id NOT IN (SELECT group_id FROM groups)", [])?;
+            tx.commit()?;
+        }
+        self.notify(GroupChange::JoinedGroups);
+        Ok(())
+    }
+
+    /// 按版本增量同步已加入的群，服务器不支持时拉取完整列表；退出的群连同成员一起删除
+    pub async fn sync_joined_groups_incremental(&self, api: &ApiClient, me: &str) -> Result<(), OpenIMError> {
+        let since = sync::load_version(&*self.store.conn()?, version_key::JOINED_GROUPS)?;
+        let inc = match api.get_incremental_join_groups(me, &since.version_id, since.version).await {
+            Ok(inc) => inc,
+            Err(e) if rest::is_not_supported(&e) => return self.sync_joined_groups(api, me).await,
+            Err(e) => return Err(e),
+        };
+        let version = SyncVersion { version_id: inc.version_id.clone(), version: inc.version };
+        let full = inc.full;
+        let (deleted, upserts) = inc.into_changes();
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            if full {
+                tx.execute("DELETE FROM groups", [])?;
+            }
+            for group_id in &deleted {
+                tx.execute("DELETE FROM groups WHERE group_id = ?1", params![group_id])?;
+            }
+            for group in &upserts {
+                upsert_group(&tx, group)?;
+            }
+            tx.execute("DELETE FROM group_members WHERE group_id NOT IN (SELECT group_id FROM groups)", [])?;
+            sync::save_version(&tx, version_key::JOINED_GROUPS, &version)?;
+            tx.commit()?;
+        }
+        if full || !deleted.is_empty() || !upserts.is_empty() {
+            self.notify(GroupChange::JoinedGroups);
+        }
+        for group in upserts {
+            self.notify(GroupChange::Info { group_id: group.group_id });
+        }
+        Ok(())
+    }
+
+    /// 从服务器同步某个群的全部成员
+    pub async fn sync_members(&self, api: &ApiClient, group_id: &str) -> Result<(), OpenIMError> {
+        let members = api.get_all_group_members(group_id).await?;
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            tx.execute("DELETE FROM group_members WHERE group_id = ?1", params![group_id])?;
+            for member in &members {
+                upsert_member(&tx, group_id, member)?;
+            }
+            tx.commit()?;
+        }
+        self.notify(GroupChange::Members { group_id: group_id.to_string() });
+        Ok(())
+    }
+
+    /// 从服务器刷新群资料
+    pub async fn refresh_group(&self, api: &ApiClient, group_id: &str) -> Result<(), OpenIMError> {
+        for group in api.get_groups_info(&[group_id.to_string()]).await? {
+            upsert_group(&*self.store.conn()?, &group)?;
+            self.notify(GroupChange::Info { group_id: group.group_id });
+        }
+        Ok(())
+    }
+
+    /// 创建群（自己为群主），返回新群资料
+    pub async fn create_group(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        group_name: &str,
+        member_user_ids: &[String],
+        admin_user_ids: &[String],
+    ) -> Result<GroupInfo, OpenIMError> {
+        let group = api.create_group(me, group_name, member_user_ids, admin_user_ids).await?;
+        upsert_group(&*self.store.conn()?, &group)?;
+        self.notify(GroupChange::JoinedGroups);
+        self.sync_members(api, &group.group_id).await?;
+        Ok(group)
+    }
+
+    /// 邀请入群
+    pub async fn invite(&self, api: &ApiClient, group_id: &str, user_ids: &[String], reason: &str) -> Result<(), OpenIMError> {
+        api.invite_user_to_group(group_id, user_ids, reason).await?;
+        self.sync_members(api, group_id).await
+    }
+
+    /// 踢出群
+    pub async fn kick(&self, api: &ApiClient, group_id: &str, user_ids: &[String], reason: &str) -> Result<(), OpenIMError> {
+        api.kick_group(group_id, user_ids, reason).await?;
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            for user_id in user_ids {
+                tx.execute(
+                    "DELETE FROM group_members WHERE group_id = ?1 AND user_id = ?2",
+                    params![group_id, user_id],
+                )?;
+            }
+            tx.commit()?;
+        }
+        self.notify(GroupChange::Members { group_id: group_id.to_string() });
+        Ok(())
+    }
+
+    /// 申请入群：加入后（不需要验证或管理员同意时）由入群通知写入缓存
+    pub async fn join(&self, api: &ApiClient, group_id: &str, req_msg: &str, join_source: i32) -> Result<(), OpenIMError> {
+        api.join_group(group_id, req_msg, join_source).await?;
+        Ok(())
+    }
+
+    /// 同意或拒绝入群申请（群主或管理员），同意后同步群成员
+    pub async fn respond_application(
+        &self,
+        api: &ApiClient,
+        group_id: &str,
+        from_user_id: &str,
+        accept: bool,
+        handled_msg: &str,
+    ) -> Result<(), OpenIMError> {
+        let result = if accept { handle_result::ACCEPT } else { handle_result::REJECT };
+        api.group_application_response(group_id, from_user_id, result, handled_msg).await?;
+        if accept {
+            self.sync_members(api, group_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 退出群
+    pub async fn quit(&self, api: &ApiClient, me: &str, group_id: &str) -> Result<(), OpenIMError> {
+        api.quit_group(group_id, me).await?;
+        self.remove_group(group_id)
+    }
+
+    /// 解散群（仅群主）
+    pub async fn dismiss(&self, api: &ApiClient, group_id: &str) -> Result<(), OpenIMError> {
+        api.dismiss_group(group_id).await?;
+        self.remove_group(group_id)
+    }
+
+    /// 转让群主，自己变为普通成员
+    pub async fn transfer_owner(&self, api: &ApiClient, me: &str, group_id: &str, new_owner: &str) -> Result<(), OpenIMError> {
+        api.transfer_group(group_id, me, new_owner).await?;
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            tx.execute(
+                "UPDATE groups SET owner_user_id = ?2 WHERE group_id = ?1",
+                params![group_id, new_owner],
+            )?;
+            set_owner(&tx, group_id, new_owner)?;
+            tx.commit()?;
+        }
+        self.notify(GroupChange::Info { group_id: group_id.to_string() });
+        self.notify(GroupChange::Members { group_id: group_id.to_string() });
+        Ok(())
+    }
+
+    /// 设置或取消管理员
+    pub async fn set_admin(&self, api: &ApiClient, group_id: &str, user_id: &str, admin: bool) -> Result<(), OpenIMError> {
+        let role = if admin { role_level::ADMIN } else { role_level::ORDINARY };
+        api.set_group_member_role(group_id, user_id, role).await?;
+        self.store.conn()?.execute(
+            "UPDATE group_members SET role_level = ?3 WHERE group_id = ?1 AND user_id = ?2",
+            params![group_id, user_id, role],
+        )?;
+        self.notify(GroupChange::Members { group_id: group_id.to_string() });
+        Ok(())
+    }
+
+    /// 禁言成员，muted_seconds 为 0 时解除禁言
+    pub async fn mute_member(
+        &self,
+        api: &ApiClient,
+        group_id: &str,
+        user_id: &str,
+        muted_seconds: u32,
+        now_ms: i64,
+    ) -> Result<(), OpenIMError> {
+        api.mute_group_member(group_id, user_id, muted_seconds).await?;
+        let mute_end_time = if muted_seconds == 0 { 0 } else { now_ms + muted_seconds as i64 * 1000 };
+        self.store.conn()?.execute(
+            "UPDATE group_members SET mute_end_time = ?3 WHERE group_id = ?1 AND user_id = ?2",
+            params![group_id, user_id, mute_end_time],
+        )?;
+        self.notify(GroupChange::Members { group_id: group_id.to_string() });
+        Ok(())
+    }
+
+    /// 全员禁言 / 解除全员禁言
+    pub async fn mute_group(&self, api: &ApiClient, group_id: &str, muted: bool) -> Result<(), OpenIMError> {
+        api.mute_group(group_id, muted).await?;
+        self.refresh_group(api, group_id).await
+    }
+
+    /// 删除群及其成员（退出、被踢、解散）
+    fn remove_group(&self, group_id: &str) -> Result<(), OpenIMError> {
+        {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            tx.execute("DELETE FROM groups WHERE group_id = ?1", params![group_id])?;
+            tx.execute("DELETE FROM group_members WHERE group_id = ?1", params![group_id])?;
+            tx.commit()?;
+        }
+        self.notify(GroupChange::JoinedGroups);
+        Ok(())
+    }
+
+    /// 根据群通知更新缓存
+    pub fn apply_notification(&self, me: &str, content_type: i32, content: &[u8]) -> Result<(), OpenIMError> {
+        match content_type {
+            // 入群申请暂不缓存
+            content_type::JOIN_GROUP_APPLICATION | content_type::GROUP_APPLICATION_REJECTED => return Ok(()),
+            content_type::GROUP_CREATED..=content_type::GROUP_INFO_SET_NAME => {}
+            _ => return Ok(()),
+        }
+        let tips: GroupTips = notification_detail(content)?;
+        let Some(group) = tips.group else {
+            return Ok(());
+        };
+        let group_id = group.group_id.clone();
+
+        let mut removed: Vec<GroupMemberInfo> = tips.kicked_user_list.unwrap_or_default();
+        removed.extend(tips.quit_user);
+        if content_type == content_type::GROUP_DISMISSED || removed.iter().any(|m| m.user_id == me) {
+            return self.remove_group(&group_id);
+        }
+        let mut updated = tips.member_list.unwrap_or_default();
+        updated.extend(tips.invited_user_list.unwrap_or_default());
+        updated.extend(tips.entrant_user);
+        updated.extend(tips.muted_user);
+        updated.extend(tips.changed_user);
+        let new_owner = tips.new_group_owner.map(|m| m.user_id.clone());
+
+        let joined = {
+            let mut conn = self.store.conn()?;
+            let tx = conn.transaction()?;
+            let known = tx
+                .query_row("SELECT 1 FROM groups WHERE group_id = ?1", params![group_id], |_| Ok(()))
+                .optional()?
+                .is_some();
+            upsert_group(&tx, &group)?;
+            for member in &removed {
+                tx.execute(
+                    "DELETE FROM group_members WHERE group_id = ?1 AND user_id = ?2",
+                    params![group_id, member.user_id],
+                )?;
+            }
+            for member in &updated {
+                upsert_member(&tx, &group_id, member)?;
+            }
+            if let Some(new_owner) = &new_owner {
+                set_owner(&tx, &group_id, new_owner)?;
+            }
+            tx.commit()?;
+            !known
+        };
+        if joined {
+            self.notify(GroupChange::JoinedGroups);
+        }
+        self.notify(GroupChange::Info { group_id: group_id.clone() });
+        if !removed.is_empty() || !updated.is_empty() || new_owner.is_some() {
+            self.notify(GroupChange::Members { group_id });
+        }
+        Ok(())
+    }
+}
+
+fn upsert_group(conn: &Connection, g: &GroupInfo) -> Result<(), OpenIMError> {
+    conn.execute(
+        &format!(
+            "INSERT OR REPLACE INTO groups ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            GROUP_COLUMNS
+        ),
+        params![
+            g.group_id,
+            g.group_name,
+            g.notification,
+            g.introduction,
+            g.face_url,
+            g.owner_user_id,
+            g.creator_user_id,
+            g.member_count,
+            g.status,
+            g.group_type,
+            g.need_verification,
+            g.ex,
+            g.create_time,
+        ],
+    )?;
+    Ok(())
+}
+
+fn upsert_member(conn: &Connection, group_id: &str, m: &GroupMemberInfo) -> Result<(), OpenIMError> {
+    conn.execute(
+        &format!(
+            "INSERT OR REPLACE INTO group_members ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            MEMBER_COLUMNS
+        ),
+        params![
+            group_id,
+            m.user_id,
+            m.nickname,
+            m.face_url,
+            m.role_level,
+            m.join_time,
+            m.join_source,
+            m.inviter_user_id,
+            m.mute_end_time,
+            m.ex,
+        ],
+    )?;
+    Ok(())
+}
+
+/// 设置新群主，原群主降为普通成员
+fn set_owner(conn: &Connection, group_id: &str, new_owner: &str) -> Result<(), OpenIMError> {
+    conn.execute(
+        "UPDATE group_members SET role_level = CASE WHEN user_id = ?2 THEN ?3 ELSE ?4 END \
+         WHERE group_id = ?1 AND (user_id = ?2 OR role_level = ?3)",
+        params![group_id, new_owner, role_level::OWNER, role_level::ORDINARY],
+    )?;
+    Ok(())
+}
+
+fn group_from_row(row: &Row) -> rusqlite::Result<GroupInfo> {
+    Ok(GroupInfo {
+        group_id: row.get(0)?,
+        group_name: row.get(1)?,
+        notification: row.get(2)?,
+        introduction: row.get(3)?,
+        face_url: row.get(4)?,
+        owner_user_id: row.get(5)?,
+        creator_user_id: row.get(6)?,
+        member_count: row.get(7)?,
+        status: row.get(8)?,
+        group_type: row.get(9)?,
+        need_verification: row.get(10)?,
+        ex: row.get(11)?,
+        create_time: row.get(12)?,
+    })
+}
+
+fn member_from_row(row: &Row) -> rusqlite::Result<GroupMemberInfo> {
+    Ok(GroupMemberInfo {
+        group_id: row.get(0)?,
+        user_id: row.get(1)?,
+        nickname: row.get(2)?,
+        face_url: row.get(3)?,
+        role_level: row.get(4)?,
+        join_time: row.get(5)?,
+        join_source: row.get(6)?,
+        inviter_user_id: row.get(7)?,
+        mute_end_time: row.get(8)?,
+        ex: row.get(9)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rest::tests::serve;
+    use super::*;
+
+    fn notification(detail: serde_json::Value) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({ "detail": detail.to_string() })).unwrap()
+    }
+
+    fn member(user_id: &str, role: i32, join_time: i64) -> serde_json::Value {
+        serde_json::json!({ "groupID": "g1", "userID": user_id, "roleLevel": role, "joinTime": join_time })
+    }
+
+    #[test]
+    fn notifications_update_cache() {
+        let groups = Groups::new(Arc::new(Store::open_in_memory().unwrap()));
+        let mut events = groups.subscribe();
+        let group = serde_json::json!({ "groupID": "g1", "groupName": "周末爬山", "ownerUserID": "u1", "memberCount": 3 });
+
+        groups
+            .apply_notification(
+                "me",
+                content_type::GROUP_CREATED,
+                &notification(serde_json::json!({
+                    "group": group,
+                    "opUser": member("u1", role_level::OWNER, 1),
+                    "memberList": [member("u1", role_level::OWNER, 1), member("me", role_level::ORDINARY, 2), member("u3", role_level::ORDINARY, 3)],
+                })),
+            )
+            .unwrap();
+        assert_eq!(events.try_recv().unwrap(), GroupChange::JoinedGroups);
+        assert_eq!(groups.joined_groups().unwrap()[0].group_name, "周末爬山");
+        let ids = |groups: &Groups| -> Vec<String> {
+            groups.members("g1", 0, 10).unwrap().into_iter().map(|m| m.user_id).collect()
+        };
+        assert_eq!(ids(&groups), ["u1", "me", "u3"]);
+
+        // 转让群主：新群主排到最前，原群主降为普通成员
+        groups
+            .apply_notification(
+                "me",
+                content_type::GROUP_OWNER_TRANSFERRED,
+                &notification(serde_json::json!({ "group": group, "newGroupOwner": member("u3", role_level::OWNER, 3) })),
+            )
+            .unwrap();
+        assert_eq!(ids(&groups), ["u3", "u1", "me"]);
+        assert_eq!(groups.member("g1", "u1").unwrap().unwrap().role_level, role_level::ORDINARY);
+
+        groups
+            .apply_notification(
+                "me",
+                content_type::MEMBER_KICKED,
+                &notification(serde_json::json!({ "group": group, "kickedUserList": [member("u1", 20, 1)], "invitedUserList": null })),
+            )
+            .unwrap();
+        assert_eq!(groups.members("g1", 1, 10).unwrap().len(), 1);
+
+        // 自己被踢出后整个群从缓存删除
+        groups
+            .apply_notification(
+                "me",
+                content_type::MEMBER_KICKED,
+                &notification(serde_json::json!({ "group": group, "kickedUserList": [member("me", 20, 2)] })),
+            )
+            .unwrap();
+        assert!(groups.joined_groups().unwrap().is_empty());
+        assert!(groups.members("g1", 0, 10).unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn accepted_application_syncs_members() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"errMsg":"","errDlt":""}"#,
+            r#"{"errCode":0,"data":{"total":2,"members":[{"groupID":"g1","userID":"me","roleLevel":100},{"groupID":"g1","userID":"u2","roleLevel":20}]}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let groups = Groups::new(Arc::new(Store::open_in_memory().unwrap()));
+        let mut events = groups.subscribe();
+
+        groups.respond_application(&api, "g1", "u2", true, "").await.unwrap();
+        assert_eq!(events.try_recv().unwrap(), GroupChange::Members { group_id: "g1".to_string() });
+        let ids: Vec<String> = groups.members("g1", 0, 10).unwrap().into_iter().map(|m| m.user_id).collect();
+        assert_eq!(ids, ["me", "u2"]);
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].ends_with(r#""handleResult":1}"#));
+        assert!(requests[1].starts_with("POST /group/get_group_member_list "));
+    }
+}
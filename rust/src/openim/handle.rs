@@ -0,0 +1,187 @@
+//! 连接任务的命令通道
+//!
+//! 连接任务（`connect_and_run`）独占连接，其他调用通过 [`ClientHandle`] 发送命令，由连接任务按顺序写出。
+//! 句柄可以克隆，在多个调用中并发使用。普通消息不走命令通道，而是写入发件箱，由连接任务按顺序发送和重试。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use openim_protocol::sdkws::MsgData;
+use tokio::sync::{mpsc, oneshot, Notify};
+
+use super::connection::{ConnectionState, StateMachine};
+use super::OpenIMError;
+
+/// 等待请求响应（拉取消息等）的超时时间
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 请求的响应数据（服务器错误码不为 0 时为 [`OpenIMError::Api`]）
+pub(crate) type Reply = oneshot::Sender<Result<Vec<u8>, OpenIMError>>;
+
+/// 连接任务接收的命令
+pub(crate) enum Command {
+    /// 发送信令（正在输入等），断线期间积压的直接丢弃
+    Signal(Box<MsgData>),
+    /// 发送请求并等待对应 msgIncr 的响应
+    Request { req_identifier: i32, data: Vec<u8>, reply: Reply },
+    /// 切换前后台状态（重连后自动恢复）
+    SetBackground(bool),
+    /// 关闭当前连接（发送 close 帧），`connect_and_run` 返回；关闭后通知调用方
+    Disconnect(oneshot::Sender<()>),
+}
+
+impl Command {
+    /// 断线期间积压、重连后不再执行的命令
+    pub(crate) fn is_stale(&self) -> bool {
+        match self {
+            Self::Signal(_) | Self::Disconnect(_) => true,
+            // 调用方已超时
+            Self::Request { reply, .. } => reply.is_closed(),
+            Self::SetBackground(_) => false,
+        }
+    }
+}
+
+/// 客户端句柄
+#[derive(Clone)]
+pub struct ClientHandle {
+    commands: mpsc::Sender<Command>,
+    state: Arc<StateMachine>,
+    /// 未连接（正在连接或等待重连）时请求停止
+    stopping: Arc<AtomicBool>,
+    stop: Arc<Notify>,
+}
+
+impl ClientHandle {
+    pub(crate) fn new(capacity: usize) -> (Self, mpsc::Receiver<Command>) {
+        let (commands, rx) = mpsc::channel(capacity);
+        let handle = Self {
+            commands,
+            state: Arc::new(StateMachine::default()),
+            stopping: Arc::new(AtomicBool::new(false)),
+            stop: Arc::new(Notify::new()),
+        };
+        (handle, rx)
+    }
+
+    pub(crate) fn state_machine(&self) -> &StateMachine {
+        &self.state
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state.current()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.state.current().is_connected()
+    }
+
+    /// 是否已请求停止（连接任务在连接前和重连等待中检查）
+    pub(crate) fn stop_requested(&self) -> bool {
+        self.stopping.load(Ordering::Relaxed)
+    }
+
+    /// 等待停止请求
+    pub(crate) async fn stopped(&self) {
+        let notified = self.stop.notified();
+        if !self.stop_requested() {
+            notified.await;
+        }
+    }
+
+    pub(crate) fn reset_stop(&self) {
+        self.stopping.store(false, Ordering::Relaxed);
+    }
+
+    /// 发送信令，队列已满（连接不可用）时直接丢弃
+    pub(crate) fn signal(&self, msg: MsgData) {
+        let _ = self.commands.try_send(Command::Signal(Box::new(msg)));
+    }
+
+    /// 发送请求并等待响应数据（需要连接正在运行，超时返回错误）
+    pub async fn request(&self, req_identifier: i32, data: Vec<u8>) -> Result<Vec<u8>, OpenIMError> {
+        let (reply, response) = oneshot::channel();
+        let wait = async {
+            self.send(Command::Request { req_identifier, data, reply }).await?;
+            response.await.map_err(|_| OpenIMError::RequestFailed("连接已断开".to_string()))?
+        };
+        tokio::time::timeout(REQUEST_TIMEOUT, wait)
+            .await
+            .map_err(|_| OpenIMError::RequestFailed("等待响应超时".to_string()))?
+    }
+
+    /// 切换前后台状态，服务器对后台连接改为离线推送
+    pub async fn set_background(&self, background: bool) -> Result<(), OpenIMError> {
+        self.send(Command::SetBackground(background)).await
+    }
+
+    /// 断开当前连接并等待关闭完成，正在连接或等待重连时停止重连
+    ///
+    /// 等待响应的请求以 [`OpenIMError::Cancelled`] 结束；发件箱中未确认的消息保留在数据库中，下次连接时重新发送。
+    pub async fn disconnect(&self) -> Result<(), OpenIMError> {
+        let state = self.state();
+        if !state.is_connected() {
+            if state.is_active() {
+                self.stopping.store(true, Ordering::Relaxed);
+                self.stop.notify_waiters();
+            }
+            return Ok(());
+        }
+        let (done, closed) = oneshot::channel();
+        self.send(Command::Disconnect(done)).await?;
+        // 命令被丢弃（连接恰好已断开）时同样视为完成
+        let _ = tokio::time::timeout(REQUEST_TIMEOUT, closed).await;
+        Ok(())
+    }
+
+    pub(crate) async fn send(&self, command: Command) -> Result<(), OpenIMError> {
+        self.commands
+            .send(command)
+            .await
+            .map_err(|_| OpenIMError::RequestFailed("客户端已关闭".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_from_cloned_handles_share_one_queue() {
+        let (handle, mut rx) = ClientHandle::new(8);
+        let tasks: Vec<_> = (0..3)
+            .map(|i| {
+                let handle = handle.clone();
+                tokio::spawn(async move { handle.request(1002, vec![i]).await })
+            })
+            .collect();
+
+        for _ in 0..3 {
+            let Some(Command::Request { data, reply, .. }) = rx.recv().await else {
+                panic!("应为请求命令");
+            };
+            let result = match data.as_slice() {
+                [1] => Err(OpenIMError::Api { code: 1001, msg: "ArgsError".to_string(), dlt: String::new() }),
+                _ => Ok(data),
+            };
+            let _ = reply.send(result);
+        }
+        let results: Vec<_> = futures_util::future::join_all(tasks).await.into_iter().map(Result::unwrap).collect();
+        assert_eq!(results[0].as_ref().unwrap(), &[0]);
+        assert!(matches!(results[1], Err(OpenIMError::Api { code: 1001, .. })));
+        assert_eq!(results[2].as_ref().unwrap(), &[2]);
+
+        // 积压的信令和断开命令在重连后丢弃
+        handle.signal(MsgData::default());
+        handle.send(Command::Disconnect(oneshot::channel().0)).await.unwrap();
+        handle.set_background(true).await.unwrap();
+        let stale: Vec<_> = std::iter::from_fn(|| rx.try_recv().ok()).map(|c| c.is_stale()).collect();
+        assert_eq!(stale, [true, true, false]);
+
+        // 未连接时断开直接返回
+        handle.disconnect().await.unwrap();
+        drop(rx);
+        assert!(matches!(handle.set_background(false).await, Err(OpenIMError::RequestFailed(_))));
+    }
+}
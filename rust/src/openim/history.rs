@@ -0,0 +1,85 @@
+//! 消息历史分页
+//!
+//! 以 seq 为游标向前翻页：每页覆盖 before_seq 之前连续的 limit 个 seq。先读本地数据库，
+//! 本地缺失的 seq 区间再按 seq 从服务器拉取，补齐后重新读取，保证每页按 seq 升序且不重不漏
+//! （服务器上已删除的 seq 除外）。
+
+use super::messages::StoredMessage;
+
+/// 每页最多的消息数
+pub const MAX_HISTORY_PAGE: u32 = 100;
+
+/// 一页历史消息
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryPage {
+    /// 按 seq 升序
+    pub messages: Vec<StoredMessage>,
+    /// 是否还有更早的消息
+    pub has_more: bool,
+}
+
+/// seq 区间 [begin, end]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeqSpan {
+    pub begin: i64,
+    pub end: i64,
+}
+
+impl SeqSpan {
+    pub fn len(&self) -> usize {
+        (self.end - self.begin + 1).max(0) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 以 end 结尾的一页（最多 limit 个 seq，不早于 min_seq），没有更早的消息时为 None
+pub fn page_span(end: i64, limit: i64, min_seq: i64) -> Option<SeqSpan> {
+    let min_seq = min_seq.max(1);
+    (end >= min_seq).then(|| SeqSpan { begin: (end - limit.max(1) + 1).max(min_seq), end })
+}
+
+/// span 中本地没有的 seq 区间（present 为本地已有的 seq，升序）
+pub fn missing_spans(span: SeqSpan, present: &[i64]) -> Vec<SeqSpan> {
+    let mut missing = Vec::new();
+    let mut next = span.begin;
+    for &seq in present.iter().filter(|&&seq| seq >= span.begin && seq <= span.end) {
+        if seq > next {
+            missing.push(SeqSpan { begin: next, end: seq - 1 });
+        }
+        next = next.max(seq + 1);
+    }
+    if next <= span.end {
+        missing.push(SeqSpan { begin: next, end: span.end });
+    }
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_span_stops_at_min_seq() {
+        assert_eq!(page_span(10, 3, 1), Some(SeqSpan { begin: 8, end: 10 }));
+        assert_eq!(page_span(2, 3, 1), Some(SeqSpan { begin: 1, end: 2 }));
+        assert_eq!(page_span(10, 20, 6), Some(SeqSpan { begin: 6, end: 10 }));
+        assert_eq!(page_span(5, 3, 6), None);
+        assert_eq!(page_span(0, 3, 0), None);
+    }
+
+    #[test]
+    fn missing_spans_between_local_seqs() {
+        let span = SeqSpan { begin: 1, end: 10 };
+        assert_eq!(missing_spans(span, &[]), [span]);
+        assert_eq!(missing_spans(span, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]), []);
+        assert_eq!(
+            missing_spans(span, &[3, 4, 4, 7, 12]),
+            [SeqSpan { begin: 1, end: 2 }, SeqSpan { begin: 5, end: 6 }, SeqSpan { begin: 8, end: 10 }]
+        );
+        assert_eq!(missing_spans(span, &[1, 10]), [SeqSpan { begin: 2, end: 9 }]);
+        assert_eq!(missing_spans(span, &[1, 10]).iter().map(SeqSpan::len).sum::<usize>(), 8);
+    }
+}
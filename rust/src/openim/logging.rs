@@ -0,0 +1,210 @@
+//! 日志（tracing）
+//!
+//! 全局 subscriber 同时输出到两处：广播给 Dart 的日志流，以及可选的按天滚动日志文件（用于问题反馈）。
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use tokio::sync::broadcast;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{Builder, Rotation};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use super::OpenIMError;
+
+/// 日志文件名前缀（`openim.2024-01-01.log`）
+pub const LOG_FILE_PREFIX: &str = "openim";
+const LOG_FILE_SUFFIX: &str = "log";
+
+/// 一条日志
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub time_ms: i64,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    /// 事件字段（不含 message）
+    pub fields: Vec<(String, String)>,
+    /// 所在的 span，由外到内，如 `openim{user_id=u1}:session{operation_id=1}`
+    pub spans: String,
+}
+
+/// 收集事件字段
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push((field.name().to_string(), value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push((field.name().to_string(), format!("{:?}", value)));
+        }
+    }
+}
+
+/// span 字段，创建 span 时格式化后保存在扩展中
+struct SpanFields(String);
+
+/// 把日志广播出去的 layer
+pub struct BroadcastLayer {
+    tx: broadcast::Sender<LogRecord>,
+}
+
+impl BroadcastLayer {
+    pub fn new(tx: broadcast::Sender<LogRecord>) -> Self {
+        Self { tx }
+    }
+}
+
+impl<S> Layer<S> for BroadcastLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, id: &tracing::span::Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        let fields = visitor
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        // 没有订阅者（Dart 未监听）时跳过格式化
+        if self.tx.receiver_count() == 0 {
+            return;
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let mut spans = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if !spans.is_empty() {
+                    spans.push(':');
+                }
+                spans.push_str(span.name());
+                if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>() {
+                    if !fields.is_empty() {
+                        let _ = write!(spans, "{{{}}}", fields);
+                    }
+                }
+            }
+        }
+        let _ = self.tx.send(LogRecord {
+            time_ms: chrono::Utc::now().timestamp_millis(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+            spans,
+        });
+    }
+}
+
+/// 日志广播通道（未初始化日志时没有任何输出）
+fn events() -> &'static broadcast::Sender<LogRecord> {
+    static EVENTS: OnceLock<broadcast::Sender<LogRecord>> = OnceLock::new();
+    EVENTS.get_or_init(|| broadcast::channel(1024).0)
+}
+
+/// 订阅日志
+pub fn subscribe() -> broadcast::Receiver<LogRecord> {
+    events().subscribe()
+}
+
+/// 安装全局 subscriber（只能调用一次）
+///
+/// 本 crate 的日志按 `level` 过滤，依赖库只输出 warn 及以上。指定 `log_dir` 时按天滚动写入文件，最多保留 `max_files` 个。
+pub fn init(level: Level, log_dir: Option<&Path>, max_files: usize) -> Result<(), OpenIMError> {
+    static GUARD: OnceLock<Option<WorkerGuard>> = OnceLock::new();
+
+    let filter = Targets::new()
+        .with_target(env!("CARGO_CRATE_NAME"), level)
+        .with_default(LevelFilter::WARN);
+    let (file_layer, guard) = match log_dir {
+        Some(dir) => {
+            let appender = Builder::new()
+                .rotation(Rotation::DAILY)
+                .filename_prefix(LOG_FILE_PREFIX)
+                .filename_suffix(LOG_FILE_SUFFIX)
+                .max_log_files(max_files.max(1))
+                .build(dir)
+                .map_err(|e| OpenIMError::Logging(e.to_string()))?;
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer().with_ansi(false).with_writer(writer);
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+    let subscriber = tracing_subscriber::registry()
+        .with(BroadcastLayer::new(events().clone()))
+        .with(file_layer)
+        .with(filter);
+    tracing::subscriber::set_global_default(subscriber).map_err(|e| OpenIMError::Logging(e.to_string()))?;
+    // 进程结束前一直保留，保证文件日志写完
+    let _ = GUARD.set(guard);
+    Ok(())
+}
+
+/// 日志目录中的日志文件，按时间从旧到新
+pub fn log_files(log_dir: &Path) -> Result<Vec<std::path::PathBuf>, OpenIMError> {
+    let mut files: Vec<_> = std::fs::read_dir(log_dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(LOG_FILE_PREFIX) && name.ends_with(LOG_FILE_SUFFIX))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn broadcast_layer_records_fields_and_spans() {
+        let (tx, mut rx) = broadcast::channel(16);
+        let subscriber = tracing_subscriber::registry().with(BroadcastLayer::new(tx));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("openim", user_id = "u1");
+            let _enter = span.enter();
+            let inner = tracing::info_span!("session", operation_id = 1);
+            let _enter = inner.enter();
+            tracing::warn!(code = 1501, msg = %"expired", "握手被拒绝");
+        });
+        let record = rx.try_recv().unwrap();
+        assert_eq!(record.level, Level::WARN);
+        assert_eq!(record.message, "握手被拒绝");
+        assert_eq!(
+            record.fields,
+            [("code".to_string(), "1501".to_string()), ("msg".to_string(), "expired".to_string())]
+        );
+        assert_eq!(record.spans, "openim{user_id=u1}:session{operation_id=1}");
+    }
+}
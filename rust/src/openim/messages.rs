@@ -0,0 +1,251 @@
+//! 本地消息存储
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use openim_protocol::sdkws::MsgData;
+use rusqlite::{params, OptionalExtension, Row};
+
+use super::content::searchable_text;
+use super::search::{self, SearchPage, SearchQuery};
+use super::store::Store;
+use super::OpenIMError;
+
+pub(crate) const MESSAGE_COLUMNS: &str = "client_msg_id, server_msg_id, conversation_id, seq, send_id, \
+     recv_id, group_id, session_type, content_type, content, send_time";
+
+/// 本地保存的消息
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredMessage {
+    pub client_msg_id: String,
+    pub server_msg_id: String,
+    pub conversation_id: String,
+    pub seq: i64,
+    pub send_id: String,
+    pub recv_id: String,
+    pub group_id: String,
+    pub session_type: i32,
+    pub content_type: i32,
+    pub content: Vec<u8>,
+    pub send_time: i64,
+}
+
+impl StoredMessage {
+    pub fn from_msg_data(conversation_id: &str, msg: &MsgData) -> Self {
+        Self {
+            client_msg_id: msg.client_msg_id.clone(),
+            server_msg_id: msg.server_msg_id.clone(),
+            conversation_id: conversation_id.to_string(),
+            seq: msg.seq,
+            send_id: msg.send_id.clone(),
+            recv_id: msg.recv_id.clone(),
+            group_id: msg.group_id.clone(),
+            session_type: msg.session_type,
+            content_type: msg.content_type,
+            content: msg.content.clone(),
+            send_time: msg.send_time,
+        }
+    }
+
+    pub(crate) fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            client_msg_id: row.get(0)?,
+            server_msg_id: row.get(1)?,
+            conversation_id: row.get(2)?,
+            seq: row.get(3)?,
+            send_id: row.get(4)?,
+            recv_id: row.get(5)?,
+            group_id: row.get(6)?,
+            session_type: row.get(7)?,
+            content_type: row.get(8)?,
+            content: row.get(9)?,
+            send_time: row.get(10)?,
+        })
+    }
+}
+
+/// 消息存储
+pub struct MessageStore {
+    store: Arc<Store>,
+}
+
+impl MessageStore {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+
+    /// 保存消息，按 clientMsgID 去重
+    ///
+    /// 已存在时只补充服务器信息（seq / serverMsgID / sendTime），例如自己发送的消息先在确认时保存、
+    /// 之后再由推送补上 seq。返回是否为新消息。
+    pub fn save(&self, msg: &StoredMessage) -> Result<bool, OpenIMError> {
+        let mut conn = self.store.conn()?;
+        let tx = conn.transaction()?;
+        let exists = tx
+            .query_row(
+                "SELECT 1 FROM messages WHERE client_msg_id = ?1",
+                params![msg.client_msg_id],
+                |_| Ok(()),
+            )
+            .optional()?
+            .is_some();
+        if exists {
+            tx.execute(
+                "UPDATE messages SET \
+                 seq = CASE WHEN ?2 > 0 THEN ?2 ELSE seq END, \
+                 server_msg_id = CASE WHEN ?3 != '' THEN ?3 ELSE server_msg_id END, \
+                 send_time = CASE WHEN ?4 > 0 THEN ?4 ELSE send_time END \
+                 WHERE client_msg_id = ?1",
+                params![msg.client_msg_id, msg.seq, msg.server_msg_id, msg.send_time],
+            )?;
+        } else {
+            tx.execute(
+                &format!(
+                    "INSERT INTO messages ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                    MESSAGE_COLUMNS
+                ),
+                params![
+                    msg.client_msg_id,
+                    msg.server_msg_id,
+                    msg.conversation_id,
+                    msg.seq,
+                    msg.send_id,
+                    msg.recv_id,
+                    msg.group_id,
+                    msg.session_type,
+                    msg.content_type,
+                    msg.content,
+                    msg.send_time,
+                ],
+            )?;
+            if let Some(text) = searchable_text(msg.content_type, &msg.content) {
+                let (uni, bi) = search::index_columns(&text);
+                tx.execute(
+                    "INSERT INTO messages_fts (rowid, uni, bi) VALUES (?1, ?2, ?3)",
+                    params![tx.last_insert_rowid(), uni, bi],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(!exists)
+    }
+
+    /// 按 clientMsgID 查询消息
+    pub fn get(&self, client_msg_id: &str) -> Result<Option<StoredMessage>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let msg = conn
+            .query_row(
+                &format!("SELECT {} FROM messages WHERE client_msg_id = ?1", MESSAGE_COLUMNS),
+                params![client_msg_id],
+                StoredMessage::from_row,
+            )
+            .optional()?;
+        Ok(msg)
+    }
+
+    /// 删除会话的全部本地消息（包括全文索引和表情回应）
+    pub fn clear_conversation(&self, conversation_id: &str) -> Result<(), OpenIMError> {
+        let mut conn = self.store.conn()?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "DELETE FROM message_reactions WHERE client_msg_id IN \
+             (SELECT client_msg_id FROM messages WHERE conversation_id = ?1)",
+            params![conversation_id],
+        )?;
+        tx.execute(
+            "DELETE FROM messages_fts WHERE rowid IN (SELECT id FROM messages WHERE conversation_id = ?1)",
+            params![conversation_id],
+        )?;
+        tx.execute("DELETE FROM messages WHERE conversation_id = ?1", params![conversation_id])?;
+        tx.execute("DELETE FROM skipped_seqs WHERE conversation_id = ?1", params![conversation_id])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 会话中最新的一条消息
+    pub fn latest(&self, conversation_id: &str) -> Result<Option<StoredMessage>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let msg = conn
+            .query_row(
+                &format!(
+                    "SELECT {} FROM messages WHERE conversation_id = ?1 ORDER BY send_time DESC LIMIT 1",
+                    MESSAGE_COLUMNS
+                ),
+                params![conversation_id],
+                StoredMessage::from_row,
+            )
+            .optional()?;
+        Ok(msg)
+    }
+
+    /// 记录不作为消息保存的 seq（表情回应变化）
+    pub fn save_skipped_seq(&self, conversation_id: &str, seq: i64) -> Result<(), OpenIMError> {
+        if seq > 0 {
+            self.store.conn()?.execute(
+                "INSERT OR IGNORE INTO skipped_seqs (conversation_id, seq) VALUES (?1, ?2)",
+                params![conversation_id, seq],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// 每个会话本地的最大 seq（包括已有 seq 的消息和跳过的 seq）
+    pub fn max_seqs(&self) -> Result<HashMap<String, i64>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT conversation_id, MAX(seq) FROM \
+             (SELECT conversation_id, seq FROM messages WHERE seq > 0 \
+              UNION ALL SELECT conversation_id, seq FROM skipped_seqs) \
+             GROUP BY conversation_id",
+        )?;
+        let seqs = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(seqs)
+    }
+
+    /// 会话本地的最大 seq（包括跳过的 seq），没有已分配 seq 的消息时为 None
+    pub fn max_seq(&self, conversation_id: &str) -> Result<Option<i64>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let seq = conn.query_row(
+            "SELECT MAX(seq) FROM \
+             (SELECT seq FROM messages WHERE conversation_id = ?1 AND seq > 0 \
+              UNION ALL SELECT seq FROM skipped_seqs WHERE conversation_id = ?1)",
+            params![conversation_id],
+            |row| row.get(0),
+        )?;
+        Ok(seq)
+    }
+
+    /// 会话中 [begin, end] 内本地已有的 seq（包括跳过的 seq，升序）
+    pub fn present_seqs(&self, conversation_id: &str, begin: i64, end: i64) -> Result<Vec<i64>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT seq FROM messages WHERE conversation_id = ?1 AND seq BETWEEN ?2 AND ?3 \
+             UNION SELECT seq FROM skipped_seqs WHERE conversation_id = ?1 AND seq BETWEEN ?2 AND ?3 \
+             ORDER BY seq",
+        )?;
+        let seqs = stmt
+            .query_map(params![conversation_id, begin, end], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(seqs)
+    }
+
+    /// 会话中 seq 在 [begin, end] 内的消息（按 seq 升序）
+    pub fn by_seq_range(&self, conversation_id: &str, begin: i64, end: i64) -> Result<Vec<StoredMessage>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM messages WHERE conversation_id = ?1 AND seq BETWEEN ?2 AND ?3 ORDER BY seq",
+            MESSAGE_COLUMNS
+        ))?;
+        let msgs = stmt
+            .query_map(params![conversation_id, begin, end], StoredMessage::from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(msgs)
+    }
+
+    /// 全文搜索
+    pub fn search(&self, query: &SearchQuery) -> Result<SearchPage, OpenIMError> {
+        search::search(&self.store, query)
+    }
+}
@@ -0,0 +1,384 @@
+//! 本地模拟的 msggateway（仅测试使用）
+//!
+//! 完成握手（`errCode/errMsg/errDlt`）后按服务器格式返回 gzip 压缩的响应：
+//! 可以推送指定的消息和通知，应答发送、最大 seq 和按 seq 拉取请求，并模拟踢下线和断开连接。
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{Read, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use base64::Engine;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use futures_util::{SinkExt, StreamExt};
+use openim_protocol::sdkws::{self, MsgData};
+use openim_protocol::Message as ProtobufMessage;
+use serde::{Deserialize, Serialize};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, Notify};
+use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+use super::conversation::{group_conversation_id, single_conversation_id};
+use super::token::token_err;
+use crate::api::openim_client::msg_type;
+
+/// 等待请求或连接的超时时间
+const WAIT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 客户端发来的请求
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockRequest {
+    #[serde(rename = "reqIdentifier")]
+    pub req_identifier: i32,
+    #[serde(default)]
+    pub token: String,
+    #[serde(rename = "sendID", default)]
+    pub send_id: String,
+    #[serde(rename = "msgIncr", default)]
+    pub msg_incr: String,
+    #[serde(default)]
+    pub data: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct MockResp<'a> {
+    #[serde(rename = "reqIdentifier")]
+    req_identifier: i32,
+    #[serde(rename = "msgIncr")]
+    msg_incr: &'a str,
+    #[serde(rename = "operationID")]
+    operation_id: &'a str,
+    #[serde(rename = "errCode")]
+    err_code: i32,
+    #[serde(rename = "errMsg")]
+    err_msg: &'a str,
+    data: String,
+}
+
+/// 服务器下发的帧：gzip 压缩的 JSON，data 为 base64
+fn encode_resp(req_identifier: i32, msg_incr: &str, err_code: i32, err_msg: &str, data: &[u8]) -> Vec<u8> {
+    let resp = MockResp {
+        req_identifier,
+        msg_incr,
+        operation_id: "mock",
+        err_code,
+        err_msg,
+        data: base64::engine::general_purpose::STANDARD.encode(data),
+    };
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&serde_json::to_vec(&resp).unwrap()).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn decode_req(data: &[u8]) -> Option<MockRequest> {
+    if data.starts_with(&[0x1f, 0x8b]) {
+        let mut json = Vec::new();
+        GzDecoder::new(data).read_to_end(&mut json).ok()?;
+        serde_json::from_slice(&json).ok()
+    } else {
+        serde_json::from_slice(data).ok()
+    }
+}
+
+enum Outgoing {
+    Frame(Vec<u8>),
+    Close,
+}
+
+#[derive(Default)]
+struct State {
+    /// 握手时返回的错误（errCode, errMsg）
+    handshake_error: Option<(i32, String)>,
+    /// 握手时按 token 失效拒绝的 token
+    revoked_tokens: HashSet<String>,
+    /// 每次握手的查询参数
+    handshakes: Vec<HashMap<String, String>>,
+    /// 请求类型 -> 返回的错误（errCode, errMsg）
+    request_errors: HashMap<i32, (i32, String)>,
+    /// 会话 ID -> 按 seq 排列的消息
+    conversations: BTreeMap<String, Vec<MsgData>>,
+    connections: Vec<mpsc::UnboundedSender<Outgoing>>,
+    requests: Vec<MockRequest>,
+    next_server_msg_id: u64,
+}
+
+impl State {
+    /// 分配 seq 并保存
+    fn append(&mut self, conversation_id: &str, mut msg: MsgData) -> MsgData {
+        let msgs = self.conversations.entry(conversation_id.to_string()).or_default();
+        msg.seq = msgs.len() as i64 + 1;
+        msgs.push(msg.clone());
+        msg
+    }
+
+    fn broadcast(&mut self, outgoing: impl Fn() -> Outgoing) {
+        self.connections.retain(|tx| tx.send(outgoing()).is_ok());
+    }
+}
+
+/// 模拟网关
+#[derive(Clone)]
+pub struct MockGateway {
+    ws_url: String,
+    state: Arc<Mutex<State>>,
+    changed: Arc<Notify>,
+}
+
+impl MockGateway {
+    /// 在随机端口上启动
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gateway = Self {
+            ws_url: format!("ws://{}", listener.local_addr().unwrap()),
+            state: Arc::new(Mutex::new(State::default())),
+            changed: Arc::new(Notify::new()),
+        };
+        let server = gateway.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(server.clone().serve(stream));
+            }
+        });
+        gateway
+    }
+
+    /// 客户端的 ws_url
+    pub fn ws_url(&self) -> String {
+        self.ws_url.clone()
+    }
+
+    /// 之后的握手返回错误（如 token 失效），None 表示恢复正常
+    pub fn set_handshake_error(&self, error: Option<(i32, &str)>) {
+        self.state.lock().unwrap().handshake_error = error.map(|(code, msg)| (code, msg.to_string()));
+    }
+
+    /// 之后使用该 token 的握手返回 token 失效
+    pub fn revoke_token(&self, token: &str) {
+        self.state.lock().unwrap().revoked_tokens.insert(token.to_string());
+    }
+
+    /// 之后该类型的请求返回错误，None 表示恢复正常
+    pub fn set_request_error(&self, req_identifier: i32, error: Option<(i32, &str)>) {
+        let mut state = self.state.lock().unwrap();
+        match error {
+            Some((code, msg)) => state.request_errors.insert(req_identifier, (code, msg.to_string())),
+            None => state.request_errors.remove(&req_identifier),
+        };
+    }
+
+    /// 每次握手的查询参数（token、sendID、platformID 等）
+    pub fn handshakes(&self) -> Vec<HashMap<String, String>> {
+        self.state.lock().unwrap().handshakes.clone()
+    }
+
+    /// 收到的全部请求
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// 保存消息但不推送（供之后拉取），返回分配的 seq
+    pub fn add_message(&self, conversation_id: &str, msg: MsgData) -> i64 {
+        self.state.lock().unwrap().append(conversation_id, msg).seq
+    }
+
+    /// 保存并推送普通消息，返回分配的 seq
+    pub fn push_message(&self, conversation_id: &str, msg: MsgData) -> i64 {
+        self.push(conversation_id, msg, false)
+    }
+
+    /// 保存并推送通知消息，返回分配的 seq
+    pub fn push_notification(&self, conversation_id: &str, msg: MsgData) -> i64 {
+        self.push(conversation_id, msg, true)
+    }
+
+    fn push(&self, conversation_id: &str, msg: MsgData, notification: bool) -> i64 {
+        let mut state = self.state.lock().unwrap();
+        let msg = state.append(conversation_id, msg);
+        let pull = sdkws::PullMsgs { msgs: vec![msg.clone()], is_end: true };
+        let mut push = sdkws::PushMessages::default();
+        if notification {
+            push.notification_msgs.insert(conversation_id.to_string(), pull);
+        } else {
+            push.msgs.insert(conversation_id.to_string(), pull);
+        }
+        let frame = encode_resp(msg_type::WS_PUSH_MSG, "", 0, "", &push.encode_to_vec());
+        state.broadcast(|| Outgoing::Frame(frame.clone()));
+        msg.seq
+    }
+
+    /// 踢下线：发送踢下线消息后关闭连接
+    pub fn kick(&self) {
+        let frame = encode_resp(msg_type::WS_KICK_ONLINE_MSG, "", 0, "", &[]);
+        let mut state = self.state.lock().unwrap();
+        state.broadcast(|| Outgoing::Frame(frame.clone()));
+        state.broadcast(|| Outgoing::Close);
+    }
+
+    /// 关闭全部连接
+    pub fn disconnect(&self) {
+        self.state.lock().unwrap().broadcast(|| Outgoing::Close);
+    }
+
+    /// 等待第 n 次握手成功
+    pub async fn wait_handshakes(&self, n: usize) {
+        self.wait(|state| state.handshakes.len() >= n).await
+    }
+
+    /// 等待指定类型的请求，返回第 index 个（从 0 开始）
+    pub async fn wait_request(&self, req_identifier: i32, index: usize) -> MockRequest {
+        let mut found = None;
+        self.wait(|state| {
+            found = state.requests.iter().filter(|r| r.req_identifier == req_identifier).nth(index).cloned();
+            found.is_some()
+        })
+        .await;
+        found.unwrap()
+    }
+
+    async fn wait(&self, mut done: impl FnMut(&State) -> bool) {
+        let wait = async {
+            loop {
+                let changed = self.changed.notified();
+                if done(&self.state.lock().unwrap()) {
+                    return;
+                }
+                changed.await;
+            }
+        };
+        tokio::time::timeout(WAIT_TIMEOUT, wait).await.expect("等待模拟网关超时");
+    }
+
+    async fn serve(self, stream: TcpStream) {
+        let mut query = HashMap::new();
+        #[allow(clippy::result_large_err)]
+        let callback = |req: &Request, resp: Response| {
+            for pair in req.uri().query().unwrap_or_default().split('&') {
+                if let Some((key, value)) = pair.split_once('=') {
+                    query.insert(key.to_string(), value.to_string());
+                }
+            }
+            Ok(resp)
+        };
+        let Ok(ws) = tokio_tungstenite::accept_hdr_async(stream, callback).await else {
+            return;
+        };
+        let (mut write, mut read) = ws.split();
+
+        let handshake_error = {
+            let state = self.state.lock().unwrap();
+            let revoked = query.get("token").is_some_and(|token| state.revoked_tokens.contains(token));
+            state.handshake_error.clone().or_else(|| revoked.then(|| (token_err::EXPIRED, "token expired".to_string())))
+        };
+        let (code, msg) = handshake_error.clone().unwrap_or((0, String::new()));
+        let handshake = serde_json::json!({ "errCode": code, "errMsg": msg, "errDlt": "" });
+        if write.send(WsMessage::Text(handshake.to_string())).await.is_err() || handshake_error.is_some() {
+            let _ = write.send(WsMessage::Close(None)).await;
+            return;
+        }
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        {
+            let mut state = self.state.lock().unwrap();
+            state.handshakes.push(query);
+            state.connections.push(tx.clone());
+        }
+        self.changed.notify_waiters();
+
+        loop {
+            tokio::select! {
+                outgoing = rx.recv() => match outgoing {
+                    Some(Outgoing::Frame(frame)) => {
+                        if write.send(WsMessage::Binary(frame)).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Outgoing::Close) | None => {
+                        let _ = write.send(WsMessage::Close(None)).await;
+                        break;
+                    }
+                },
+                incoming = read.next() => match incoming {
+                    Some(Ok(WsMessage::Binary(data))) => {
+                        if let Some(req) = decode_req(&data) {
+                            let frame = self.handle(req);
+                            if write.send(WsMessage::Binary(frame)).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Some(Ok(WsMessage::Close(_))) | Some(Err(_)) | None => break,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        self.state.lock().unwrap().connections.retain(|c| !c.same_channel(&tx));
+    }
+
+    /// 记录请求并生成响应
+    fn handle(&self, req: MockRequest) -> Vec<u8> {
+        let mut state = self.state.lock().unwrap();
+        state.requests.push(req.clone());
+        self.changed.notify_waiters();
+        if let Some((code, msg)) = state.request_errors.get(&req.req_identifier) {
+            return encode_resp(req.req_identifier, &req.msg_incr, *code, msg, &[]);
+        }
+        let ok = |data: &[u8]| encode_resp(req.req_identifier, &req.msg_incr, 0, "", data);
+        match req.req_identifier {
+            msg_type::WS_SEND_MSG => {
+                let Ok(mut msg) = MsgData::decode(req.data.as_slice()) else {
+                    return encode_resp(req.req_identifier, &req.msg_incr, 1001, "ArgsError", &[]);
+                };
+                state.next_server_msg_id += 1;
+                msg.server_msg_id = format!("srv_{}", state.next_server_msg_id);
+                msg.send_time = chrono::Utc::now().timestamp_millis();
+                let conversation_id = if msg.group_id.is_empty() {
+                    single_conversation_id(&msg.send_id, &msg.recv_id)
+                } else {
+                    group_conversation_id(&msg.group_id)
+                };
+                let msg = state.append(&conversation_id, msg);
+                let resp = openim_protocol::msg::SendMsgResp {
+                    server_msg_id: msg.server_msg_id,
+                    client_msg_id: msg.client_msg_id,
+                    send_time: msg.send_time,
+                };
+                ok(&resp.encode_to_vec())
+            }
+            msg_type::WS_GET_NEWEST_SEQ => {
+                let resp = sdkws::GetMaxSeqResp {
+                    max_seqs: state.conversations.iter().map(|(id, msgs)| (id.clone(), msgs.len() as i64)).collect(),
+                    min_seqs: state.conversations.keys().map(|id| (id.clone(), 1)).collect(),
+                };
+                ok(&resp.encode_to_vec())
+            }
+            msg_type::WS_PULL_MSG_BY_SEQ_LIST => {
+                let Ok(pull) = sdkws::PullMessageBySeqsReq::decode(req.data.as_slice()) else {
+                    return encode_resp(req.req_identifier, &req.msg_incr, 1001, "ArgsError", &[]);
+                };
+                let mut resp = sdkws::PullMessageBySeqsResp::default();
+                for range in pull.seq_ranges {
+                    let all = state.conversations.get(&range.conversation_id).map(Vec::as_slice).unwrap_or_default();
+                    let mut msgs: Vec<MsgData> = all
+                        .iter()
+                        .filter(|m| m.seq >= range.begin && m.seq <= range.end)
+                        .cloned()
+                        .collect();
+                    let limit = if range.num > 0 { range.num as usize } else { msgs.len() };
+                    let is_end = msgs.len() <= limit;
+                    msgs.truncate(limit);
+                    let pulled = sdkws::PullMsgs { msgs, is_end };
+                    if range.conversation_id.starts_with("n_") {
+                        resp.notification_msgs.insert(range.conversation_id, pulled);
+                    } else {
+                        resp.msgs.insert(range.conversation_id, pulled);
+                    }
+                }
+                ok(&resp.encode_to_vec())
+            }
+            msg_type::WS_SUB_USER_ONLINE_STATUS => ok(&sdkws::SubUserOnlineStatusTips::default().encode_to_vec()),
+            _ => ok(&[]),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! OpenIM 客户端内部实现（不直接暴露给 Dart，由 `crate::api` 封装）
+
+pub mod auth;
+pub mod capture;
+pub mod connection;
+pub mod contacts;
+pub mod content;
+pub mod conversation;
+pub mod conversations;
+pub mod error;
+pub mod groups;
+pub mod handle;
+pub mod history;
+pub mod logging;
+pub mod messages;
+#[cfg(test)]
+pub mod mock_gateway;
+pub mod msg_id;
+pub mod ordering;
+pub mod outbox;
+pub mod picture;
+pub mod presence;
+pub mod reactions;
+pub mod rest;
+pub mod search;
+pub mod sessions;
+pub mod store;
+pub mod sync;
+pub mod token;
+pub mod transport;
+pub mod typing;
+pub mod users;
+
+pub use error::OpenIMError;
@@ -0,0 +1,264 @@
+//! 按 seq 顺序投递消息
+//!
+//! 推送和拉取的消息可能交错、乱序到达。每个会话记录已投递的最大 seq：紧接着的消息立即投递，
+//! 不连续的先缓存 [`REORDER_WINDOW`]，期间缺失的消息到达后一起按顺序投递；超时后拉取缺失的区间，
+//! 拉取后仍然缺失的 seq（服务器已删除）直接跳过；连接断开时缓存的消息直接投递。入库和通知订阅者都在投递时进行，
+//! 保证 seq 严格递增。
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::sync::Notify;
+use tokio::time::Instant;
+
+use super::messages::StoredMessage;
+use super::sync::SeqGap;
+
+/// 乱序消息等待缺失消息的时间，超时后拉取
+pub const REORDER_WINDOW: Duration = Duration::from_millis(500);
+
+/// 等待缺失消息的会话
+struct Pending {
+    msgs: BTreeMap<i64, StoredMessage>,
+    deadline: Instant,
+}
+
+#[derive(Default)]
+struct OrderingState {
+    /// 每个会话已投递的最大 seq
+    delivered: HashMap<String, i64>,
+    pending: HashMap<String, Pending>,
+}
+
+impl OrderingState {
+    /// 依次取出 delivered 之后连续的消息
+    fn drain(&mut self, conversation_id: &str) -> Vec<StoredMessage> {
+        let mut ready = Vec::new();
+        let Some(pending) = self.pending.get_mut(conversation_id) else {
+            return ready;
+        };
+        let delivered = self.delivered.entry(conversation_id.to_string()).or_default();
+        while let Some(entry) = pending.msgs.first_entry() {
+            if *entry.key() > *delivered + 1 {
+                break;
+            }
+            *delivered = (*delivered).max(*entry.key());
+            ready.push(entry.remove());
+        }
+        if pending.msgs.is_empty() {
+            self.pending.remove(conversation_id);
+        }
+        ready
+    }
+
+    /// 跳过 seq 之前（含）缺失的消息，缓存中更早的消息先投递
+    fn skip_to(&mut self, conversation_id: &str, seq: i64) -> Vec<StoredMessage> {
+        let mut ready = Vec::new();
+        if let Some(pending) = self.pending.get_mut(conversation_id) {
+            let later = pending.msgs.split_off(&(seq + 1));
+            ready.extend(std::mem::replace(&mut pending.msgs, later).into_values());
+        }
+        let delivered = self.delivered.entry(conversation_id.to_string()).or_default();
+        *delivered = (*delivered).max(seq);
+        ready.extend(self.drain(conversation_id));
+        ready
+    }
+}
+
+/// 消息顺序
+#[derive(Default)]
+pub struct SeqOrdering {
+    state: Mutex<OrderingState>,
+    notify: Notify,
+}
+
+impl SeqOrdering {
+    /// 等待新的乱序消息（到期时间可能提前）
+    pub async fn notified(&self) {
+        self.notify.notified().await
+    }
+
+    /// 最早的到期时间，没有等待中的会话时为 None
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.state.lock().unwrap().pending.values().map(|p| p.deadline).min()
+    }
+
+    /// 收到一条消息，返回可以按顺序投递的消息（可能为空）
+    ///
+    /// 首次收到会话的消息时以 local_max（本地已有的最大 seq）为起点，本地没有消息时从这条消息开始。
+    /// 已投递过的 seq 视为重复，直接丢弃。
+    pub fn push(&self, msg: StoredMessage, now: Instant, local_max: impl FnOnce() -> Option<i64>) -> Vec<StoredMessage> {
+        let mut state = self.state.lock().unwrap();
+        let conversation_id = msg.conversation_id.clone();
+        let seq = msg.seq;
+        let delivered = *state
+            .delivered
+            .entry(conversation_id.clone())
+            .or_insert_with(|| local_max().unwrap_or(seq - 1));
+        if seq <= delivered {
+            return Vec::new();
+        }
+        let pending = state
+            .pending
+            .entry(conversation_id.clone())
+            .or_insert_with(|| Pending { msgs: BTreeMap::new(), deadline: now + REORDER_WINDOW });
+        pending.msgs.insert(seq, msg);
+        let ready = state.drain(&conversation_id);
+        if state.pending.contains_key(&conversation_id) {
+            self.notify.notify_one();
+        }
+        ready
+    }
+
+    /// 拉取到 [begin, end] 区间的消息，返回可以按顺序投递的消息
+    ///
+    /// begin 之前缺失的消息不再等待（同步时只拉取最新的一部分）；区间内服务器没有返回的 seq 跳过。
+    pub fn accept_range(&self, conversation_id: &str, begin: i64, end: i64, msgs: Vec<StoredMessage>) -> Vec<StoredMessage> {
+        let mut state = self.state.lock().unwrap();
+        let mut ready = state.skip_to(conversation_id, begin - 1);
+        let delivered = state.delivered[conversation_id];
+        let now = Instant::now();
+        for msg in msgs.into_iter().filter(|m| m.seq > delivered && m.seq <= end) {
+            state
+                .pending
+                .entry(conversation_id.to_string())
+                .or_insert_with(|| Pending { msgs: BTreeMap::new(), deadline: now + REORDER_WINDOW })
+                .msgs
+                .insert(msg.seq, msg);
+        }
+        ready.extend(state.skip_to(conversation_id, end));
+        ready
+    }
+
+    /// 取出到期的缺失区间（每个会话最多 limit 条），到期时间顺延以便拉取失败时重试
+    pub fn expired_gaps(&self, now: Instant, limit: i64) -> Vec<SeqGap> {
+        let mut state = self.state.lock().unwrap();
+        let OrderingState { delivered, pending } = &mut *state;
+        let mut gaps: Vec<SeqGap> = pending
+            .iter_mut()
+            .filter(|(_, p)| p.deadline <= now)
+            .filter_map(|(id, p)| {
+                p.deadline = now + REORDER_WINDOW;
+                let first = *p.msgs.keys().next()?;
+                let begin = (delivered.get(id).copied().unwrap_or(0) + 1).max(first - limit);
+                Some(SeqGap { conversation_id: id.clone(), begin, end: first - 1 })
+            })
+            .collect();
+        gaps.sort_by(|a, b| a.conversation_id.cmp(&b.conversation_id));
+        gaps
+    }
+
+    /// 不再等待缺失的消息，按顺序取出全部缓存的消息（回放抓包和连接断开时使用）
+    pub fn flush(&self) -> Vec<StoredMessage> {
+        let mut state = self.state.lock().unwrap();
+        let conversation_ids: Vec<String> = state.pending.keys().cloned().collect();
+        let mut ready = Vec::new();
+        for conversation_id in conversation_ids {
+            let last = state.pending[&conversation_id].msgs.keys().next_back().copied().unwrap_or_default();
+            ready.extend(state.skip_to(&conversation_id, last));
+        }
+        ready
+    }
+
+    /// 连接断开时清空（先用 [`Self::flush`] 取出缓存的消息入库），重连后以本地最大 seq 为起点
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.delivered.clear();
+        state.pending.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(conversation_id: &str, seq: i64) -> StoredMessage {
+        StoredMessage {
+            client_msg_id: format!("{}_{}", conversation_id, seq),
+            server_msg_id: String::new(),
+            conversation_id: conversation_id.to_string(),
+            seq,
+            send_id: "u2".to_string(),
+            recv_id: "u1".to_string(),
+            group_id: String::new(),
+            session_type: 1,
+            content_type: 101,
+            content: Vec::new(),
+            send_time: seq,
+        }
+    }
+
+    fn seqs(msgs: &[StoredMessage]) -> Vec<i64> {
+        msgs.iter().map(|m| m.seq).collect()
+    }
+
+    #[test]
+    fn buffers_until_missing_seq_arrives() {
+        let ordering = SeqOrdering::default();
+        let now = Instant::now();
+        assert_eq!(seqs(&ordering.push(msg("a", 6), now, || Some(5))), [6]);
+        assert_eq!(seqs(&ordering.push(msg("a", 8), now, || unreachable!())), Vec::<i64>::new());
+        assert_eq!(seqs(&ordering.push(msg("a", 9), now, || unreachable!())), Vec::<i64>::new());
+        assert_eq!(ordering.next_deadline(), Some(now + REORDER_WINDOW));
+        // 重复的消息丢弃
+        assert_eq!(seqs(&ordering.push(msg("a", 6), now, || unreachable!())), Vec::<i64>::new());
+        assert_eq!(seqs(&ordering.push(msg("a", 7), now, || unreachable!())), [7, 8, 9]);
+        assert_eq!(ordering.next_deadline(), None);
+
+        // 本地没有消息的会话从第一条开始
+        assert_eq!(seqs(&ordering.push(msg("b", 42), now, || None)), [42]);
+    }
+
+    #[test]
+    fn expired_gaps_are_pulled_and_skipped() {
+        let ordering = SeqOrdering::default();
+        let now = Instant::now();
+        ordering.push(msg("a", 10), now, || Some(3));
+        ordering.push(msg("b", 300), now, || Some(1));
+        assert!(ordering.expired_gaps(now, 100).is_empty());
+
+        let later = now + REORDER_WINDOW;
+        let gaps = ordering.expired_gaps(later, 100);
+        assert_eq!(
+            gaps,
+            [
+                SeqGap { conversation_id: "a".to_string(), begin: 4, end: 9 },
+                SeqGap { conversation_id: "b".to_string(), begin: 200, end: 299 },
+            ]
+        );
+        // 到期时间顺延，拉取失败时稍后重试
+        assert!(ordering.expired_gaps(later, 100).is_empty());
+        assert_eq!(ordering.next_deadline(), Some(later + REORDER_WINDOW));
+
+        // 服务器没有 seq 6，跳过；缓存的 10 接着投递
+        let ready = ordering.accept_range("a", 4, 9, vec![msg("a", 5), msg("a", 4), msg("a", 7), msg("a", 8), msg("a", 9)]);
+        assert_eq!(seqs(&ready), [4, 5, 7, 8, 9, 10]);
+        // 只拉取了最新的 100 条，更早的不再等待
+        let ready = ordering.accept_range("b", 200, 299, vec![msg("b", 299), msg("b", 200)]);
+        assert_eq!(seqs(&ready), [200, 299, 300]);
+        assert_eq!(ordering.next_deadline(), None);
+        assert_eq!(seqs(&ordering.push(msg("b", 250), later, || unreachable!())), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn pulled_range_interleaves_with_pushes() {
+        let ordering = SeqOrdering::default();
+        let now = Instant::now();
+        // 同步拉取期间推送了更新的消息
+        assert!(ordering.push(msg("a", 11), now, || Some(7)).is_empty());
+        let ready = ordering.accept_range("a", 8, 10, vec![msg("a", 8), msg("a", 9), msg("a", 10)]);
+        assert_eq!(seqs(&ready), [8, 9, 10, 11]);
+        // 推送先于拉取到达的消息不会重复投递
+        let ready = ordering.accept_range("a", 9, 12, vec![msg("a", 9), msg("a", 12)]);
+        assert_eq!(seqs(&ready), [12]);
+
+        ordering.push(msg("a", 15), now, || unreachable!());
+        ordering.push(msg("a", 20), now, || unreachable!());
+        assert_eq!(seqs(&ordering.flush()), [15, 20]);
+        ordering.push(msg("a", 22), now, || unreachable!());
+        ordering.reset();
+        assert_eq!(ordering.next_deadline(), None);
+        assert_eq!(seqs(&ordering.push(msg("a", 13), now, || Some(12))), [13]);
+    }
+}
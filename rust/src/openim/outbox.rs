@@ -0,0 +1,466 @@
+//! 离线发件箱：消息先持久化，连接可用时按会话顺序发送，失败按退避重试
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use rusqlite::{params, OptionalExtension, Row};
+use tokio::sync::{broadcast, Notify};
+
+use super::store::Store;
+use super::OpenIMError;
+
+/// 最多尝试次数，超过后标记为发送失败
+pub const MAX_ATTEMPTS: i64 = 5;
+/// 等待服务器确认的超时时间（毫秒）
+pub const ACK_TIMEOUT_MS: i64 = 15_000;
+const RETRY_BASE_MS: i64 = 2_000;
+const RETRY_MAX_MS: i64 = 60_000;
+
+const ENTRY_COLUMNS: &str = "id, conversation_id, recv_id, group_id, session_type, content_type, \
+     content, status, attempts, next_attempt_at, last_error, server_msg_id, send_time, created_at, \
+     client_msg_id";
+
+/// 发送状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutboxStatus {
+    Sending = 0,
+    Sent = 1,
+    Failed = 2,
+}
+
+impl OutboxStatus {
+    fn from_i64(v: i64) -> Self {
+        match v {
+            1 => Self::Sent,
+            2 => Self::Failed,
+            _ => Self::Sending,
+        }
+    }
+}
+
+/// 待发送的消息
+#[derive(Debug, Clone)]
+pub struct OutgoingMessage {
+    /// 入队时生成，重试时复用
+    pub client_msg_id: String,
+    pub conversation_id: String,
+    pub recv_id: String,
+    pub group_id: String,
+    pub session_type: i32,
+    pub content_type: i32,
+    pub content: Vec<u8>,
+}
+
+/// 发件箱记录
+#[derive(Debug, Clone)]
+pub struct OutboxEntry {
+    pub id: i64,
+    pub client_msg_id: String,
+    pub conversation_id: String,
+    pub recv_id: String,
+    pub group_id: String,
+    pub session_type: i32,
+    pub content_type: i32,
+    pub content: Vec<u8>,
+    pub status: OutboxStatus,
+    pub attempts: i64,
+    pub next_attempt_at: i64,
+    pub last_error: String,
+    pub server_msg_id: String,
+    pub send_time: i64,
+    pub created_at: i64,
+}
+
+impl OutboxEntry {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            conversation_id: row.get(1)?,
+            recv_id: row.get(2)?,
+            group_id: row.get(3)?,
+            session_type: row.get(4)?,
+            content_type: row.get(5)?,
+            content: row.get(6)?,
+            status: OutboxStatus::from_i64(row.get(7)?),
+            attempts: row.get(8)?,
+            next_attempt_at: row.get(9)?,
+            last_error: row.get(10)?,
+            server_msg_id: row.get(11)?,
+            send_time: row.get(12)?,
+            created_at: row.get(13)?,
+            client_msg_id: row.get(14)?,
+        })
+    }
+
+    fn event(&self) -> OutboxEvent {
+        OutboxEvent {
+            id: self.id,
+            client_msg_id: self.client_msg_id.clone(),
+            conversation_id: self.conversation_id.clone(),
+            status: self.status,
+            attempts: self.attempts,
+            server_msg_id: self.server_msg_id.clone(),
+            send_time: self.send_time,
+            error: self.last_error.clone(),
+        }
+    }
+}
+
+/// 发送状态变化事件
+#[derive(Debug, Clone)]
+pub struct OutboxEvent {
+    pub id: i64,
+    pub client_msg_id: String,
+    pub conversation_id: String,
+    pub status: OutboxStatus,
+    pub attempts: i64,
+    pub server_msg_id: String,
+    pub send_time: i64,
+    pub error: String,
+}
+
+/// 已发出、等待服务器确认的消息
+struct InFlight {
+    id: i64,
+    sent_at: i64,
+}
+
+/// 发件箱
+pub struct Outbox {
+    store: Arc<Store>,
+    /// msgIncr -> 等待确认的消息
+    in_flight: Mutex<HashMap<String, InFlight>>,
+    events: broadcast::Sender<OutboxEvent>,
+    notify: Notify,
+}
+
+impl Outbox {
+    pub fn new(store: Arc<Store>) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self {
+            store,
+            in_flight: Mutex::new(HashMap::new()),
+            events,
+            notify: Notify::new(),
+        }
+    }
+
+    /// 订阅发送状态变化
+    pub fn subscribe(&self) -> broadcast::Receiver<OutboxEvent> {
+        self.events.subscribe()
+    }
+
+    /// 等待新消息入队或重试请求
+    pub async fn notified(&self) {
+        self.notify.notified().await
+    }
+
+    /// 消息入队（状态为发送中），并唤醒发送任务
+    pub fn enqueue(&self, msg: OutgoingMessage, now: i64) -> Result<OutboxEntry, OpenIMError> {
+        let id = {
+            let conn = self.store.conn()?;
+            conn.execute(
+                "INSERT INTO outbox (conversation_id, recv_id, group_id, session_type, content_type, \
+                 content, status, next_attempt_at, created_at, client_msg_id) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8, ?9)",
+                params![
+                    msg.conversation_id,
+                    msg.recv_id,
+                    msg.group_id,
+                    msg.session_type,
+                    msg.content_type,
+                    msg.content,
+                    OutboxStatus::Sending as i64,
+                    now,
+                    msg.client_msg_id,
+                ],
+            )?;
+            conn.last_insert_rowid()
+        };
+        let entry = self.get(id)?.ok_or(OpenIMError::NotFound(format!("outbox {}", id)))?;
+        self.emit(&entry);
+        self.notify.notify_one();
+        Ok(entry)
+    }
+
+    /// 查询发件箱记录
+    pub fn get(&self, id: i64) -> Result<Option<OutboxEntry>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let entry = conn
+            .query_row(
+                &format!("SELECT {} FROM outbox WHERE id = ?1", ENTRY_COLUMNS),
+                params![id],
+                OutboxEntry::from_row,
+            )
+            .optional()?;
+        Ok(entry)
+    }
+
+    /// 当前可以发送的消息
+    ///
+    /// 每个会话只取最早一条未完成的消息，保证同一会话内按入队顺序发送；
+    /// 已发出等待确认或仍在退避中的消息会阻塞其后的消息。
+    ///
+    /// 已失败的消息不阻塞（服务器拒绝的消息可能永远发不出去），其后的消息照常发送；
+    /// 手动重试后按新消息发送，在对方看来排在失败期间发出的消息之后。
+    pub fn due(&self, now: i64) -> Result<Vec<OutboxEntry>, OpenIMError> {
+        let entries = {
+            let conn = self.store.conn()?;
+            let mut stmt = conn.prepare(&format!(
+                "SELECT {} FROM outbox o WHERE status = ?1 AND next_attempt_at <= ?2 \
+                 AND id = (SELECT MIN(id) FROM outbox WHERE status = ?1 AND conversation_id = o.conversation_id) \
+                 ORDER BY id",
+                ENTRY_COLUMNS
+            ))?;
+            let rows = stmt.query_map(params![OutboxStatus::Sending as i64, now], OutboxEntry::from_row)?;
+            rows.collect::<Result<Vec<_>, _>>()?
+        };
+        let in_flight = self.in_flight.lock().unwrap();
+        Ok(entries
+            .into_iter()
+            .filter(|e| !in_flight.values().any(|f| f.id == e.id))
+            .collect())
+    }
+
+    /// 记录已发出的消息，等待服务器确认
+    pub fn mark_in_flight(&self, id: i64, msg_incr: String, now: i64) {
+        self.in_flight
+            .lock()
+            .unwrap()
+            .insert(msg_incr, InFlight { id, sent_at: now });
+    }
+
+    /// 处理服务器对发送请求的响应
+    ///
+    /// 服务器明确拒绝（errCode 非 0）的消息不再重试，直接标记为失败。
+    /// 返回更新后的记录；`None` 表示该 msgIncr 不属于发件箱。
+    pub fn ack(
+        &self,
+        msg_incr: &str,
+        result: Result<(String, i64), String>,
+    ) -> Result<Option<OutboxEntry>, OpenIMError> {
+        let Some(in_flight) = self.in_flight.lock().unwrap().remove(msg_incr) else {
+            return Ok(None);
+        };
+        match result {
+            Ok((server_msg_id, send_time)) => {
+                self.store.conn()?.execute(
+                    "UPDATE outbox SET status = ?2, server_msg_id = ?3, send_time = ?4, last_error = '' \
+                     WHERE id = ?1",
+                    params![in_flight.id, OutboxStatus::Sent as i64, server_msg_id, send_time],
+                )?;
+            }
+            Err(error) => {
+                self.store.conn()?.execute(
+                    "UPDATE outbox SET status = ?2, attempts = attempts + 1, last_error = ?3 WHERE id = ?1",
+                    params![in_flight.id, OutboxStatus::Failed as i64, error],
+                )?;
+            }
+        }
+        let entry = self.get(in_flight.id)?;
+        if let Some(entry) = &entry {
+            self.emit(entry);
+        }
+        // 同一会话的下一条消息可以发送了
+        self.notify.notify_one();
+        Ok(entry)
+    }
+
+    /// 超时未确认的消息按失败处理，进入退避重试
+    pub fn expire_in_flight(&self, now: i64) -> Result<(), OpenIMError> {
+        let expired: Vec<i64> = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            let keys: Vec<String> = in_flight
+                .iter()
+                .filter(|(_, f)| now - f.sent_at >= ACK_TIMEOUT_MS)
+                .map(|(k, _)| k.clone())
+                .collect();
+            keys.iter().filter_map(|k| in_flight.remove(k)).map(|f| f.id).collect()
+        };
+        for id in expired {
+            self.record_failure(id, "等待服务器确认超时", now)?;
+        }
+        Ok(())
+    }
+
+    /// 连接断开：清空等待确认列表，这些消息在重连后重新发送
+    pub fn reset_in_flight(&self) {
+        self.in_flight.lock().unwrap().clear();
+    }
+
+    /// 记录一次可重试的失败；达到最大次数后标记为失败
+    pub fn record_failure(&self, id: i64, error: &str, now: i64) -> Result<(), OpenIMError> {
+        let Some(entry) = self.get(id)? else {
+            return Ok(());
+        };
+        let attempts = entry.attempts + 1;
+        let status = if attempts >= MAX_ATTEMPTS {
+            OutboxStatus::Failed
+        } else {
+            OutboxStatus::Sending
+        };
+        self.store.conn()?.execute(
+            "UPDATE outbox SET status = ?2, attempts = ?3, next_attempt_at = ?4, last_error = ?5 WHERE id = ?1",
+            params![id, status as i64, attempts, now + retry_delay_ms(attempts), error],
+        )?;
+        self.emit_by_id(id)
+    }
+
+    /// 手动重发失败的消息（排在该会话尚未发出的更晚的消息之前）
+    pub fn retry(&self, id: i64, now: i64) -> Result<(), OpenIMError> {
+        let updated = self.store.conn()?.execute(
+            "UPDATE outbox SET status = ?2, attempts = 0, next_attempt_at = ?3, last_error = '' \
+             WHERE id = ?1 AND status = ?4",
+            params![id, OutboxStatus::Sending as i64, now, OutboxStatus::Failed as i64],
+        )?;
+        if updated == 0 {
+            return Err(OpenIMError::NotFound(format!("failed outbox {}", id)));
+        }
+        self.emit_by_id(id)?;
+        self.notify.notify_one();
+        Ok(())
+    }
+
+    fn emit_by_id(&self, id: i64) -> Result<(), OpenIMError> {
+        if let Some(entry) = self.get(id)? {
+            self.emit(&entry);
+        }
+        Ok(())
+    }
+
+    fn emit(&self, entry: &OutboxEntry) {
+        // 没有订阅者时发送失败，忽略即可
+        let _ = self.events.send(entry.event());
+    }
+}
+
+/// 第 n 次失败后的重试间隔：指数退避，上限 60 秒
+pub fn retry_delay_ms(attempts: i64) -> i64 {
+    let shift = (attempts - 1).clamp(0, 10);
+    (RETRY_BASE_MS << shift).min(RETRY_MAX_MS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outbox() -> Outbox {
+        Outbox::new(Arc::new(Store::open_in_memory().unwrap()))
+    }
+
+    fn text(conversation_id: &str) -> OutgoingMessage {
+        OutgoingMessage {
+            client_msg_id: uuid::Uuid::new_v4().simple().to_string(),
+            conversation_id: conversation_id.to_string(),
+            recv_id: "u2".to_string(),
+            group_id: String::new(),
+            session_type: 1,
+            content_type: 101,
+            content: br#"{"content":"hi"}"#.to_vec(),
+        }
+    }
+
+    /// 同一会话按顺序逐条发送，不同会话互不阻塞
+    #[test]
+    fn due_is_ordered_per_conversation() {
+        let outbox = outbox();
+        let a1 = outbox.enqueue(text("si_a"), 0).unwrap();
+        let a2 = outbox.enqueue(text("si_a"), 0).unwrap();
+        let b1 = outbox.enqueue(text("si_b"), 0).unwrap();
+
+        let ids: Vec<i64> = outbox.due(0).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![a1.id, b1.id]);
+
+        outbox.mark_in_flight(a1.id, "1".into(), 0);
+        outbox.mark_in_flight(b1.id, "2".into(), 0);
+        assert!(outbox.due(0).unwrap().is_empty());
+
+        assert!(outbox.ack("1", Ok(("s1".into(), 10))).unwrap().is_some());
+        let ids: Vec<i64> = outbox.due(0).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![a2.id]);
+        assert_eq!(outbox.get(a1.id).unwrap().unwrap().status, OutboxStatus::Sent);
+    }
+
+    /// 超时按退避重试，达到上限后标记失败，手动重试后恢复发送
+    #[test]
+    fn timeout_retries_with_backoff() {
+        let outbox = outbox();
+        let mut events = outbox.subscribe();
+        let entry = outbox.enqueue(text("si_a"), 0).unwrap();
+        assert_eq!(events.try_recv().unwrap().status, OutboxStatus::Sending);
+
+        let mut now = 0;
+        for attempt in 1..=MAX_ATTEMPTS {
+            // 每次重试都复用同一个 clientMsgID
+            let due = outbox.due(now).unwrap();
+            assert_eq!(due.len(), 1);
+            assert_eq!(due[0].client_msg_id, entry.client_msg_id);
+            outbox.mark_in_flight(entry.id, attempt.to_string(), now);
+            now += ACK_TIMEOUT_MS;
+            outbox.expire_in_flight(now).unwrap();
+            if attempt < MAX_ATTEMPTS {
+                assert!(outbox.due(now).unwrap().is_empty());
+                now += retry_delay_ms(attempt);
+            }
+        }
+        let failed = outbox.get(entry.id).unwrap().unwrap();
+        assert_eq!(failed.status, OutboxStatus::Failed);
+        assert_eq!(failed.attempts, MAX_ATTEMPTS);
+        assert!(outbox.due(now).unwrap().is_empty());
+        assert_eq!(last_event(&mut events).status, OutboxStatus::Failed);
+
+        outbox.retry(entry.id, now).unwrap();
+        assert_eq!(outbox.due(now).unwrap().len(), 1);
+    }
+
+    /// 失败的消息不阻塞同一会话之后的消息，重试后接着发送
+    #[test]
+    fn failed_message_does_not_block_conversation() {
+        let outbox = outbox();
+        let a1 = outbox.enqueue(text("si_a"), 0).unwrap();
+        let a2 = outbox.enqueue(text("si_a"), 0).unwrap();
+        let a3 = outbox.enqueue(text("si_a"), 0).unwrap();
+        outbox.mark_in_flight(a1.id, "1".into(), 0);
+        outbox.ack("1", Err("1302 - not friend".into())).unwrap();
+
+        let ids: Vec<i64> = outbox.due(0).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![a2.id]);
+        outbox.mark_in_flight(a2.id, "2".into(), 0);
+        outbox.ack("2", Ok(("s2".into(), 10))).unwrap();
+
+        // 重试的消息先于仍在等待的 a3 发送
+        outbox.retry(a1.id, 0).unwrap();
+        let ids: Vec<i64> = outbox.due(0).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![a1.id]);
+        outbox.mark_in_flight(a1.id, "3".into(), 0);
+        outbox.ack("3", Ok(("s1".into(), 20))).unwrap();
+        let ids: Vec<i64> = outbox.due(0).unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![a3.id]);
+    }
+
+    /// 服务器拒绝的消息直接失败
+    #[test]
+    fn server_error_fails_immediately() {
+        let outbox = outbox();
+        let entry = outbox.enqueue(text("si_a"), 0).unwrap();
+        outbox.mark_in_flight(entry.id, "7".into(), 0);
+        assert!(outbox.ack("7", Err("1302 - not friend".into())).unwrap().is_some());
+        assert!(outbox.ack("7", Err("dup".into())).unwrap().is_none());
+        assert_eq!(outbox.get(entry.id).unwrap().unwrap().status, OutboxStatus::Failed);
+    }
+
+    #[test]
+    fn retry_delay_is_capped() {
+        assert_eq!(retry_delay_ms(1), 2_000);
+        assert_eq!(retry_delay_ms(3), 8_000);
+        assert_eq!(retry_delay_ms(20), 60_000);
+    }
+
+    fn last_event(events: &mut broadcast::Receiver<OutboxEvent>) -> OutboxEvent {
+        let mut last = None;
+        while let Ok(e) = events.try_recv() {
+            last = Some(e);
+        }
+        last.unwrap()
+    }
+}
@@ -0,0 +1,613 @@
+//! 图片消息预处理：解码、生成大图 / 缩略图、去除 EXIF 中的 GPS 信息
+
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader};
+
+use super::content::{PictureBaseInfo, PictureElem};
+use super::OpenIMError;
+
+/// 大图最长边（像素）
+pub const BIG_MAX_SIDE: u32 = 1280;
+/// 缩略图最长边（像素）
+pub const SNAPSHOT_MAX_SIDE: u32 = 320;
+/// 重新编码 JPEG 时的质量
+const JPEG_QUALITY: u8 = 85;
+/// EXIF 中指向 GPS IFD 的标签
+const GPS_INFO_TAG: usize = 0x8825;
+/// JPEG APP1 中 XMP 段的前缀
+const JPEG_XMP_PREFIX: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+/// PNG 中存放 XMP 的 iTXt 块关键字
+const PNG_XMP_KEYWORD: &[u8] = b"XML:com.adobe.xmp\0";
+
+/// 单个尺寸的图片
+#[derive(Debug, Clone)]
+pub struct ImageVariant {
+    pub data: Vec<u8>,
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageVariant {
+    /// 生成 PictureBaseInfo（url 需上传后填写）
+    fn base_info(&self) -> PictureBaseInfo {
+        PictureBaseInfo {
+            uuid: format!("{}.{}", uuid::Uuid::new_v4(), extension(&self.mime_type)),
+            mime_type: self.mime_type.clone(),
+            size: self.data.len() as i64,
+            width: self.width as i32,
+            height: self.height as i32,
+            url: String::new(),
+        }
+    }
+}
+
+/// 预处理后的图片（原图 / 大图 / 缩略图）
+#[derive(Debug, Clone)]
+pub struct ProcessedPicture {
+    pub source: ImageVariant,
+    pub big: ImageVariant,
+    pub snapshot: ImageVariant,
+}
+
+impl ProcessedPicture {
+    /// 生成图片消息内容，三个尺寸的 url 由调用方在上传完成后填写
+    pub fn picture_elem(&self, source_path: &str) -> PictureElem {
+        PictureElem {
+            source_path: source_path.to_string(),
+            source_picture: self.source.base_info(),
+            big_picture: self.big.base_info(),
+            snapshot_picture: self.snapshot.base_info(),
+        }
+    }
+}
+
+/// 预处理待发送的图片
+///
+/// 原图保留原始编码，仅原地清空 EXIF 和 XMP 中的 GPS 信息；大图和缩略图按 EXIF 方向旋转后重新编码。
+/// HEIC/HEIF 无法在纯 Rust 中解码，需由平台侧先转码为 JPEG。
+pub fn process_picture(data: &[u8]) -> Result<ProcessedPicture, OpenIMError> {
+    if is_heif(data) {
+        return Err(OpenIMError::UnsupportedImage(
+            "HEIC/HEIF，请先在平台侧转码为 JPEG".to_string(),
+        ));
+    }
+
+    let format = image::guess_format(data)?;
+    let mime_type = match format {
+        ImageFormat::Jpeg => "image/jpeg",
+        ImageFormat::Png => "image/png",
+        ImageFormat::WebP => "image/webp",
+        other => return Err(OpenIMError::UnsupportedImage(format!("{:?}", other))),
+    };
+
+    let mut source_data = data.to_vec();
+    strip_gps(format, &mut source_data);
+
+    let mut decoder = ImageReader::with_format(Cursor::new(data), format).into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+
+    let source = ImageVariant {
+        data: source_data,
+        mime_type: mime_type.to_string(),
+        width: img.width(),
+        height: img.height(),
+    };
+    let big = resize_variant(&img, &source, BIG_MAX_SIDE)?;
+    let snapshot = resize_variant(&img, &source, SNAPSHOT_MAX_SIDE)?;
+
+    Ok(ProcessedPicture { source, big, snapshot })
+}
+
+/// 按最长边缩放；原图已经足够小时直接复用原图
+fn resize_variant(
+    img: &DynamicImage,
+    source: &ImageVariant,
+    max_side: u32,
+) -> Result<ImageVariant, OpenIMError> {
+    if img.width().max(img.height()) <= max_side {
+        return Ok(source.clone());
+    }
+    encode(&img.resize(max_side, max_side, FilterType::Triangle))
+}
+
+/// 重新编码：带透明通道的用 PNG，其余用 JPEG
+fn encode(img: &DynamicImage) -> Result<ImageVariant, OpenIMError> {
+    let mut data = Vec::new();
+    let mime_type = if img.color().has_alpha() {
+        img.write_to(&mut Cursor::new(&mut data), ImageFormat::Png)?;
+        "image/png"
+    } else {
+        DynamicImage::ImageRgb8(img.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut data, JPEG_QUALITY))?;
+        "image/jpeg"
+    };
+    Ok(ImageVariant {
+        data,
+        mime_type: mime_type.to_string(),
+        width: img.width(),
+        height: img.height(),
+    })
+}
+
+fn extension(mime_type: &str) -> &'static str {
+    match mime_type {
+        "image/png" => "png",
+        "image/webp" => "webp",
+        _ => "jpg",
+    }
+}
+
+/// 检查是否为 HEIC/HEIF/AVIF（ISO BMFF 容器）
+fn is_heif(data: &[u8]) -> bool {
+    if data.get(4..8) != Some(b"ftyp".as_slice()) {
+        return false;
+    }
+    matches!(
+        data.get(8..12),
+        Some(b"heic" | b"heix" | b"hevc" | b"hevx" | b"heim" | b"heis" | b"mif1" | b"msf1" | b"avif")
+    )
+}
+
+/// 清空图片中 EXIF 和 XMP 的 GPS 信息，返回是否找到并清除了 GPS 数据
+pub fn strip_gps(format: ImageFormat, data: &mut [u8]) -> bool {
+    match format {
+        ImageFormat::Jpeg => strip_jpeg_gps(data),
+        ImageFormat::Png => strip_png_gps(data),
+        ImageFormat::WebP => strip_webp_gps(data),
+        _ => false,
+    }
+}
+
+/// JPEG：在 APP1 的 "Exif" 段和 XMP 段中清除
+fn strip_jpeg_gps(data: &mut [u8]) -> bool {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return false;
+    }
+    let mut stripped = false;
+    let mut pos = 2;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            break;
+        }
+        let marker = data[pos + 1];
+        match marker {
+            // 填充字节
+            0xFF => {
+                pos += 1;
+                continue;
+            }
+            // SOS / EOI 之后不再有元数据段
+            0xDA | 0xD9 => break,
+            // 无长度字段的标记
+            0x01 | 0xD0..=0xD7 => {
+                pos += 2;
+                continue;
+            }
+            _ => {}
+        }
+        let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > data.len() {
+            break;
+        }
+        let segment = &mut data[pos + 4..end];
+        if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+            stripped |= scrub_gps_ifd(&mut segment[6..]);
+        } else if marker == 0xE1 && segment.starts_with(JPEG_XMP_PREFIX) {
+            stripped |= scrub_xmp_gps(&mut segment[JPEG_XMP_PREFIX.len()..]);
+        }
+        pos = end;
+    }
+    stripped
+}
+
+/// PNG：在 eXIf 块和 XMP 的 iTXt 块中清除，并重新计算 CRC
+fn strip_png_gps(data: &mut [u8]) -> bool {
+    const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    if !data.starts_with(SIGNATURE) {
+        return false;
+    }
+    let mut stripped = false;
+    let mut pos = SIGNATURE.len();
+    while pos + 12 <= data.len() {
+        let len = u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+        let body_end = pos + 8 + len;
+        if body_end + 4 > data.len() {
+            break;
+        }
+        let chunk_type = [data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]];
+        let body = &mut data[pos + 8..body_end];
+        let changed = match &chunk_type {
+            b"eXIf" => scrub_gps_ifd(body),
+            b"iTXt" => png_xmp_text(body).is_some_and(scrub_xmp_gps),
+            _ => false,
+        };
+        if changed {
+            let mut crc = flate2::Crc::new();
+            crc.update(&data[pos + 4..body_end]);
+            data[body_end..body_end + 4].copy_from_slice(&crc.sum().to_be_bytes());
+            stripped = true;
+        }
+        if &chunk_type == b"IEND" {
+            break;
+        }
+        pos = body_end + 4;
+    }
+    stripped
+}
+
+/// WebP：在 RIFF 的 EXIF 块和 "XMP " 块中清除
+fn strip_webp_gps(data: &mut [u8]) -> bool {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WEBP" {
+        return false;
+    }
+    let mut stripped = false;
+    let mut pos = 12;
+    while pos + 8 <= data.len() {
+        let len = u32::from_le_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]]) as usize;
+        let body_end = pos + 8 + len;
+        if body_end > data.len() {
+            break;
+        }
+        if &data[pos..pos + 4] == b"EXIF" {
+            let body = &mut data[pos + 8..body_end];
+            // 部分编码器会在 EXIF 块中保留 "Exif\0\0" 前缀
+            let tiff = if body.starts_with(b"Exif\0\0") { &mut body[6..] } else { body };
+            stripped |= scrub_gps_ifd(tiff);
+        } else if &data[pos..pos + 4] == b"XMP " {
+            stripped |= scrub_xmp_gps(&mut data[pos + 8..body_end]);
+        }
+        // 块按偶数字节对齐
+        pos = body_end + (len & 1);
+    }
+    stripped
+}
+
+/// 在 TIFF 结构中原地清空 GPS IFD，其余 EXIF 信息（如方向）和数据长度保持不变
+///
+/// 返回是否清除了 GPS 信息，没有 GPS IFD 或已为空时返回 false。
+fn scrub_gps_ifd(tiff: &mut [u8]) -> bool {
+    let big_endian = match tiff.get(0..2) {
+        Some(b"II") => false,
+        Some(b"MM") => true,
+        _ => return false,
+    };
+    let Some(ifd0) = read_u32(tiff, 4, big_endian) else {
+        return false;
+    };
+    let Some(count) = read_u16(tiff, ifd0, big_endian) else {
+        return false;
+    };
+    let gps_ifd = (0..count).find_map(|i| {
+        let entry = ifd0 + 2 + i * 12;
+        if read_u16(tiff, entry, big_endian)? == GPS_INFO_TAG {
+            read_u32(tiff, entry + 8, big_endian)
+        } else {
+            None
+        }
+    });
+    let Some(gps_ifd) = gps_ifd else {
+        return false;
+    };
+    let Some(gps_count) = read_u16(tiff, gps_ifd, big_endian).filter(|&n| n > 0) else {
+        return false;
+    };
+
+    // 先清空条目引用的外部数据（超过 4 字节的值存放在偏移处）
+    for i in 0..gps_count {
+        let entry = gps_ifd + 2 + i * 12;
+        let (Some(field_type), Some(n)) = (
+            read_u16(tiff, entry + 2, big_endian),
+            read_u32(tiff, entry + 4, big_endian),
+        ) else {
+            break;
+        };
+        let size = tiff_type_size(field_type).saturating_mul(n);
+        if size <= 4 {
+            continue;
+        }
+        if let Some(offset) = read_u32(tiff, entry + 8, big_endian) {
+            if let Some(value) = tiff.get_mut(offset..offset.saturating_add(size)) {
+                value.fill(0);
+            }
+        }
+    }
+
+    // 再清空 IFD 本身（条目数置 0 即为空 IFD）
+    let ifd_end = (gps_ifd + 2 + gps_count * 12 + 4).min(tiff.len());
+    tiff[gps_ifd..ifd_end].fill(0);
+    true
+}
+
+/// PNG iTXt 块中的 XMP 文本；压缩的文本无法原地修改，返回 None（XMP 规范要求不压缩）
+fn png_xmp_text(body: &mut [u8]) -> Option<&mut [u8]> {
+    if !body.starts_with(PNG_XMP_KEYWORD) {
+        return None;
+    }
+    // 关键字之后：压缩标志、压缩方法、语言标签、翻译后的关键字，之后为文本
+    let mut pos = PNG_XMP_KEYWORD.len();
+    if body.get(pos) != Some(&0) {
+        return None;
+    }
+    pos += 2;
+    for _ in 0..2 {
+        pos += body.get(pos..)?.iter().position(|b| *b == 0)? + 1;
+    }
+    body.get_mut(pos..)
+}
+
+/// 在 XMP 中原地把 GPS 属性（`exif:GPSLatitude` 等）的值替换为空格，数据长度保持不变
+///
+/// 同时处理属性写法 `exif:GPSLatitude="…"` 和元素写法 `<exif:GPSLatitude>…</exif:GPSLatitude>`，
+/// 返回是否清除了非空的值。
+fn scrub_xmp_gps(xmp: &mut [u8]) -> bool {
+    let is_name_byte = |b: &u8| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.');
+    let mut stripped = false;
+    let mut pos = 0;
+    while let Some(found) = find_bytes(&xmp[pos..], b":GPS") {
+        let colon = pos + found;
+        let start = xmp[..colon].iter().rposition(|b| !is_name_byte(b)).map_or(0, |i| i + 1);
+        let name_end = xmp[colon + 1..].iter().position(|b| !is_name_byte(b)).map_or(xmp.len(), |i| colon + 1 + i);
+        let value = if start > 0 && xmp[start - 1] == b'<' {
+            xmp_element_content(xmp, name_end, &xmp[start..name_end])
+        } else {
+            xmp_attribute_value(xmp, name_end)
+        };
+        pos = name_end;
+        if let Some(value) = value {
+            let value = &mut xmp[value];
+            if value.iter().any(|b| !b.is_ascii_whitespace()) {
+                value.fill(b' ');
+                stripped = true;
+            }
+        }
+    }
+    stripped
+}
+
+/// 属性名之后 `="…"` 中引号内的范围
+fn xmp_attribute_value(xmp: &[u8], name_end: usize) -> Option<std::ops::Range<usize>> {
+    let skip_space = |at: usize| {
+        let rest = xmp.get(at..)?;
+        rest.iter().position(|b| !b.is_ascii_whitespace()).map(|i| at + i)
+    };
+    let eq = skip_space(name_end)?;
+    if xmp[eq] != b'=' {
+        return None;
+    }
+    let open = skip_space(eq + 1)?;
+    let quote = xmp[open];
+    if quote != b'"' && quote != b'\'' {
+        return None;
+    }
+    let close = open + 1 + xmp[open + 1..].iter().position(|b| *b == quote)?;
+    Some(open + 1..close)
+}
+
+/// 开始标签之后到对应结束标签之前的范围（自闭合的元素没有内容）
+fn xmp_element_content(xmp: &[u8], name_end: usize, name: &[u8]) -> Option<std::ops::Range<usize>> {
+    let tag_end = name_end + xmp.get(name_end..)?.iter().position(|b| *b == b'>')?;
+    if xmp[tag_end - 1] == b'/' {
+        return None;
+    }
+    let closing = [b"</".as_slice(), name].concat();
+    let end = tag_end + 1 + find_bytes(&xmp[tag_end + 1..], &closing)?;
+    Some(tag_end + 1..end)
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn read_u16(buf: &[u8], at: usize, big_endian: bool) -> Option<usize> {
+    let b: [u8; 2] = buf.get(at..at.checked_add(2)?)?.try_into().ok()?;
+    Some(if big_endian { u16::from_be_bytes(b) } else { u16::from_le_bytes(b) } as usize)
+}
+
+fn read_u32(buf: &[u8], at: usize, big_endian: bool) -> Option<usize> {
+    let b: [u8; 4] = buf.get(at..at.checked_add(4)?)?.try_into().ok()?;
+    Some(if big_endian { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) } as usize)
+}
+
+/// TIFF 字段类型对应的单个值字节数
+fn tiff_type_size(field_type: usize) -> usize {
+    match field_type {
+        3 | 8 => 2,
+        4 | 9 | 11 => 4,
+        5 | 10 | 12 => 8,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    /// 构造一个小端 TIFF：IFD0 含方向（6）和 GPS 指针，GPS IFD 含一个纬度条目
+    fn tiff_with_gps() -> Vec<u8> {
+        let mut t = Vec::new();
+        t.extend_from_slice(b"II");
+        t.extend_from_slice(&42u16.to_le_bytes());
+        t.extend_from_slice(&8u32.to_le_bytes());
+        // IFD0 @8: 2 个条目
+        t.extend_from_slice(&2u16.to_le_bytes());
+        t.extend_from_slice(&0x0112u16.to_le_bytes());
+        t.extend_from_slice(&3u16.to_le_bytes());
+        t.extend_from_slice(&1u32.to_le_bytes());
+        t.extend_from_slice(&6u32.to_le_bytes());
+        t.extend_from_slice(&0x8825u16.to_le_bytes());
+        t.extend_from_slice(&4u16.to_le_bytes());
+        t.extend_from_slice(&1u32.to_le_bytes());
+        t.extend_from_slice(&38u32.to_le_bytes());
+        t.extend_from_slice(&0u32.to_le_bytes());
+        // GPS IFD @38: GPSLatitude，3 个 RATIONAL 存放在 @56
+        t.extend_from_slice(&1u16.to_le_bytes());
+        t.extend_from_slice(&0x0002u16.to_le_bytes());
+        t.extend_from_slice(&5u16.to_le_bytes());
+        t.extend_from_slice(&3u32.to_le_bytes());
+        t.extend_from_slice(&56u32.to_le_bytes());
+        t.extend_from_slice(&0u32.to_le_bytes());
+        for v in [31u32, 1, 14, 1, 2520, 100] {
+            t.extend_from_slice(&v.to_le_bytes());
+        }
+        t
+    }
+
+    /// XMP 中的 GPS 同时使用属性写法和元素写法，另有方向信息
+    const XMP: &str = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+<rdf:Description rdf:about="" xmlns:exif="http://ns.adobe.com/exif/1.0/" xmlns:tiff="http://ns.adobe.com/tiff/1.0/"
+ tiff:Orientation="6" exif:GPSLatitude="31,14.25N" exif:GPSLongitude = '121,28.5E'>
+<exif:GPSAltitude>12/1</exif:GPSAltitude><exif:GPSVersionID><rdf:Seq><rdf:li>2.2.0.0</rdf:li></rdf:Seq></exif:GPSVersionID>
+<exif:GPSMapDatum/></rdf:Description></rdf:RDF></x:xmpmeta>"#;
+
+    /// 断言 XMP 中的 GPS 值已清除，其余内容和长度不变
+    fn assert_xmp_scrubbed(xmp: &[u8]) {
+        let xmp = std::str::from_utf8(xmp).unwrap();
+        assert_eq!(xmp.len(), XMP.len());
+        for value in ["31,14.25N", "121,28.5E", "12/1", "2.2.0.0", "rdf:Seq"] {
+            assert!(!xmp.contains(value), "{}", value);
+        }
+        assert!(xmp.contains(r#"tiff:Orientation="6""#));
+        assert!(xmp.contains("</exif:GPSAltitude><exif:GPSVersionID>"));
+        assert!(xmp.ends_with("</rdf:Description></rdf:RDF></x:xmpmeta>"));
+    }
+
+    fn jpeg(width: u32, height: u32) -> Vec<u8> {
+        let img = RgbImage::from_pixel(width, height, Rgb([200, 100, 50]));
+        let mut data = Vec::new();
+        DynamicImage::ImageRgb8(img)
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut data, 90))
+            .unwrap();
+        data
+    }
+
+    /// 清空 GPS IFD，保留方向信息
+    #[test]
+    fn scrub_gps_keeps_orientation() {
+        let mut tiff = tiff_with_gps();
+        let len = tiff.len();
+
+        assert!(scrub_gps_ifd(&mut tiff));
+        assert_eq!(tiff.len(), len);
+        assert_eq!(read_u16(&tiff, 10, false), Some(0x0112));
+        assert_eq!(read_u32(&tiff, 18, false), Some(6));
+        assert_eq!(read_u16(&tiff, 38, false), Some(0));
+        assert!(tiff[56..].iter().all(|b| *b == 0));
+
+        // 再次处理时已无 GPS 数据
+        assert!(!scrub_gps_ifd(&mut tiff));
+        assert!(!scrub_gps_ifd(&mut b"not a tiff".to_vec()));
+    }
+
+    /// JPEG 的 APP1 段中的 GPS 被清除，图片仍可解码且方向被应用
+    #[test]
+    fn process_jpeg_with_exif() {
+        let plain = jpeg(400, 200);
+        let mut app1 = b"Exif\0\0".to_vec();
+        app1.extend_from_slice(&tiff_with_gps());
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        data.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+        data.extend_from_slice(&app1);
+        data.extend_from_slice(&plain[2..]);
+
+        let picture = process_picture(&data).unwrap();
+        assert_eq!(picture.source.data.len(), data.len());
+        // SOI + APP1 标记 + 长度 + "Exif\0\0" 之后即为 TIFF
+        let tiff = &picture.source.data[12..];
+        assert_eq!(read_u16(tiff, 38, false), Some(0));
+        assert_eq!(read_u32(tiff, 18, false), Some(6));
+
+        // 方向 6（顺时针 90°）：宽高互换
+        assert_eq!((picture.source.width, picture.source.height), (200, 400));
+        assert_eq!((picture.snapshot.width, picture.snapshot.height), (160, 320));
+        assert_eq!(picture.big.data, picture.source.data);
+    }
+
+    /// XMP 中属性和元素写法的 GPS 都被清除，再次处理时不再报告变化
+    #[test]
+    fn scrub_xmp_gps_values() {
+        let mut xmp = XMP.as_bytes().to_vec();
+        assert!(scrub_xmp_gps(&mut xmp));
+        assert_xmp_scrubbed(&xmp);
+        assert!(!scrub_xmp_gps(&mut xmp));
+        assert!(!scrub_xmp_gps(&mut b"<x:xmpmeta></x:xmpmeta>".to_vec()));
+    }
+
+    /// JPEG、PNG、WebP 中的 XMP 都被清除，PNG 块的 CRC 重新计算
+    #[test]
+    fn strip_xmp_in_containers() {
+        let plain = jpeg(40, 20);
+        let mut app1 = JPEG_XMP_PREFIX.to_vec();
+        app1.extend_from_slice(XMP.as_bytes());
+        let mut data = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        data.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+        data.extend_from_slice(&app1);
+        data.extend_from_slice(&plain[2..]);
+        let picture = process_picture(&data).unwrap();
+        let start = 6 + JPEG_XMP_PREFIX.len();
+        assert_xmp_scrubbed(&picture.source.data[start..start + XMP.len()]);
+
+        let mut png = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(4, 4)).write_to(&mut Cursor::new(&mut png), ImageFormat::Png).unwrap();
+        let mut body = PNG_XMP_KEYWORD.to_vec();
+        body.extend_from_slice(&[0, 0, 0, 0]);
+        body.extend_from_slice(XMP.as_bytes());
+        let mut chunk = (body.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(b"iTXt");
+        chunk.extend_from_slice(&body);
+        let mut crc = flate2::Crc::new();
+        crc.update(&chunk[4..]);
+        chunk.extend_from_slice(&crc.sum().to_be_bytes());
+        // 放在 IHDR（签名 8 字节 + 块 25 字节）之后
+        png.splice(33..33, chunk);
+        let picture = process_picture(&png).unwrap();
+        let start = 33 + 8 + PNG_XMP_KEYWORD.len() + 4;
+        assert_xmp_scrubbed(&picture.source.data[start..start + XMP.len()]);
+        let chunk_end = start + XMP.len();
+        let mut crc = flate2::Crc::new();
+        crc.update(&picture.source.data[33 + 4..chunk_end]);
+        assert_eq!(picture.source.data[chunk_end..chunk_end + 4], crc.sum().to_be_bytes());
+
+        let mut webp = b"RIFF\0\0\0\0WEBPXMP ".to_vec();
+        webp.extend_from_slice(&(XMP.len() as u32).to_le_bytes());
+        webp.extend_from_slice(XMP.as_bytes());
+        assert!(strip_gps(ImageFormat::WebP, &mut webp));
+        assert_xmp_scrubbed(&webp[20..20 + XMP.len()]);
+    }
+
+    /// 大图与缩略图按最长边缩放，PictureElem 信息完整
+    #[test]
+    fn picture_elem_metadata() {
+        let data = jpeg(2000, 1000);
+        let picture = process_picture(&data).unwrap();
+        let elem = picture.picture_elem("/tmp/a.jpg");
+
+        assert_eq!(elem.source_path, "/tmp/a.jpg");
+        assert_eq!((elem.source_picture.width, elem.source_picture.height), (2000, 1000));
+        assert_eq!((elem.big_picture.width, elem.big_picture.height), (1280, 640));
+        assert_eq!((elem.snapshot_picture.width, elem.snapshot_picture.height), (320, 160));
+        assert_eq!(elem.snapshot_picture.mime_type, "image/jpeg");
+        assert_eq!(elem.snapshot_picture.size, picture.snapshot.data.len() as i64);
+        assert!(elem.big_picture.uuid.ends_with(".jpg"));
+
+        let json = serde_json::to_value(&elem).unwrap();
+        assert_eq!(json["bigPicture"]["width"], 1280);
+        assert_eq!(json["snapshotPicture"]["type"], "image/jpeg");
+    }
+
+    /// HEIC 返回明确的不支持错误
+    #[test]
+    fn heic_is_unsupported() {
+        let mut data = vec![0, 0, 0, 24];
+        data.extend_from_slice(b"ftypheic");
+        data.extend_from_slice(&[0; 12]);
+        assert!(matches!(process_picture(&data), Err(OpenIMError::UnsupportedImage(_))));
+    }
+}
@@ -0,0 +1,173 @@
+//! 用户在线状态订阅
+//!
+//! 订阅列表保存在本地，通过 WebSocket（`WS_SUB_USER_ONLINE_STATUS`）发送给服务器；
+//! 重连后重新订阅全部用户。服务器在订阅时和状态变化时推送各平台的在线情况。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use tokio::sync::{broadcast, Notify};
+
+/// 在线状态变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresenceEvent {
+    pub user_id: String,
+    /// 在线的平台，为空表示离线
+    pub platform_ids: Vec<i32>,
+}
+
+#[derive(Default)]
+struct PresenceState {
+    subscribed: HashSet<String>,
+    pending_subscribe: HashSet<String>,
+    pending_unsubscribe: HashSet<String>,
+    platforms: HashMap<String, Vec<i32>>,
+}
+
+/// 在线状态
+pub struct Presence {
+    state: Mutex<PresenceState>,
+    notify: Notify,
+    events: broadcast::Sender<PresenceEvent>,
+}
+
+impl Default for Presence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Presence {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self {
+            state: Mutex::new(PresenceState::default()),
+            notify: Notify::new(),
+            events,
+        }
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<PresenceEvent> {
+        self.events.subscribe()
+    }
+
+    /// 等待订阅列表变化
+    pub async fn notified(&self) {
+        self.notify.notified().await
+    }
+
+    /// 订阅用户的在线状态
+    pub fn subscribe(&self, user_ids: &[String]) {
+        let mut state = self.state.lock().unwrap();
+        for user_id in user_ids {
+            if state.subscribed.insert(user_id.clone()) {
+                state.pending_unsubscribe.remove(user_id);
+                state.pending_subscribe.insert(user_id.clone());
+            }
+        }
+        self.notify.notify_one();
+    }
+
+    /// 取消订阅
+    pub fn unsubscribe(&self, user_ids: &[String]) {
+        let mut state = self.state.lock().unwrap();
+        for user_id in user_ids {
+            if state.subscribed.remove(user_id) {
+                state.platforms.remove(user_id);
+                if !state.pending_subscribe.remove(user_id) {
+                    state.pending_unsubscribe.insert(user_id.clone());
+                }
+            }
+        }
+        self.notify.notify_one();
+    }
+
+    /// 取出待发送的订阅 / 取消订阅列表，没有变化时返回 None
+    pub fn take_pending(&self) -> Option<(Vec<String>, Vec<String>)> {
+        let mut state = self.state.lock().unwrap();
+        if state.pending_subscribe.is_empty() && state.pending_unsubscribe.is_empty() {
+            return None;
+        }
+        let mut subscribe: Vec<_> = state.pending_subscribe.drain().collect();
+        let mut unsubscribe: Vec<_> = state.pending_unsubscribe.drain().collect();
+        subscribe.sort();
+        unsubscribe.sort();
+        Some((subscribe, unsubscribe))
+    }
+
+    /// 新连接建立后重新订阅全部用户（服务器不保留上一个连接的订阅）
+    pub fn resubscribe_all(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.pending_subscribe = state.subscribed.clone();
+        state.pending_unsubscribe.clear();
+    }
+
+    /// 当前在线的平台，未订阅或离线时为空
+    pub fn platforms(&self, user_id: &str) -> Vec<i32> {
+        self.state.lock().unwrap().platforms.get(user_id).cloned().unwrap_or_default()
+    }
+
+    /// 处理服务器推送的在线状态，有变化时通知
+    pub fn apply(&self, user_id: &str, mut platform_ids: Vec<i32>) {
+        platform_ids.sort_unstable();
+        platform_ids.dedup();
+        {
+            let mut state = self.state.lock().unwrap();
+            if !state.subscribed.contains(user_id) {
+                return;
+            }
+            let previous = state.platforms.get(user_id).map(Vec::as_slice).unwrap_or_default();
+            if previous == platform_ids.as_slice() {
+                return;
+            }
+            state.platforms.insert(user_id.to_string(), platform_ids.clone());
+        }
+        let _ = self.events.send(PresenceEvent { user_id: user_id.to_string(), platform_ids });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn pending_and_resubscribe() {
+        let presence = Presence::new();
+        presence.subscribe(&ids(&["u1", "u2"]));
+        presence.unsubscribe(&ids(&["u2"]));
+        // 还没发给服务器就取消的用户不需要再发取消订阅
+        assert_eq!(presence.take_pending(), Some((ids(&["u1"]), vec![])));
+        assert_eq!(presence.take_pending(), None);
+
+        presence.unsubscribe(&ids(&["u1"]));
+        presence.subscribe(&ids(&["u3"]));
+        assert_eq!(presence.take_pending(), Some((ids(&["u3"]), ids(&["u1"]))));
+
+        presence.resubscribe_all();
+        assert_eq!(presence.take_pending(), Some((ids(&["u3"]), vec![])));
+    }
+
+    #[test]
+    fn apply_emits_changes_only() {
+        let presence = Presence::new();
+        let mut events = presence.subscribe_events();
+        presence.subscribe(&ids(&["u1"]));
+
+        presence.apply("u1", vec![5, 1]);
+        presence.apply("u1", vec![1, 5]);
+        presence.apply("u9", vec![1]);
+        assert_eq!(
+            events.try_recv().unwrap(),
+            PresenceEvent { user_id: "u1".to_string(), platform_ids: vec![1, 5] }
+        );
+        assert!(events.try_recv().is_err());
+        assert_eq!(presence.platforms("u1"), vec![1, 5]);
+
+        presence.apply("u1", vec![]);
+        assert!(events.try_recv().unwrap().platform_ids.is_empty());
+    }
+}
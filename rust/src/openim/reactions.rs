@@ -0,0 +1,274 @@
+//! 消息的表情回应
+//!
+//! 表情回应保存在服务器的消息扩展字段中：每个用户的每个表情占一个键（见 [`type_key`]），值为
+//! [`ReactionValue`] 的 JSON。本地按消息汇总为每个表情回应过的用户；收到扩展字段修改 / 删除的通知后
+//! 更新本地，并通过事件通知变化的消息。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use super::content::{content_type, is_reaction_change, notification_detail};
+use super::rest::msg::KeyValue;
+use super::rest::ApiClient;
+use super::store::Store;
+use super::OpenIMError;
+
+/// 扩展字段中表情回应的键前缀
+const TYPE_KEY_PREFIX: &str = "reaction:";
+
+/// 扩展字段的键：每个用户的每个表情一个
+pub fn type_key(emoji: &str, user_id: &str) -> String {
+    format!("{}{}:{}", TYPE_KEY_PREFIX, emoji, user_id)
+}
+
+/// 扩展字段的值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionValue {
+    pub emoji: String,
+    #[serde(rename = "userID")]
+    pub user_id: String,
+}
+
+/// 一个表情的回应汇总
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactionSummary {
+    pub emoji: String,
+    /// 回应过的用户（按回应时间）
+    pub user_ids: Vec<String>,
+}
+
+/// 表情回应变化
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReactionEvent {
+    pub conversation_id: String,
+    pub client_msg_id: String,
+}
+
+/// ReactionMessageModifierNotification / ReactionMessageDeleteNotification
+#[derive(Deserialize)]
+struct ReactionTips {
+    #[serde(rename = "conversationID", default)]
+    conversation_id: String,
+    #[serde(rename = "clientMsgID", default)]
+    client_msg_id: String,
+    #[serde(rename = "successReactionExtensions", alias = "reactionExtensionList", default)]
+    extensions: Option<HashMap<String, KeyValue>>,
+}
+
+/// 表情回应服务
+#[derive(Clone)]
+pub struct Reactions {
+    store: Arc<Store>,
+    events: broadcast::Sender<ReactionEvent>,
+}
+
+impl Reactions {
+    pub fn new(store: Arc<Store>) -> Self {
+        let (events, _) = broadcast::channel(256);
+        Self { store, events }
+    }
+
+    /// 订阅表情回应变化
+    pub fn subscribe(&self) -> broadcast::Receiver<ReactionEvent> {
+        self.events.subscribe()
+    }
+
+    fn notify(&self, conversation_id: &str, client_msg_id: &str) {
+        let _ = self.events.send(ReactionEvent {
+            conversation_id: conversation_id.to_string(),
+            client_msg_id: client_msg_id.to_string(),
+        });
+    }
+
+    /// 消息的表情回应（按每个表情最早的回应时间排序）
+    pub fn summary(&self, client_msg_id: &str) -> Result<Vec<ReactionSummary>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT emoji, user_id FROM message_reactions WHERE client_msg_id = ?1 ORDER BY update_time, rowid",
+        )?;
+        let rows = stmt
+            .query_map(params![client_msg_id], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut summary: Vec<ReactionSummary> = Vec::new();
+        for (emoji, user_id) in rows {
+            match summary.iter_mut().find(|s| s.emoji == emoji) {
+                Some(s) => s.user_ids.push(user_id),
+                None => summary.push(ReactionSummary { emoji, user_ids: vec![user_id] }),
+            }
+        }
+        Ok(summary)
+    }
+
+    /// 保存扩展字段中的表情回应（忽略其他扩展字段），返回是否有变化
+    fn upsert(&self, client_msg_id: &str, extensions: &[KeyValue]) -> Result<bool, OpenIMError> {
+        let mut conn = self.store.conn()?;
+        let tx = conn.transaction()?;
+        let mut changed = false;
+        for kv in extensions.iter().filter(|kv| kv.type_key.starts_with(TYPE_KEY_PREFIX)) {
+            let Ok(value) = serde_json::from_str::<ReactionValue>(&kv.value) else {
+                tracing::warn!(type_key = %kv.type_key, "无法解析的表情回应");
+                continue;
+            };
+            changed |= tx.execute(
+                "INSERT INTO message_reactions (client_msg_id, type_key, emoji, user_id, update_time) \
+                 VALUES (?1, ?2, ?3, ?4, ?5) ON CONFLICT (client_msg_id, type_key) DO NOTHING",
+                params![client_msg_id, kv.type_key, value.emoji, value.user_id, kv.latest_update_time],
+            )? > 0;
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 删除表情回应，返回是否有变化
+    fn delete<'a>(&self, client_msg_id: &str, type_keys: impl IntoIterator<Item = &'a str>) -> Result<bool, OpenIMError> {
+        let mut conn = self.store.conn()?;
+        let tx = conn.transaction()?;
+        let mut changed = false;
+        for type_key in type_keys {
+            changed |= tx.execute(
+                "DELETE FROM message_reactions WHERE client_msg_id = ?1 AND type_key = ?2",
+                params![client_msg_id, type_key],
+            )? > 0;
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// 对消息添加表情回应
+    pub async fn add(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        conversation_id: &str,
+        client_msg_id: &str,
+        emoji: &str,
+        now_ms: i64,
+    ) -> Result<(), OpenIMError> {
+        let value = ReactionValue { emoji: emoji.to_string(), user_id: me.to_string() };
+        let kv = KeyValue {
+            type_key: type_key(emoji, me),
+            value: serde_json::to_string(&value)?,
+            latest_update_time: now_ms,
+        };
+        let extensions = [kv];
+        api.set_message_reaction_extensions(conversation_id, client_msg_id, &extensions).await?;
+        if self.upsert(client_msg_id, &extensions)? {
+            self.notify(conversation_id, client_msg_id);
+        }
+        Ok(())
+    }
+
+    /// 取消自己对消息的表情回应
+    pub async fn remove(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        conversation_id: &str,
+        client_msg_id: &str,
+        emoji: &str,
+    ) -> Result<(), OpenIMError> {
+        let kv = KeyValue { type_key: type_key(emoji, me), ..Default::default() };
+        api.delete_message_reaction_extensions(conversation_id, client_msg_id, std::slice::from_ref(&kv))
+            .await?;
+        if self.delete(client_msg_id, [kv.type_key.as_str()])? {
+            self.notify(conversation_id, client_msg_id);
+        }
+        Ok(())
+    }
+
+    /// 扩展字段修改 / 删除的通知（包括自己在其他设备上的操作）
+    pub fn apply_notification(&self, content_type: i32, content: &[u8]) -> Result<(), OpenIMError> {
+        if !is_reaction_change(content_type) {
+            return Ok(());
+        }
+        // 通知内容可能直接是 Tips 的 JSON，也可能包装在 detail 中
+        let tips: ReactionTips = match notification_detail(content) {
+            Ok(tips) => tips,
+            Err(_) => serde_json::from_slice(content)?,
+        };
+        let extensions = tips.extensions.unwrap_or_default();
+        let changed = if content_type == content_type::REACTION_MESSAGE_MODIFIER {
+            let kvs: Vec<KeyValue> = extensions
+                .into_iter()
+                .map(|(key, mut kv)| {
+                    if kv.type_key.is_empty() {
+                        kv.type_key = key;
+                    }
+                    kv
+                })
+                .collect();
+            self.upsert(&tips.client_msg_id, &kvs)?
+        } else {
+            self.delete(&tips.client_msg_id, extensions.keys().map(String::as_str))?
+        };
+        if changed {
+            self.notify(&tips.conversation_id, &tips.client_msg_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rest::tests::serve;
+    use super::*;
+
+    fn notification(client_msg_id: &str, emoji: &str, user_id: &str) -> Vec<u8> {
+        let key = type_key(emoji, user_id);
+        let value = serde_json::to_string(&ReactionValue { emoji: emoji.to_string(), user_id: user_id.to_string() }).unwrap();
+        let tips = serde_json::json!({
+            "conversationID": "si_u1_u2",
+            "clientMsgID": client_msg_id,
+            "successReactionExtensions": { key.clone(): { "typeKey": key, "value": value, "latestUpdateTime": 5 } },
+        });
+        serde_json::to_vec(&serde_json::json!({ "detail": tips.to_string() })).unwrap()
+    }
+
+    #[tokio::test]
+    async fn reactions_are_aggregated_per_emoji() {
+        let ok = r#"{"errCode":0,"errMsg":"","errDlt":""}"#;
+        let (base_url, server) = serve(&[ok, ok]).await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let reactions = Reactions::new(Arc::new(Store::open_in_memory().unwrap()));
+        let mut events = reactions.subscribe();
+
+        reactions.add(&api, "u1", "si_u1_u2", "m1", "👍", 1).await.unwrap();
+        let modifier = content_type::REACTION_MESSAGE_MODIFIER;
+        reactions.apply_notification(modifier, &notification("m1", "❤️", "u2")).unwrap();
+        reactions.apply_notification(modifier, &notification("m1", "👍", "u2")).unwrap();
+        // 自己操作的通知不重复触发事件
+        reactions.apply_notification(modifier, &notification("m1", "👍", "u1")).unwrap();
+        assert_eq!(
+            reactions.summary("m1").unwrap(),
+            [
+                ReactionSummary { emoji: "👍".to_string(), user_ids: vec!["u1".to_string(), "u2".to_string()] },
+                ReactionSummary { emoji: "❤️".to_string(), user_ids: vec!["u2".to_string()] },
+            ]
+        );
+
+        reactions.remove(&api, "u1", "si_u1_u2", "m1", "👍").await.unwrap();
+        reactions
+            .apply_notification(content_type::REACTION_MESSAGE_DELETER, &notification("m1", "❤️", "u2"))
+            .unwrap();
+        assert_eq!(
+            reactions.summary("m1").unwrap(),
+            [ReactionSummary { emoji: "👍".to_string(), user_ids: vec!["u2".to_string()] }]
+        );
+        let seen = std::iter::from_fn(|| events.try_recv().ok()).count();
+        assert_eq!(seen, 5);
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("POST /msg/set_message_reaction_extensions "));
+        assert!(requests[0].ends_with(
+            r#""reactionExtensions":{"reaction:👍:u1":{"typeKey":"reaction:👍:u1","value":"{\"emoji\":\"👍\",\"userID\":\"u1\"}","latestUpdateTime":1}}}"#
+        ));
+        assert!(requests[1].starts_with("POST /msg/delete_message_reaction_extensions "));
+        assert!(requests[1].ends_with(
+            r#"{"conversationID":"si_u1_u2","clientMsgID":"m1","isExternalExtensions":false,"reactionExtensions":[{"typeKey":"reaction:👍:u1","value":"","latestUpdateTime":0}]}"#
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+//! 认证相关接口
+
+use serde::{Deserialize, Serialize};
+
+use super::ApiClient;
+use crate::openim::OpenIMError;
+
+/// 获取到的 token
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TokenResp {
+    pub token: String,
+    #[serde(rename = "expireTimeSeconds", default)]
+    pub expire_time_seconds: i64,
+}
+
+#[derive(Serialize)]
+struct GetAdminTokenReq<'a> {
+    secret: &'a str,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct GetUserTokenReq<'a> {
+    #[serde(rename = "platformID")]
+    platform_id: i32,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+}
+
+impl ApiClient {
+    /// 使用服务器密钥获取管理员 token
+    pub async fn get_admin_token(&self, secret: &str, admin_user_id: &str) -> Result<TokenResp, OpenIMError> {
+        self.post(
+            "/auth/get_admin_token",
+            &GetAdminTokenReq { secret, user_id: admin_user_id },
+        )
+        .await
+    }
+
+    /// 获取用户 token（需要以管理员 token 调用）
+    pub async fn get_user_token(&self, platform_id: i32, user_id: &str) -> Result<TokenResp, OpenIMError> {
+        self.post("/auth/get_user_token", &GetUserTokenReq { platform_id, user_id })
+            .await
+    }
+}
@@ -0,0 +1,170 @@
+//! 会话相关接口
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, Empty, Incremental, Pagination};
+use crate::openim::OpenIMError;
+
+/// 每页数量（拉取完整列表时使用）
+const PAGE_SIZE: i32 = 100;
+
+/// 服务器保存的会话设置（对应服务器 conversation.Conversation）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ServerConversation {
+    #[serde(rename = "conversationID")]
+    pub conversation_id: String,
+    #[serde(rename = "conversationType", default)]
+    pub conversation_type: i32,
+    #[serde(rename = "userID", default)]
+    pub user_id: String,
+    #[serde(rename = "groupID", default)]
+    pub group_id: String,
+    #[serde(rename = "recvMsgOpt", default)]
+    pub recv_msg_opt: i32,
+    #[serde(rename = "isPinned", default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    pub ex: String,
+}
+
+/// 修改会话设置，None 表示不修改
+#[derive(Debug, Clone, Default)]
+pub struct ConversationUpdate {
+    pub recv_msg_opt: Option<i32>,
+    pub is_pinned: Option<bool>,
+}
+
+/// protobuf 包装类型（Int32Value / BoolValue）
+#[derive(Serialize)]
+struct Value<T> {
+    value: T,
+}
+
+#[derive(Serialize)]
+struct ConversationReq<'a> {
+    #[serde(rename = "conversationID")]
+    conversation_id: &'a str,
+    #[serde(rename = "conversationType")]
+    conversation_type: i32,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "recvMsgOpt", skip_serializing_if = "Option::is_none")]
+    recv_msg_opt: Option<Value<i32>>,
+    #[serde(rename = "isPinned", skip_serializing_if = "Option::is_none")]
+    is_pinned: Option<Value<bool>>,
+}
+
+#[derive(Serialize)]
+struct SetConversationsReq<'a> {
+    #[serde(rename = "userIDs")]
+    user_ids: [&'a str; 1],
+    conversation: ConversationReq<'a>,
+}
+
+#[derive(Serialize)]
+struct OwnerConversationReq<'a> {
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    pagination: Pagination,
+}
+
+#[derive(Default, Deserialize)]
+struct OwnerConversationResp {
+    #[serde(default)]
+    conversations: Vec<ServerConversation>,
+}
+
+#[derive(Serialize)]
+struct ClearConversationsMsgReq<'a> {
+    #[serde(rename = "conversationIDs")]
+    conversation_ids: &'a [String],
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+}
+
+impl ApiClient {
+    /// 获取自己的全部会话设置
+    pub async fn get_owner_conversations(&self, user_id: &str) -> Result<Vec<ServerConversation>, OpenIMError> {
+        let mut all = Vec::new();
+        for page_number in 1.. {
+            let pagination = Pagination { page_number, show_number: PAGE_SIZE };
+            let resp: OwnerConversationResp = self
+                .post("/conversation/get_owner_conversation", &OwnerConversationReq { user_id, pagination })
+                .await?;
+            let done = resp.conversations.len() < PAGE_SIZE as usize;
+            all.extend(resp.conversations);
+            if done {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    /// 增量获取会话设置
+    pub async fn get_incremental_conversations(
+        &self,
+        user_id: &str,
+        version_id: &str,
+        version: i64,
+    ) -> Result<Incremental<ServerConversation>, OpenIMError> {
+        self.get_incremental("/conversation/get_incremental_conversations", user_id, version_id, version).await
+    }
+
+    /// 修改自己的会话设置
+    pub async fn set_conversation(
+        &self,
+        owner_user_id: &str,
+        conversation: &ServerConversation,
+        update: &ConversationUpdate,
+    ) -> Result<(), OpenIMError> {
+        let req = SetConversationsReq {
+            user_ids: [owner_user_id],
+            conversation: ConversationReq {
+                conversation_id: &conversation.conversation_id,
+                conversation_type: conversation.conversation_type,
+                user_id: &conversation.user_id,
+                group_id: &conversation.group_id,
+                recv_msg_opt: update.recv_msg_opt.map(|value| Value { value }),
+                is_pinned: update.is_pinned.map(|value| Value { value }),
+            },
+        };
+        let _: Empty = self.post("/conversation/set_conversations", &req).await?;
+        Ok(())
+    }
+
+    /// 清空会话消息（只影响自己）
+    pub async fn clear_conversation_msg(&self, user_id: &str, conversation_ids: &[String]) -> Result<(), OpenIMError> {
+        let _: Empty = self
+            .post("/msg/clear_conversation_msg", &ClearConversationsMsgReq { conversation_ids, user_id })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::serve;
+    use super::*;
+
+    #[tokio::test]
+    async fn set_conversation_sends_wrapped_values() {
+        let (base_url, server) = serve(&[r#"{"errCode":0,"errMsg":"","errDlt":""}"#]).await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let conversation = ServerConversation {
+            conversation_id: "si_u1_u2".to_string(),
+            conversation_type: 1,
+            user_id: "u2".to_string(),
+            ..Default::default()
+        };
+        let update = ConversationUpdate { is_pinned: Some(true), ..Default::default() };
+        api.set_conversation("u1", &conversation, &update).await.unwrap();
+
+        let request = server.await.unwrap().remove(0);
+        assert!(request.starts_with("POST /conversation/set_conversations "));
+        assert!(request.ends_with(
+            r#"{"userIDs":["u1"],"conversation":{"conversationID":"si_u1_u2","conversationType":1,"userID":"u2","groupID":"","isPinned":{"value":true}}}"#
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+//! 好友相关接口（好友、好友申请、黑名单）
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::user::UserInfo;
+use super::{ApiClient, Empty, Incremental, Pagination};
+use crate::openim::OpenIMError;
+
+/// 每页数量（拉取完整列表时使用）
+const PAGE_SIZE: i32 = 100;
+
+/// 处理好友申请的结果
+pub mod handle_result {
+    pub const ACCEPT: i32 = 1;
+    pub const REJECT: i32 = -1;
+}
+
+/// 好友信息（对应服务器 sdkws.FriendInfo）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct FriendInfo {
+    #[serde(rename = "ownerUserID", default)]
+    pub owner_user_id: String,
+    #[serde(default)]
+    pub remark: String,
+    #[serde(rename = "createTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "friendUser", default)]
+    pub friend_user: UserInfo,
+    #[serde(rename = "addSource", default)]
+    pub add_source: i32,
+    #[serde(default)]
+    pub ex: String,
+}
+
+/// 好友申请（对应服务器 sdkws.FriendRequest）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct FriendRequest {
+    #[serde(rename = "fromUserID")]
+    pub from_user_id: String,
+    #[serde(rename = "fromNickname", default)]
+    pub from_nickname: String,
+    #[serde(rename = "fromFaceURL", default)]
+    pub from_face_url: String,
+    #[serde(rename = "toUserID")]
+    pub to_user_id: String,
+    #[serde(rename = "toNickname", default)]
+    pub to_nickname: String,
+    #[serde(rename = "toFaceURL", default)]
+    pub to_face_url: String,
+    /// 0 未处理，1 同意，-1 拒绝
+    #[serde(rename = "handleResult", default)]
+    pub handle_result: i32,
+    #[serde(rename = "reqMsg", default)]
+    pub req_msg: String,
+    #[serde(rename = "createTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "handleMsg", default)]
+    pub handle_msg: String,
+    #[serde(rename = "handleTime", default)]
+    pub handle_time: i64,
+}
+
+/// 黑名单用户的公开信息（对应服务器 sdkws.PublicUserInfo）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PublicUserInfo {
+    #[serde(rename = "userID", default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub nickname: String,
+    #[serde(rename = "faceURL", default)]
+    pub face_url: String,
+}
+
+/// 黑名单记录（对应服务器 sdkws.BlackInfo）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct BlackInfo {
+    #[serde(rename = "createTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "blackUserInfo", default)]
+    pub black_user_info: PublicUserInfo,
+}
+
+#[derive(Serialize)]
+struct UserPageReq<'a> {
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    pagination: Pagination,
+}
+
+#[derive(Default, Deserialize)]
+struct FriendListResp {
+    #[serde(rename = "friendsInfo", default)]
+    friends_info: Vec<FriendInfo>,
+}
+
+#[derive(Default, Deserialize)]
+struct FriendRequestsResp {
+    #[serde(rename = "friendRequests", default)]
+    friend_requests: Vec<FriendRequest>,
+}
+
+#[derive(Default, Deserialize)]
+struct BlackListResp {
+    #[serde(default)]
+    blacks: Vec<BlackInfo>,
+}
+
+#[derive(Serialize)]
+struct AddFriendReq<'a> {
+    #[serde(rename = "fromUserID")]
+    from_user_id: &'a str,
+    #[serde(rename = "toUserID")]
+    to_user_id: &'a str,
+    #[serde(rename = "reqMsg")]
+    req_msg: &'a str,
+}
+
+#[derive(Serialize)]
+struct RespondFriendReq<'a> {
+    #[serde(rename = "fromUserID")]
+    from_user_id: &'a str,
+    #[serde(rename = "toUserID")]
+    to_user_id: &'a str,
+    #[serde(rename = "handleResult")]
+    handle_result: i32,
+    #[serde(rename = "handleMsg")]
+    handle_msg: &'a str,
+}
+
+#[derive(Serialize)]
+struct FriendReq<'a> {
+    #[serde(rename = "ownerUserID")]
+    owner_user_id: &'a str,
+    #[serde(rename = "friendUserID")]
+    friend_user_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remark: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct BlackReq<'a> {
+    #[serde(rename = "ownerUserID")]
+    owner_user_id: &'a str,
+    #[serde(rename = "blackUserID")]
+    black_user_id: &'a str,
+}
+
+/// 分页响应中的列表
+trait PageResp<T>: DeserializeOwned + Default {
+    fn into_items(self) -> Vec<T>;
+}
+
+impl PageResp<FriendInfo> for FriendListResp {
+    fn into_items(self) -> Vec<FriendInfo> {
+        self.friends_info
+    }
+}
+
+impl PageResp<FriendRequest> for FriendRequestsResp {
+    fn into_items(self) -> Vec<FriendRequest> {
+        self.friend_requests
+    }
+}
+
+impl PageResp<BlackInfo> for BlackListResp {
+    fn into_items(self) -> Vec<BlackInfo> {
+        self.blacks
+    }
+}
+
+impl ApiClient {
+    /// 按页拉取完整列表，返回条数不足一页时结束
+    async fn fetch_all<R: PageResp<T>, T>(&self, path: &str, user_id: &str) -> Result<Vec<T>, OpenIMError> {
+        let mut all = Vec::new();
+        for page_number in 1.. {
+            let pagination = Pagination { page_number, show_number: PAGE_SIZE };
+            let resp: R = self.post(path, &UserPageReq { user_id, pagination }).await?;
+            let items = resp.into_items();
+            let done = items.len() < PAGE_SIZE as usize;
+            all.extend(items);
+            if done {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    /// 获取全部好友
+    pub async fn get_friend_list(&self, user_id: &str) -> Result<Vec<FriendInfo>, OpenIMError> {
+        self.fetch_all::<FriendListResp, _>("/friend/get_friend_list", user_id).await
+    }
+
+    /// 增量获取好友（服务器不支持时返回 404，见 [`super::is_not_supported`]）
+    pub async fn get_incremental_friends(
+        &self,
+        user_id: &str,
+        version_id: &str,
+        version: i64,
+    ) -> Result<Incremental<FriendInfo>, OpenIMError> {
+        self.get_incremental("/friend/get_incremental_friends", user_id, version_id, version).await
+    }
+
+    /// 获取收到的好友申请
+    pub async fn get_friend_apply_list(&self, user_id: &str) -> Result<Vec<FriendRequest>, OpenIMError> {
+        self.fetch_all::<FriendRequestsResp, _>("/friend/get_friend_apply_list", user_id).await
+    }
+
+    /// 获取自己发出的好友申请
+    pub async fn get_self_friend_apply_list(&self, user_id: &str) -> Result<Vec<FriendRequest>, OpenIMError> {
+        self.fetch_all::<FriendRequestsResp, _>("/friend/get_self_friend_apply_list", user_id).await
+    }
+
+    /// 获取黑名单
+    pub async fn get_black_list(&self, user_id: &str) -> Result<Vec<BlackInfo>, OpenIMError> {
+        self.fetch_all::<BlackListResp, _>("/friend/get_black_list", user_id).await
+    }
+
+    /// 发送好友申请
+    pub async fn add_friend(&self, from_user_id: &str, to_user_id: &str, req_msg: &str) -> Result<(), OpenIMError> {
+        let _: Empty = self
+            .post("/friend/add_friend", &AddFriendReq { from_user_id, to_user_id, req_msg })
+            .await?;
+        Ok(())
+    }
+
+    /// 处理好友申请（from 为申请人，to 为自己），结果见 [`handle_result`]
+    pub async fn add_friend_response(
+        &self,
+        from_user_id: &str,
+        to_user_id: &str,
+        handle_result: i32,
+        handle_msg: &str,
+    ) -> Result<(), OpenIMError> {
+        let req = RespondFriendReq { from_user_id, to_user_id, handle_result, handle_msg };
+        let _: Empty = self.post("/friend/add_friend_response", &req).await?;
+        Ok(())
+    }
+
+    /// 删除好友
+    pub async fn delete_friend(&self, owner_user_id: &str, friend_user_id: &str) -> Result<(), OpenIMError> {
+        let req = FriendReq { owner_user_id, friend_user_id, remark: None };
+        let _: Empty = self.post("/friend/delete_friend", &req).await?;
+        Ok(())
+    }
+
+    /// 设置好友备注
+    pub async fn set_friend_remark(
+        &self,
+        owner_user_id: &str,
+        friend_user_id: &str,
+        remark: &str,
+    ) -> Result<(), OpenIMError> {
+        let req = FriendReq { owner_user_id, friend_user_id, remark: Some(remark) };
+        let _: Empty = self.post("/friend/set_friend_remark", &req).await?;
+        Ok(())
+    }
+
+    /// 加入黑名单
+    pub async fn add_black(&self, owner_user_id: &str, black_user_id: &str) -> Result<(), OpenIMError> {
+        let _: Empty = self.post("/friend/add_black", &BlackReq { owner_user_id, black_user_id }).await?;
+        Ok(())
+    }
+
+    /// 移出黑名单
+    pub async fn remove_black(&self, owner_user_id: &str, black_user_id: &str) -> Result<(), OpenIMError> {
+        let _: Empty = self
+            .post("/friend/remove_black", &BlackReq { owner_user_id, black_user_id })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::serve;
+    use super::*;
+
+    #[tokio::test]
+    async fn get_friend_list_decodes_data() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"errMsg":"","errDlt":"","data":{"total":1,"friendsInfo":[{"ownerUserID":"u1","remark":"老王","createTime":1700000000,"friendUser":{"userID":"u2","nickname":"王五","faceURL":""},"addSource":1,"operatorUserID":"u1","ex":"","isPinned":false}]}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let friends = api.get_friend_list("u1").await.unwrap();
+        assert_eq!(friends.len(), 1);
+        assert_eq!(friends[0].remark, "老王");
+        assert_eq!(friends[0].friend_user.nickname, "王五");
+
+        let request = server.await.unwrap().remove(0);
+        assert!(request.ends_with(r#"{"userID":"u1","pagination":{"pageNumber":1,"showNumber":100}}"#));
+    }
+}
@@ -0,0 +1,453 @@
+//! 群组相关接口
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, Empty, Incremental, Pagination};
+use crate::openim::OpenIMError;
+
+/// 每页数量（拉取完整列表时使用）
+const PAGE_SIZE: i32 = 100;
+
+/// 群成员角色（对应服务器常量）
+pub mod role_level {
+    pub const OWNER: i32 = 100;
+    pub const ADMIN: i32 = 60;
+    pub const ORDINARY: i32 = 20;
+}
+
+/// 入群方式（对应服务器常量）
+pub mod join_source {
+    pub const INVITATION: i32 = 2;
+    pub const SEARCH: i32 = 3;
+    pub const QR_CODE: i32 = 4;
+}
+
+/// 群状态（对应服务器常量）
+#[allow(dead_code)]
+pub mod group_status {
+    pub const OK: i32 = 0;
+    pub const BANNED: i32 = 1;
+    pub const DISMISSED: i32 = 2;
+    pub const MUTED: i32 = 3;
+}
+
+/// 工作群（当前版本只支持这一种群类型）
+const WORKING_GROUP: i32 = 2;
+
+/// 群信息（对应服务器 sdkws.GroupInfo）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupInfo {
+    #[serde(rename = "groupID", default)]
+    pub group_id: String,
+    #[serde(rename = "groupName", default)]
+    pub group_name: String,
+    #[serde(default)]
+    pub notification: String,
+    #[serde(default)]
+    pub introduction: String,
+    #[serde(rename = "faceURL", default)]
+    pub face_url: String,
+    #[serde(rename = "ownerUserID", default)]
+    pub owner_user_id: String,
+    #[serde(rename = "createTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "memberCount", default)]
+    pub member_count: i32,
+    #[serde(default)]
+    pub ex: String,
+    /// 见 [`group_status`]
+    #[serde(default)]
+    pub status: i32,
+    #[serde(rename = "creatorUserID", default)]
+    pub creator_user_id: String,
+    #[serde(rename = "groupType", default)]
+    pub group_type: i32,
+    #[serde(rename = "needVerification", default)]
+    pub need_verification: i32,
+}
+
+/// 群成员（对应服务器 sdkws.GroupMemberFullInfo）
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct GroupMemberInfo {
+    #[serde(rename = "groupID", default)]
+    pub group_id: String,
+    #[serde(rename = "userID", default)]
+    pub user_id: String,
+    /// 见 [`role_level`]
+    #[serde(rename = "roleLevel", default)]
+    pub role_level: i32,
+    #[serde(rename = "joinTime", default)]
+    pub join_time: i64,
+    #[serde(default)]
+    pub nickname: String,
+    #[serde(rename = "faceURL", default)]
+    pub face_url: String,
+    #[serde(rename = "joinSource", default)]
+    pub join_source: i32,
+    #[serde(rename = "inviterUserID", default)]
+    pub inviter_user_id: String,
+    /// 禁言结束时间（毫秒）
+    #[serde(rename = "muteEndTime", default)]
+    pub mute_end_time: i64,
+    #[serde(default)]
+    pub ex: String,
+}
+
+#[derive(Serialize)]
+struct CreateGroupReq<'a> {
+    #[serde(rename = "memberUserIDs")]
+    member_user_ids: &'a [String],
+    #[serde(rename = "adminUserIDs")]
+    admin_user_ids: &'a [String],
+    #[serde(rename = "ownerUserID")]
+    owner_user_id: &'a str,
+    #[serde(rename = "groupInfo")]
+    group_info: &'a GroupInfo,
+}
+
+#[derive(Default, Deserialize)]
+struct GroupInfoResp {
+    #[serde(rename = "groupInfo", default)]
+    group_info: GroupInfo,
+}
+
+#[derive(Serialize)]
+struct GroupIdsReq<'a> {
+    #[serde(rename = "groupIDs")]
+    group_ids: &'a [String],
+}
+
+#[derive(Default, Deserialize)]
+struct GroupsInfoResp {
+    #[serde(rename = "groupInfos", default)]
+    group_infos: Vec<GroupInfo>,
+}
+
+#[derive(Serialize)]
+struct JoinedGroupsReq<'a> {
+    #[serde(rename = "fromUserID")]
+    from_user_id: &'a str,
+    pagination: Pagination,
+}
+
+#[derive(Default, Deserialize)]
+struct JoinedGroupsResp {
+    #[serde(default)]
+    groups: Vec<GroupInfo>,
+}
+
+#[derive(Serialize)]
+struct MemberListReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    pagination: Pagination,
+}
+
+#[derive(Default, Deserialize)]
+struct MemberListResp {
+    #[serde(default)]
+    members: Vec<GroupMemberInfo>,
+}
+
+#[derive(Serialize)]
+struct InviteReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "invitedUserIDs")]
+    invited_user_ids: &'a [String],
+    reason: &'a str,
+}
+
+#[derive(Serialize)]
+struct KickReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "kickedUserIDs")]
+    kicked_user_ids: &'a [String],
+    reason: &'a str,
+}
+
+#[derive(Serialize)]
+struct JoinReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "reqMessage")]
+    req_message: &'a str,
+    #[serde(rename = "joinSource")]
+    join_source: i32,
+}
+
+#[derive(Serialize)]
+struct ApplicationResponseReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "fromUserID")]
+    from_user_id: &'a str,
+    #[serde(rename = "handledMsg")]
+    handled_msg: &'a str,
+    #[serde(rename = "handleResult")]
+    handle_result: i32,
+}
+
+#[derive(Serialize)]
+struct GroupUserReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct GroupReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct DismissReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "deleteMember")]
+    delete_member: bool,
+}
+
+#[derive(Serialize)]
+struct TransferReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "oldOwnerUserID")]
+    old_owner_user_id: &'a str,
+    #[serde(rename = "newOwnerUserID")]
+    new_owner_user_id: &'a str,
+}
+
+#[derive(Serialize)]
+struct SetMemberRoleReq<'a> {
+    members: [MemberRole<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct MemberRole<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    #[serde(rename = "roleLevel")]
+    role_level: i32,
+}
+
+#[derive(Serialize)]
+struct MuteMemberReq<'a> {
+    #[serde(rename = "groupID")]
+    group_id: &'a str,
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    #[serde(rename = "mutedSeconds")]
+    muted_seconds: u32,
+}
+
+impl ApiClient {
+    /// 创建群（自己为群主）
+    pub async fn create_group(
+        &self,
+        owner_user_id: &str,
+        group_name: &str,
+        member_user_ids: &[String],
+        admin_user_ids: &[String],
+    ) -> Result<GroupInfo, OpenIMError> {
+        let group_info = GroupInfo {
+            group_name: group_name.to_string(),
+            group_type: WORKING_GROUP,
+            ..Default::default()
+        };
+        let req = CreateGroupReq { member_user_ids, admin_user_ids, owner_user_id, group_info: &group_info };
+        let resp: GroupInfoResp = self.post("/group/create_group", &req).await?;
+        Ok(resp.group_info)
+    }
+
+    /// 批量获取群信息
+    pub async fn get_groups_info(&self, group_ids: &[String]) -> Result<Vec<GroupInfo>, OpenIMError> {
+        let resp: GroupsInfoResp = self.post("/group/get_groups_info", &GroupIdsReq { group_ids }).await?;
+        Ok(resp.group_infos)
+    }
+
+    /// 获取已加入的全部群
+    pub async fn get_joined_group_list(&self, user_id: &str) -> Result<Vec<GroupInfo>, OpenIMError> {
+        let mut all = Vec::new();
+        for page_number in 1.. {
+            let pagination = Pagination { page_number, show_number: PAGE_SIZE };
+            let resp: JoinedGroupsResp = self
+                .post("/group/get_joined_group_list", &JoinedGroupsReq { from_user_id: user_id, pagination })
+                .await?;
+            let done = resp.groups.len() < PAGE_SIZE as usize;
+            all.extend(resp.groups);
+            if done {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    /// 增量获取已加入的群
+    pub async fn get_incremental_join_groups(
+        &self,
+        user_id: &str,
+        version_id: &str,
+        version: i64,
+    ) -> Result<Incremental<GroupInfo>, OpenIMError> {
+        self.get_incremental("/group/get_incremental_join_groups", user_id, version_id, version).await
+    }
+
+    /// 分页获取群成员
+    pub async fn get_group_member_list(
+        &self,
+        group_id: &str,
+        pagination: Pagination,
+    ) -> Result<Vec<GroupMemberInfo>, OpenIMError> {
+        let resp: MemberListResp = self
+            .post("/group/get_group_member_list", &MemberListReq { group_id, pagination })
+            .await?;
+        Ok(resp.members)
+    }
+
+    /// 获取全部群成员
+    pub async fn get_all_group_members(&self, group_id: &str) -> Result<Vec<GroupMemberInfo>, OpenIMError> {
+        let mut all = Vec::new();
+        for page_number in 1.. {
+            let members = self
+                .get_group_member_list(group_id, Pagination { page_number, show_number: PAGE_SIZE })
+                .await?;
+            let done = members.len() < PAGE_SIZE as usize;
+            all.extend(members);
+            if done {
+                break;
+            }
+        }
+        Ok(all)
+    }
+
+    /// 邀请入群
+    pub async fn invite_user_to_group(&self, group_id: &str, user_ids: &[String], reason: &str) -> Result<(), OpenIMError> {
+        let req = InviteReq { group_id, invited_user_ids: user_ids, reason };
+        let _: Empty = self.post("/group/invite_user_to_group", &req).await?;
+        Ok(())
+    }
+
+    /// 踢出群
+    pub async fn kick_group(&self, group_id: &str, user_ids: &[String], reason: &str) -> Result<(), OpenIMError> {
+        let req = KickReq { group_id, kicked_user_ids: user_ids, reason };
+        let _: Empty = self.post("/group/kick_group", &req).await?;
+        Ok(())
+    }
+
+    /// 申请入群（不需要验证的群直接加入），入群方式见 [`join_source`]
+    pub async fn join_group(&self, group_id: &str, req_message: &str, join_source: i32) -> Result<(), OpenIMError> {
+        let req = JoinReq { group_id, req_message, join_source };
+        let _: Empty = self.post("/group/join_group", &req).await?;
+        Ok(())
+    }
+
+    /// 处理入群申请（群主或管理员），结果见 [`super::friend::handle_result`]
+    pub async fn group_application_response(
+        &self,
+        group_id: &str,
+        from_user_id: &str,
+        handle_result: i32,
+        handled_msg: &str,
+    ) -> Result<(), OpenIMError> {
+        let req = ApplicationResponseReq { group_id, from_user_id, handled_msg, handle_result };
+        let _: Empty = self.post("/group/group_application_response", &req).await?;
+        Ok(())
+    }
+
+    /// 退出群
+    pub async fn quit_group(&self, group_id: &str, user_id: &str) -> Result<(), OpenIMError> {
+        let _: Empty = self.post("/group/quit_group", &GroupUserReq { group_id, user_id }).await?;
+        Ok(())
+    }
+
+    /// 解散群
+    pub async fn dismiss_group(&self, group_id: &str) -> Result<(), OpenIMError> {
+        let _: Empty = self
+            .post("/group/dismiss_group", &DismissReq { group_id, delete_member: false })
+            .await?;
+        Ok(())
+    }
+
+    /// 转让群主
+    pub async fn transfer_group(&self, group_id: &str, old_owner: &str, new_owner: &str) -> Result<(), OpenIMError> {
+        let req = TransferReq { group_id, old_owner_user_id: old_owner, new_owner_user_id: new_owner };
+        let _: Empty = self.post("/group/transfer_group", &req).await?;
+        Ok(())
+    }
+
+    /// 设置成员角色（管理员 / 普通成员），见 [`role_level`]
+    pub async fn set_group_member_role(&self, group_id: &str, user_id: &str, role_level: i32) -> Result<(), OpenIMError> {
+        let req = SetMemberRoleReq { members: [MemberRole { group_id, user_id, role_level }] };
+        let _: Empty = self.post("/group/set_group_member_info", &req).await?;
+        Ok(())
+    }
+
+    /// 禁言成员，muted_seconds 为 0 时解除禁言
+    pub async fn mute_group_member(&self, group_id: &str, user_id: &str, muted_seconds: u32) -> Result<(), OpenIMError> {
+        let _: Empty = if muted_seconds == 0 {
+            self.post("/group/cancel_mute_group_member", &GroupUserReq { group_id, user_id })
+                .await?
+        } else {
+            self.post("/group/mute_group_member", &MuteMemberReq { group_id, user_id, muted_seconds })
+                .await?
+        };
+        Ok(())
+    }
+
+    /// 全员禁言 / 解除全员禁言
+    pub async fn mute_group(&self, group_id: &str, muted: bool) -> Result<(), OpenIMError> {
+        let path = if muted { "/group/mute_group" } else { "/group/cancel_mute_group" };
+        let _: Empty = self.post(path, &GroupReq { group_id }).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::friend::handle_result;
+    use super::super::tests::serve;
+    use super::*;
+
+    #[tokio::test]
+    async fn create_group_sends_owner_and_members() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"data":{"groupInfo":{"groupID":"g1","groupName":"周末爬山","ownerUserID":"u1","memberCount":3,"status":0,"groupType":2}}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let group = api
+            .create_group("u1", "周末爬山", &["u2".to_string(), "u3".to_string()], &[])
+            .await
+            .unwrap();
+        assert_eq!(group.group_id, "g1");
+        assert_eq!(group.member_count, 3);
+
+        let request = server.await.unwrap().remove(0);
+        let body: serde_json::Value = serde_json::from_str(&request[request.find("\r\n\r\n").unwrap() + 4..]).unwrap();
+        assert_eq!(body["ownerUserID"], "u1");
+        assert_eq!(body["memberUserIDs"], serde_json::json!(["u2", "u3"]));
+        assert_eq!(body["groupInfo"]["groupName"], "周末爬山");
+        assert_eq!(body["groupInfo"]["groupType"], 2);
+    }
+
+    #[tokio::test]
+    async fn join_and_respond_to_application() {
+        let ok = r#"{"errCode":0,"errMsg":"","errDlt":""}"#;
+        let (base_url, server) = serve(&[ok, ok]).await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        api.join_group("g1", "我是小王", join_source::SEARCH).await.unwrap();
+        api.group_application_response("g1", "u2", handle_result::ACCEPT, "欢迎").await.unwrap();
+
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("POST /group/join_group "));
+        assert!(requests[0].ends_with(r#"{"groupID":"g1","reqMessage":"我是小王","joinSource":3}"#));
+        assert!(requests[1].starts_with("POST /group/group_application_response "));
+        assert!(requests[1].ends_with(r#"{"groupID":"g1","fromUserID":"u2","handledMsg":"欢迎","handleResult":1}"#));
+    }
+}
@@ -0,0 +1,221 @@
+//! OpenIM REST API 客户端（默认端口 10002）
+//!
+//! 所有接口均为 POST JSON，请求头携带 `operationID` 和 `token`，
+//! 响应统一为 `{"errCode", "errMsg", "errDlt", "data"}`。
+
+pub mod auth;
+pub mod conversation;
+pub mod friend;
+pub mod group;
+pub mod msg;
+pub mod user;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::OpenIMError;
+
+/// 统一响应结构
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    #[serde(rename = "errCode")]
+    err_code: i32,
+    #[serde(rename = "errMsg", default)]
+    err_msg: String,
+    #[serde(rename = "errDlt", default)]
+    err_dlt: String,
+    #[serde(default = "Option::default")]
+    data: Option<T>,
+}
+
+/// 无数据的响应
+#[derive(Debug, Default, Deserialize)]
+pub struct Empty {}
+
+/// 分页参数（pageNumber 从 1 开始）
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Pagination {
+    #[serde(rename = "pageNumber")]
+    pub page_number: i32,
+    #[serde(rename = "showNumber")]
+    pub show_number: i32,
+}
+
+/// 增量同步请求（首次同步时 versionID 为空、version 为 0）
+#[derive(Serialize)]
+struct IncrementalReq<'a> {
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    #[serde(rename = "versionID")]
+    version_id: &'a str,
+    version: i64,
+}
+
+/// 增量同步响应（好友、已加入的群、会话）
+///
+/// full 为 true 时服务器无法给出增量（本地版本过旧或首次同步），insert 为完整列表，需要替换本地数据。
+#[derive(Debug, Default, Deserialize)]
+pub struct Incremental<T> {
+    #[serde(rename = "versionID", default)]
+    pub version_id: String,
+    #[serde(default)]
+    pub version: i64,
+    #[serde(default)]
+    pub full: bool,
+    /// 删除的记录 ID（Go 端空列表序列化为 null）
+    #[serde(default)]
+    pub delete: Option<Vec<String>>,
+    #[serde(default)]
+    pub insert: Option<Vec<T>>,
+    #[serde(default)]
+    pub update: Option<Vec<T>>,
+}
+
+impl<T> Incremental<T> {
+    /// 拆分为删除的 ID 和新增或修改的记录
+    pub fn into_changes(self) -> (Vec<String>, Vec<T>) {
+        let mut upserts = self.insert.unwrap_or_default();
+        upserts.extend(self.update.unwrap_or_default());
+        (self.delete.unwrap_or_default(), upserts)
+    }
+}
+
+/// 服务器不支持该接口（旧版本服务器没有增量同步接口，返回 404）
+pub fn is_not_supported(err: &OpenIMError) -> bool {
+    matches!(err, OpenIMError::Http(e) if e.status() == Some(reqwest::StatusCode::NOT_FOUND))
+}
+
+/// REST API 客户端
+#[derive(Clone)]
+pub struct ApiClient {
+    base_url: String,
+    token: String,
+    http: reqwest::Client,
+}
+
+impl ApiClient {
+    pub fn new(base_url: String, token: String, http: reqwest::Client) -> Self {
+        Self { base_url, token, http }
+    }
+
+    /// 发送请求并解析 `data`；errCode 非 0 时返回 `OpenIMError::Api`
+    pub async fn post<Req, Resp>(&self, path: &str, req: &Req) -> Result<Resp, OpenIMError>
+    where
+        Req: Serialize + ?Sized,
+        Resp: DeserializeOwned + Default,
+    {
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        let resp: ApiResponse<Resp> = self
+            .http
+            .post(url)
+            .header("operationID", operation_id())
+            .header("token", &self.token)
+            .json(req)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        if resp.err_code != 0 {
+            return Err(OpenIMError::Api {
+                code: resp.err_code,
+                msg: resp.err_msg,
+                dlt: resp.err_dlt,
+            });
+        }
+        Ok(resp.data.unwrap_or_default())
+    }
+
+    /// 获取 version_id / version 之后的变化
+    pub(crate) async fn get_incremental<T>(
+        &self,
+        path: &str,
+        user_id: &str,
+        version_id: &str,
+        version: i64,
+    ) -> Result<Incremental<T>, OpenIMError>
+    where
+        T: DeserializeOwned + Default,
+    {
+        self.post(path, &IncrementalReq { user_id, version_id, version }).await
+    }
+}
+
+/// 生成 operationID：毫秒时间戳 + 进程内序号，便于在服务器日志中定位单次请求
+pub fn operation_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{}{:04}",
+        chrono::Utc::now().timestamp_millis(),
+        COUNTER.fetch_add(1, Ordering::Relaxed) % 10_000
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// 本地 HTTP 服务：按顺序对每个连接返回一个响应体，结束后返回收到的原始请求
+    pub(crate) async fn serve(bodies: &[&str]) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        let bodies: Vec<String> = bodies.iter().map(|b| b.to_string()).collect();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for body in bodies {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some(head_end) = text.find("\r\n\r\n") {
+                        let content_length = text[..head_end]
+                            .lines()
+                            .find_map(|l| {
+                                l.to_ascii_lowercase()
+                                    .strip_prefix("content-length:")
+                                    .map(|v| v.trim().parse::<usize>().unwrap())
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= head_end + 4 + content_length {
+                            break;
+                        }
+                    }
+                }
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+                requests.push(String::from_utf8(request).unwrap());
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+
+    #[tokio::test]
+    async fn post_sends_headers_and_maps_errors() {
+        let (base_url, server) =
+            serve(&[r#"{"errCode":1004,"errMsg":"RecordNotFoundError","errDlt":"user not found"}"#]).await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let err = api
+            .post::<_, Empty>("/user/get_users_info", &serde_json::json!({"userIDs": ["u1"]}))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, OpenIMError::Api { code: 1004, .. }));
+
+        let request = server.await.unwrap()[0].to_ascii_lowercase();
+        assert!(request.starts_with("post /user/get_users_info "));
+        assert!(request.contains("\r\ntoken: tok\r\n"));
+        assert!(request.contains("\r\noperationid: "));
+        assert!(request.ends_with(r#"{"userids":["u1"]}"#));
+    }
+}
@@ -0,0 +1,82 @@
+//! 消息扩展（表情回应）接口
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, Empty};
+use crate::openim::OpenIMError;
+
+/// 消息扩展字段（对应服务器 sdkws.KeyValue）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyValue {
+    #[serde(rename = "typeKey", default)]
+    pub type_key: String,
+    #[serde(default)]
+    pub value: String,
+    #[serde(rename = "latestUpdateTime", default)]
+    pub latest_update_time: i64,
+}
+
+#[derive(Serialize)]
+struct SetReactionExtensionsReq<'a> {
+    #[serde(rename = "conversationID")]
+    conversation_id: &'a str,
+    #[serde(rename = "clientMsgID")]
+    client_msg_id: &'a str,
+    #[serde(rename = "isReact")]
+    is_react: bool,
+    #[serde(rename = "isExternalExtensions")]
+    is_external_extensions: bool,
+    #[serde(rename = "reactionExtensions")]
+    reaction_extensions: HashMap<&'a str, &'a KeyValue>,
+}
+
+#[derive(Serialize)]
+struct DeleteReactionExtensionsReq<'a> {
+    #[serde(rename = "conversationID")]
+    conversation_id: &'a str,
+    #[serde(rename = "clientMsgID")]
+    client_msg_id: &'a str,
+    #[serde(rename = "isExternalExtensions")]
+    is_external_extensions: bool,
+    #[serde(rename = "reactionExtensions")]
+    reaction_extensions: &'a [KeyValue],
+}
+
+impl ApiClient {
+    /// 设置消息的扩展字段（已存在的键覆盖）
+    pub async fn set_message_reaction_extensions(
+        &self,
+        conversation_id: &str,
+        client_msg_id: &str,
+        extensions: &[KeyValue],
+    ) -> Result<(), OpenIMError> {
+        let req = SetReactionExtensionsReq {
+            conversation_id,
+            client_msg_id,
+            is_react: true,
+            is_external_extensions: false,
+            reaction_extensions: extensions.iter().map(|kv| (kv.type_key.as_str(), kv)).collect(),
+        };
+        let _: Empty = self.post("/msg/set_message_reaction_extensions", &req).await?;
+        Ok(())
+    }
+
+    /// 删除消息的扩展字段
+    pub async fn delete_message_reaction_extensions(
+        &self,
+        conversation_id: &str,
+        client_msg_id: &str,
+        extensions: &[KeyValue],
+    ) -> Result<(), OpenIMError> {
+        let req = DeleteReactionExtensionsReq {
+            conversation_id,
+            client_msg_id,
+            is_external_extensions: false,
+            reaction_extensions: extensions,
+        };
+        let _: Empty = self.post("/msg/delete_message_reaction_extensions", &req).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! 用户相关接口
+
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, Empty};
+use crate::openim::OpenIMError;
+
+/// 用户信息（对应服务器 sdkws.UserInfo）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserInfo {
+    #[serde(rename = "userID")]
+    pub user_id: String,
+    #[serde(default)]
+    pub nickname: String,
+    #[serde(rename = "faceURL", default)]
+    pub face_url: String,
+    #[serde(default)]
+    pub ex: String,
+    #[serde(rename = "createTime", default)]
+    pub create_time: i64,
+    #[serde(rename = "globalRecvMsgOpt", default)]
+    pub global_recv_msg_opt: i32,
+}
+
+#[derive(Serialize)]
+struct GetUsersInfoReq<'a> {
+    #[serde(rename = "userIDs")]
+    user_ids: &'a [String],
+}
+
+#[derive(Default, Deserialize)]
+struct GetUsersInfoResp {
+    #[serde(rename = "usersInfo", default)]
+    users_info: Vec<UserInfo>,
+}
+
+/// protobuf StringValue（服务器用于区分"不修改"和"修改为空"）
+#[derive(Serialize)]
+struct StringValue<'a> {
+    value: &'a str,
+}
+
+/// 只包含需要修改的字段
+#[derive(Serialize)]
+struct UserInfoWithEx<'a> {
+    #[serde(rename = "userID")]
+    user_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nickname: Option<StringValue<'a>>,
+    #[serde(rename = "faceURL", skip_serializing_if = "Option::is_none")]
+    face_url: Option<StringValue<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ex: Option<StringValue<'a>>,
+}
+
+#[derive(Serialize)]
+struct UpdateUserInfoReq<'a> {
+    #[serde(rename = "userInfo")]
+    user_info: UserInfoWithEx<'a>,
+}
+
+impl ApiClient {
+    /// 批量获取用户信息
+    pub async fn get_users_info(&self, user_ids: &[String]) -> Result<Vec<UserInfo>, OpenIMError> {
+        let resp: GetUsersInfoResp = self
+            .post("/user/get_users_info", &GetUsersInfoReq { user_ids })
+            .await?;
+        Ok(resp.users_info)
+    }
+
+    /// 修改用户资料，None 表示不修改
+    pub async fn update_user_info_ex(
+        &self,
+        user_id: &str,
+        nickname: Option<&str>,
+        face_url: Option<&str>,
+        ex: Option<&str>,
+    ) -> Result<(), OpenIMError> {
+        let user_info = UserInfoWithEx {
+            user_id,
+            nickname: nickname.map(|value| StringValue { value }),
+            face_url: face_url.map(|value| StringValue { value }),
+            ex: ex.map(|value| StringValue { value }),
+        };
+        let _: Empty = self
+            .post("/user/update_user_info_ex", &UpdateUserInfoReq { user_info })
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::serve;
+    use super::*;
+
+    #[tokio::test]
+    async fn get_users_info_decodes_data() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"errMsg":"","errDlt":"","data":{"total":1,"usersInfo":[{"userID":"u1","nickname":"张三","faceURL":"http://a/b.png","ex":"","createTime":1700000000000,"appMangerLevel":0,"globalRecvMsgOpt":0}]}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let users = api.get_users_info(&["u1".to_string()]).await.unwrap();
+        assert_eq!(users.len(), 1);
+        assert_eq!(users[0].nickname, "张三");
+        assert_eq!(users[0].face_url, "http://a/b.png");
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn update_user_info_sends_only_changed_fields() {
+        let (base_url, server) = serve(&[r#"{"errCode":0,"errMsg":"","errDlt":""}"#]).await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        api.update_user_info_ex("u1", Some("李四"), None, Some("")).await.unwrap();
+
+        let request = server.await.unwrap().remove(0);
+        assert!(request.ends_with(r#"{"userInfo":{"userID":"u1","nickname":{"value":"李四"},"ex":{"value":""}}}"#));
+    }
+}
@@ -0,0 +1,420 @@
+//! 本地消息全文搜索（SQLite FTS5）
+//!
+//! unicode61 分词器不会切分连续的中日韩文字，因此索引前先在 Rust 中处理：
+//! `uni` 列存放单字和单词，`bi` 列存放相邻两字组成的二元组和单词。
+//! 查询单个汉字时匹配 `uni` 列，多个汉字时按二元组短语匹配 `bi` 列，英文单词按前缀匹配。
+
+use rusqlite::params_from_iter;
+use rusqlite::types::Value;
+
+use super::content::searchable_text;
+use super::messages::{StoredMessage, MESSAGE_COLUMNS};
+use super::store::Store;
+use super::OpenIMError;
+
+/// 摘要中命中位置之前保留的字符数
+const SNIPPET_BEFORE: usize = 12;
+/// 摘要最大字符数
+const SNIPPET_MAX_CHARS: usize = 64;
+
+/// 搜索条件（关键词为空时只按其余条件过滤）
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    pub keyword: String,
+    pub conversation_id: Option<String>,
+    pub sender_id: Option<String>,
+    /// 为空表示不限类型
+    pub content_types: Vec<i32>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// 摘要中的命中区间（UTF-16 偏移，便于 Dart 直接使用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Highlight {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// 搜索结果
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub message: StoredMessage,
+    pub snippet: String,
+    pub highlights: Vec<Highlight>,
+}
+
+/// 一页搜索结果
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    pub hits: Vec<SearchHit>,
+    pub has_more: bool,
+}
+
+/// 分词结果
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    /// 非 CJK 的字母数字串（已转小写）
+    Word(String),
+    /// 连续的 CJK 文字
+    Cjk(Vec<char>),
+}
+
+impl Term {
+    fn chars(&self) -> Vec<char> {
+        match self {
+            Term::Word(w) => w.chars().collect(),
+            Term::Cjk(run) => run.clone(),
+        }
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF       // 平假名、片假名
+            | 0x3400..=0x4DBF // CJK 扩展 A
+            | 0x4E00..=0x9FFF // CJK 统一汉字
+            | 0xAC00..=0xD7AF // 韩文音节
+            | 0xF900..=0xFAFF // CJK 兼容汉字
+            | 0x20000..=0x2A6DF // CJK 扩展 B
+    )
+}
+
+/// 单个字符转小写（保持字符一一对应，便于在原文中定位）
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn terms(text: &str) -> Vec<Term> {
+    let mut terms = Vec::new();
+    let mut word = String::new();
+    let mut run = Vec::new();
+    for c in text.chars() {
+        if is_cjk(c) {
+            if !word.is_empty() {
+                terms.push(Term::Word(std::mem::take(&mut word)));
+            }
+            run.push(c);
+        } else {
+            if !run.is_empty() {
+                terms.push(Term::Cjk(std::mem::take(&mut run)));
+            }
+            if c.is_alphanumeric() {
+                word.push(fold(c));
+            } else if !word.is_empty() {
+                terms.push(Term::Word(std::mem::take(&mut word)));
+            }
+        }
+    }
+    if !word.is_empty() {
+        terms.push(Term::Word(word));
+    }
+    if !run.is_empty() {
+        terms.push(Term::Cjk(run));
+    }
+    terms
+}
+
+/// 生成索引列内容 (uni, bi)
+pub fn index_columns(text: &str) -> (String, String) {
+    let mut uni = Vec::new();
+    let mut bi = Vec::new();
+    for term in terms(text) {
+        match term {
+            Term::Word(w) => {
+                uni.push(w.clone());
+                bi.push(w);
+            }
+            Term::Cjk(run) => {
+                uni.extend(run.iter().map(|c| c.to_string()));
+                if run.len() == 1 {
+                    bi.push(run[0].to_string());
+                }
+                bi.extend(run.windows(2).map(|w| w.iter().collect::<String>()));
+            }
+        }
+    }
+    (uni.join(" "), bi.join(" "))
+}
+
+/// 生成 FTS5 查询表达式，关键词中没有可搜索内容时返回 None
+fn match_expression(keyword: &str) -> Option<String> {
+    let parts: Vec<String> = terms(keyword)
+        .into_iter()
+        .map(|term| match term {
+            Term::Word(w) => format!("\"{}\"*", w),
+            Term::Cjk(run) if run.len() == 1 => format!("uni : \"{}\"", run[0]),
+            Term::Cjk(run) => {
+                let bigrams: Vec<String> = run.windows(2).map(|w| w.iter().collect()).collect();
+                format!("bi : \"{}\"", bigrams.join(" "))
+            }
+        })
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(" AND "))
+    }
+}
+
+/// 搜索消息，按发送时间倒序分页
+pub fn search(store: &Store, query: &SearchQuery) -> Result<SearchPage, OpenIMError> {
+    let mut sql = format!("SELECT {} FROM messages m", prefixed_columns());
+    let mut conditions = Vec::new();
+    let mut args: Vec<Value> = Vec::new();
+
+    if !query.keyword.trim().is_empty() {
+        let Some(expr) = match_expression(&query.keyword) else {
+            return Ok(SearchPage { hits: Vec::new(), has_more: false });
+        };
+        sql.push_str(" JOIN messages_fts f ON f.rowid = m.id");
+        conditions.push("messages_fts MATCH ?".to_string());
+        args.push(Value::Text(expr));
+    }
+    if let Some(conversation_id) = &query.conversation_id {
+        conditions.push("m.conversation_id = ?".to_string());
+        args.push(Value::Text(conversation_id.clone()));
+    }
+    if let Some(sender_id) = &query.sender_id {
+        conditions.push("m.send_id = ?".to_string());
+        args.push(Value::Text(sender_id.clone()));
+    }
+    if !query.content_types.is_empty() {
+        let placeholders = vec!["?"; query.content_types.len()].join(", ");
+        conditions.push(format!("m.content_type IN ({})", placeholders));
+        args.extend(query.content_types.iter().map(|t| Value::Integer(*t as i64)));
+    }
+    if let Some(start_time) = query.start_time {
+        conditions.push("m.send_time >= ?".to_string());
+        args.push(Value::Integer(start_time));
+    }
+    if let Some(end_time) = query.end_time {
+        conditions.push("m.send_time <= ?".to_string());
+        args.push(Value::Integer(end_time));
+    }
+    if !conditions.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&conditions.join(" AND "));
+    }
+    // 多取一条用于判断是否还有下一页
+    sql.push_str(" ORDER BY m.send_time DESC, m.id DESC LIMIT ? OFFSET ?");
+    args.push(Value::Integer(query.limit as i64 + 1));
+    args.push(Value::Integer(query.offset as i64));
+
+    let mut messages = {
+        let conn = store.conn()?;
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(params_from_iter(args), StoredMessage::from_row)?;
+        rows.collect::<Result<Vec<_>, _>>()?
+    };
+    let has_more = messages.len() > query.limit as usize;
+    messages.truncate(query.limit as usize);
+
+    let hits = messages
+        .into_iter()
+        .map(|message| {
+            let text = searchable_text(message.content_type, &message.content).unwrap_or_default();
+            let (snippet, highlights) = snippet(&text, &query.keyword);
+            SearchHit { message, snippet, highlights }
+        })
+        .collect();
+    Ok(SearchPage { hits, has_more })
+}
+
+fn prefixed_columns() -> String {
+    MESSAGE_COLUMNS
+        .split(',')
+        .map(|c| format!("m.{}", c.trim()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 生成摘要：以第一个命中位置为中心截取原文，并标出所有命中区间
+pub fn snippet(text: &str, keyword: &str) -> (String, Vec<Highlight>) {
+    let chars: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = chars.iter().map(|c| fold(*c)).collect();
+
+    // 原文中的命中区间（字符下标）
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for needle in terms(keyword).iter().map(Term::chars) {
+        let mut i = 0;
+        while i + needle.len() <= folded.len() {
+            if folded[i..i + needle.len()] == needle[..] {
+                ranges.push((i, i + needle.len()));
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+    ranges.sort_unstable();
+    let mut merged: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let window_start = merged
+        .first()
+        .map_or(0, |(start, _)| start.saturating_sub(SNIPPET_BEFORE));
+    let window_end = (window_start + SNIPPET_MAX_CHARS).min(chars.len());
+
+    let mut snippet = String::new();
+    if window_start > 0 {
+        snippet.push('…');
+    }
+    // 窗口内每个字符在摘要中的 UTF-16 起始偏移
+    let mut offsets = Vec::with_capacity(window_end - window_start + 1);
+    let mut offset = snippet.encode_utf16().count() as u32;
+    for c in &chars[window_start..window_end] {
+        offsets.push(offset);
+        offset += c.len_utf16() as u32;
+        snippet.push(*c);
+    }
+    offsets.push(offset);
+    if window_end < chars.len() {
+        snippet.push('…');
+    }
+
+    let highlights = merged
+        .into_iter()
+        .filter(|(start, end)| *start >= window_start && *end <= window_end)
+        .map(|(start, end)| Highlight {
+            start: offsets[start - window_start],
+            end: offsets[end - window_start],
+        })
+        .collect();
+    (snippet, highlights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::messages::MessageStore;
+    use super::*;
+    use std::sync::Arc;
+
+    fn message(id: &str, conv: &str, sender: &str, content_type: i32, text: &str, time: i64) -> StoredMessage {
+        let key = if content_type == 101 { "content" } else { "text" };
+        StoredMessage {
+            client_msg_id: id.to_string(),
+            server_msg_id: String::new(),
+            conversation_id: conv.to_string(),
+            seq: 0,
+            send_id: sender.to_string(),
+            recv_id: String::new(),
+            group_id: String::new(),
+            session_type: 1,
+            content_type,
+            content: serde_json::to_vec(&serde_json::json!({ key: text })).unwrap(),
+            send_time: time,
+        }
+    }
+
+    fn store() -> MessageStore {
+        let store = MessageStore::new(Arc::new(Store::open_in_memory().unwrap()));
+        let messages = [
+            message("1", "si_a", "u1", 101, "明天一起去北京吃烤鸭吧", 1_000),
+            message("2", "si_a", "u2", 101, "好的，北京见！Rust meetup 也在那天", 2_000),
+            message("3", "si_b", "u2", 106, "@u1 北京的会议改到周五", 3_000),
+            message("4", "si_b", "u1", 101, "收到 rustacean", 4_000),
+            message("5", "si_c", "u3", 101, "东京下雨了", 5_000),
+        ];
+        for m in &messages {
+            assert!(store.save(m).unwrap());
+        }
+        store
+    }
+
+    fn ids(page: &SearchPage) -> Vec<&str> {
+        page.hits.iter().map(|h| h.message.client_msg_id.as_str()).collect()
+    }
+
+    fn query(keyword: &str) -> SearchQuery {
+        SearchQuery { keyword: keyword.to_string(), limit: 20, ..Default::default() }
+    }
+
+    #[test]
+    fn index_splits_cjk_into_bigrams() {
+        let (uni, bi) = index_columns("去北京 Rust!");
+        assert_eq!(uni, "去 北 京 rust");
+        assert_eq!(bi, "去北 北京 rust");
+        assert_eq!(match_expression("北京 rust"), Some("bi : \"北京\" AND \"rust\"*".to_string()));
+        assert_eq!(match_expression("！？"), None);
+    }
+
+    /// 中文按短语匹配（不会把“北京”匹配到“东京”），单字和英文前缀同样可搜
+    #[test]
+    fn search_cjk_and_prefix() {
+        let store = store();
+        assert_eq!(ids(&store.search(&query("北京")).unwrap()), vec!["3", "2", "1"]);
+        assert_eq!(ids(&store.search(&query("京")).unwrap()), vec!["5", "3", "2", "1"]);
+        assert_eq!(ids(&store.search(&query("一起去北京")).unwrap()), vec!["1"]);
+        assert_eq!(ids(&store.search(&query("RUST")).unwrap()), vec!["4", "2"]);
+        assert_eq!(ids(&store.search(&query("北京 rust")).unwrap()), vec!["2"]);
+        assert!(store.search(&query("上海")).unwrap().hits.is_empty());
+    }
+
+    #[test]
+    fn search_filters_and_pagination() {
+        let store = store();
+        let q = SearchQuery { sender_id: Some("u2".into()), ..query("北京") };
+        assert_eq!(ids(&store.search(&q).unwrap()), vec!["3", "2"]);
+
+        let q = SearchQuery { conversation_id: Some("si_a".into()), ..query("北京") };
+        assert_eq!(ids(&store.search(&q).unwrap()), vec!["2", "1"]);
+
+        let q = SearchQuery { content_types: vec![106], ..query("北京") };
+        assert_eq!(ids(&store.search(&q).unwrap()), vec!["3"]);
+
+        let q = SearchQuery { start_time: Some(1_500), end_time: Some(4_000), ..query("") };
+        assert_eq!(ids(&store.search(&q).unwrap()), vec!["4", "3", "2"]);
+
+        let first = store.search(&SearchQuery { limit: 2, ..query("北京") }).unwrap();
+        assert_eq!((ids(&first), first.has_more), (vec!["3", "2"], true));
+        let second = store.search(&SearchQuery { limit: 2, offset: 2, ..query("北京") }).unwrap();
+        assert_eq!((ids(&second), second.has_more), (vec!["1"], false));
+    }
+
+    /// 全文索引按消息 id 关联，删除消息并 VACUUM 后仍对应到正确的消息
+    #[test]
+    fn search_after_delete_and_vacuum() {
+        let db = Arc::new(Store::open_in_memory().unwrap());
+        let store = MessageStore::new(db.clone());
+        store.save(&message("a", "si_a", "u1", 101, "北京", 1_000)).unwrap();
+        store.save(&message("b", "si_b", "u1", 101, "东京", 2_000)).unwrap();
+        store.save(&message("c", "si_b", "u2", 101, "上海", 3_000)).unwrap();
+        store.clear_conversation("si_a").unwrap();
+        db.conn().unwrap().execute_batch("VACUUM").unwrap();
+
+        assert!(store.search(&query("北京")).unwrap().hits.is_empty());
+        assert_eq!(ids(&store.search(&query("东京")).unwrap()), vec!["b"]);
+        assert_eq!(ids(&store.search(&query("上海")).unwrap()), vec!["c"]);
+        assert!(store.save(&message("d", "si_b", "u2", 101, "广州", 4_000)).unwrap());
+        assert_eq!(ids(&store.search(&query("广州")).unwrap()), vec!["d"]);
+    }
+
+    /// 命中区间为 UTF-16 偏移；长文本从命中位置附近截取
+    #[test]
+    fn snippet_highlights() {
+        let (text, highlights) = snippet("好的，北京见！Rust meetup", "北京 rust");
+        assert_eq!(text, "好的，北京见！Rust meetup");
+        assert_eq!(highlights, vec![Highlight { start: 3, end: 5 }, Highlight { start: 7, end: 11 }]);
+
+        let long = format!("{}北京{}", "啊".repeat(40), "哦".repeat(80));
+        let (text, highlights) = snippet(&long, "北京");
+        assert!(text.starts_with('…') && text.ends_with('…'));
+        let h = highlights[0];
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert_eq!(String::from_utf16(&utf16[h.start as usize..h.end as usize]).unwrap(), "北京");
+
+        let (text, highlights) = snippet("😀 emoji 北京", "北京");
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        let h = highlights[0];
+        assert_eq!(String::from_utf16(&utf16[h.start as usize..h.end as usize]).unwrap(), "北京");
+    }
+}
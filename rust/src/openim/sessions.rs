@@ -0,0 +1,176 @@
+//! 多账号会话管理
+//!
+//! 同一进程中可以同时登录多个账号，每个会话有独立的客户端实例（数据目录、连接和事件流），
+//! 通过会话 ID 访问。其中一个会话为当前会话，用于账号切换。
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use tokio::sync::broadcast;
+
+use super::OpenIMError;
+
+/// 会话 ID（同一账号在不同平台登录视为不同会话）
+pub fn session_id(user_id: &str, platform_id: i32) -> String {
+    format!("{}_{}", user_id, platform_id)
+}
+
+/// 会话变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEvent {
+    Opened(String),
+    Closed(String),
+    /// 当前会话切换，None 表示已没有会话
+    Activated(Option<String>),
+}
+
+struct Inner<T> {
+    sessions: BTreeMap<String, T>,
+    active: Option<String>,
+}
+
+/// 会话表
+pub struct SessionRegistry<T> {
+    inner: Mutex<Inner<T>>,
+    events: broadcast::Sender<SessionEvent>,
+}
+
+impl<T: Clone> Default for SessionRegistry<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> SessionRegistry<T> {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self {
+            inner: Mutex::new(Inner { sessions: BTreeMap::new(), active: None }),
+            events,
+        }
+    }
+
+    /// 订阅会话变化
+    pub fn subscribe(&self) -> broadcast::Receiver<SessionEvent> {
+        self.events.subscribe()
+    }
+
+    fn notify(&self, event: SessionEvent) {
+        let _ = self.events.send(event);
+    }
+
+    /// 添加会话，没有当前会话时自动成为当前会话
+    pub fn insert(&self, session_id: &str, session: T) -> Result<(), OpenIMError> {
+        let activated = {
+            let mut inner = self.inner.lock().unwrap();
+            if inner.sessions.contains_key(session_id) {
+                return Err(OpenIMError::SessionExists(session_id.to_string()));
+            }
+            inner.sessions.insert(session_id.to_string(), session);
+            let activated = inner.active.is_none();
+            if activated {
+                inner.active = Some(session_id.to_string());
+            }
+            activated
+        };
+        self.notify(SessionEvent::Opened(session_id.to_string()));
+        if activated {
+            self.notify(SessionEvent::Activated(Some(session_id.to_string())));
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, session_id: &str) -> Option<T> {
+        self.inner.lock().unwrap().sessions.get(session_id).cloned()
+    }
+
+    /// 全部会话 ID
+    pub fn ids(&self) -> Vec<String> {
+        self.inner.lock().unwrap().sessions.keys().cloned().collect()
+    }
+
+    /// 移除会话，移除的是当前会话时切换到剩余的第一个会话
+    pub fn remove(&self, session_id: &str) -> Option<T> {
+        let (session, activated) = {
+            let mut inner = self.inner.lock().unwrap();
+            let session = inner.sessions.remove(session_id)?;
+            let mut activated = None;
+            if inner.active.as_deref() == Some(session_id) {
+                inner.active = inner.sessions.keys().next().cloned();
+                activated = Some(inner.active.clone());
+            }
+            (session, activated)
+        };
+        self.notify(SessionEvent::Closed(session_id.to_string()));
+        if let Some(active) = activated {
+            self.notify(SessionEvent::Activated(active));
+        }
+        Some(session)
+    }
+
+    /// 切换当前会话
+    pub fn set_active(&self, session_id: &str) -> Result<(), OpenIMError> {
+        {
+            let mut inner = self.inner.lock().unwrap();
+            if !inner.sessions.contains_key(session_id) {
+                return Err(OpenIMError::NotFound(format!("会话 {}", session_id)));
+            }
+            if inner.active.as_deref() == Some(session_id) {
+                return Ok(());
+            }
+            inner.active = Some(session_id.to_string());
+        }
+        self.notify(SessionEvent::Activated(Some(session_id.to_string())));
+        Ok(())
+    }
+
+    /// 当前会话
+    pub fn active(&self) -> Option<(String, T)> {
+        let inner = self.inner.lock().unwrap();
+        let id = inner.active.clone()?;
+        let session = inner.sessions.get(&id)?.clone();
+        Some((id, session))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_switch_and_remove() {
+        let registry = SessionRegistry::new();
+        let mut events = registry.subscribe();
+        registry.insert("u1_5", 1).unwrap();
+        registry.insert("u2_5", 2).unwrap();
+        assert!(matches!(registry.insert("u1_5", 3), Err(OpenIMError::SessionExists(_))));
+        assert_eq!(registry.active(), Some(("u1_5".to_string(), 1)));
+        assert_eq!(registry.ids(), ["u1_5", "u2_5"]);
+
+        registry.set_active("u2_5").unwrap();
+        assert!(registry.set_active("u3_5").is_err());
+        assert_eq!(registry.remove("u2_5"), Some(2));
+        assert_eq!(registry.active(), Some(("u1_5".to_string(), 1)));
+        assert_eq!(registry.remove("u1_5"), Some(1));
+        assert_eq!(registry.active(), None);
+
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            received.push(event);
+        }
+        let id = |s: &str| s.to_string();
+        assert_eq!(
+            received,
+            [
+                SessionEvent::Opened(id("u1_5")),
+                SessionEvent::Activated(Some(id("u1_5"))),
+                SessionEvent::Opened(id("u2_5")),
+                SessionEvent::Activated(Some(id("u2_5"))),
+                SessionEvent::Closed(id("u2_5")),
+                SessionEvent::Activated(Some(id("u1_5"))),
+                SessionEvent::Closed(id("u1_5")),
+                SessionEvent::Activated(None),
+            ]
+        );
+    }
+}
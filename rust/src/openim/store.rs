@@ -0,0 +1,225 @@
+//! 本地存储（SQLite）
+
+use std::ops::{Deref, DerefMut};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+
+use rusqlite::Connection;
+
+use super::OpenIMError;
+
+/// 数据库迁移脚本，按顺序执行；已执行的版本记录在 `PRAGMA user_version` 中
+const MIGRATIONS: &[&str] = &[
+    // 1: 发件箱（clientMsgID 入队时生成，重试时复用）
+    "CREATE TABLE outbox (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        client_msg_id TEXT NOT NULL,
+        conversation_id TEXT NOT NULL,
+        recv_id TEXT NOT NULL,
+        group_id TEXT NOT NULL,
+        session_type INTEGER NOT NULL,
+        content_type INTEGER NOT NULL,
+        content BLOB NOT NULL,
+        status INTEGER NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        next_attempt_at INTEGER NOT NULL,
+        last_error TEXT NOT NULL DEFAULT '',
+        server_msg_id TEXT NOT NULL DEFAULT '',
+        send_time INTEGER NOT NULL DEFAULT 0,
+        created_at INTEGER NOT NULL
+    );
+    CREATE INDEX idx_outbox_status ON outbox(status, conversation_id, id);
+    CREATE UNIQUE INDEX idx_outbox_client_msg_id ON outbox(client_msg_id);",
+    // 2: 消息及全文索引（全文索引的 rowid 为消息 id；uni 为单字 + 单词，bi 为 CJK 二元组 + 单词，见 search 模块）
+    "CREATE TABLE messages (
+        id INTEGER PRIMARY KEY,
+        client_msg_id TEXT NOT NULL,
+        server_msg_id TEXT NOT NULL DEFAULT '',
+        conversation_id TEXT NOT NULL,
+        seq INTEGER NOT NULL DEFAULT 0,
+        send_id TEXT NOT NULL,
+        recv_id TEXT NOT NULL,
+        group_id TEXT NOT NULL,
+        session_type INTEGER NOT NULL,
+        content_type INTEGER NOT NULL,
+        content BLOB NOT NULL,
+        send_time INTEGER NOT NULL
+    );
+    CREATE UNIQUE INDEX idx_messages_client_msg_id ON messages(client_msg_id);
+    CREATE INDEX idx_messages_conversation ON messages(conversation_id, seq);
+    CREATE INDEX idx_messages_send_time ON messages(send_time);
+    CREATE VIRTUAL TABLE messages_fts USING fts5(uni, bi, tokenize = 'unicode61 remove_diacritics 2');",
+    // 3: 联系人缓存（好友、黑名单、好友申请）
+    "CREATE TABLE friends (
+        user_id TEXT PRIMARY KEY,
+        nickname TEXT NOT NULL,
+        face_url TEXT NOT NULL,
+        remark TEXT NOT NULL,
+        ex TEXT NOT NULL,
+        create_time INTEGER NOT NULL
+    );
+    CREATE TABLE blacklist (
+        user_id TEXT PRIMARY KEY,
+        nickname TEXT NOT NULL,
+        face_url TEXT NOT NULL,
+        create_time INTEGER NOT NULL
+    );
+    CREATE TABLE friend_requests (
+        from_user_id TEXT NOT NULL,
+        to_user_id TEXT NOT NULL,
+        from_nickname TEXT NOT NULL,
+        from_face_url TEXT NOT NULL,
+        to_nickname TEXT NOT NULL,
+        to_face_url TEXT NOT NULL,
+        handle_result INTEGER NOT NULL,
+        req_msg TEXT NOT NULL,
+        handle_msg TEXT NOT NULL,
+        create_time INTEGER NOT NULL,
+        handle_time INTEGER NOT NULL,
+        PRIMARY KEY (from_user_id, to_user_id)
+    );",
+    // 4: 群组缓存
+    "CREATE TABLE groups (
+        group_id TEXT PRIMARY KEY,
+        group_name TEXT NOT NULL,
+        notification TEXT NOT NULL,
+        introduction TEXT NOT NULL,
+        face_url TEXT NOT NULL,
+        owner_user_id TEXT NOT NULL,
+        creator_user_id TEXT NOT NULL,
+        member_count INTEGER NOT NULL,
+        status INTEGER NOT NULL,
+        group_type INTEGER NOT NULL,
+        need_verification INTEGER NOT NULL,
+        ex TEXT NOT NULL,
+        create_time INTEGER NOT NULL
+    );
+    CREATE TABLE group_members (
+        group_id TEXT NOT NULL,
+        user_id TEXT NOT NULL,
+        nickname TEXT NOT NULL,
+        face_url TEXT NOT NULL,
+        role_level INTEGER NOT NULL,
+        join_time INTEGER NOT NULL,
+        join_source INTEGER NOT NULL,
+        inviter_user_id TEXT NOT NULL,
+        mute_end_time INTEGER NOT NULL,
+        ex TEXT NOT NULL,
+        PRIMARY KEY (group_id, user_id)
+    );",
+    // 5: 用户资料缓存（fetched_at 为 0 表示需要重新获取）
+    "CREATE TABLE users (
+        user_id TEXT PRIMARY KEY,
+        nickname TEXT NOT NULL,
+        face_url TEXT NOT NULL,
+        ex TEXT NOT NULL,
+        create_time INTEGER NOT NULL,
+        global_recv_msg_opt INTEGER NOT NULL,
+        fetched_at INTEGER NOT NULL
+    );",
+    // 6: 会话（置顶、免打扰与服务器同步，草稿和隐藏只保存在本地）
+    "CREATE TABLE conversations (
+        conversation_id TEXT PRIMARY KEY,
+        conversation_type INTEGER NOT NULL,
+        user_id TEXT NOT NULL,
+        group_id TEXT NOT NULL,
+        recv_msg_opt INTEGER NOT NULL DEFAULT 0,
+        is_pinned INTEGER NOT NULL DEFAULT 0,
+        draft_text TEXT NOT NULL DEFAULT '',
+        draft_text_time INTEGER NOT NULL DEFAULT 0,
+        hidden INTEGER NOT NULL DEFAULT 0,
+        latest_msg_time INTEGER NOT NULL DEFAULT 0,
+        ex TEXT NOT NULL DEFAULT ''
+    );",
+    // 7: 增量同步的版本号（好友、已加入的群、会话）
+    "CREATE TABLE sync_versions (
+        name TEXT PRIMARY KEY,
+        version_id TEXT NOT NULL,
+        version INTEGER NOT NULL
+    );",
+    // 8: 消息的表情回应（每个用户的每个表情一行，type_key 为服务器扩展字段的键）
+    "CREATE TABLE message_reactions (
+        client_msg_id TEXT NOT NULL,
+        type_key TEXT NOT NULL,
+        emoji TEXT NOT NULL,
+        user_id TEXT NOT NULL,
+        update_time INTEGER NOT NULL DEFAULT 0,
+        PRIMARY KEY (client_msg_id, type_key)
+    );",
+    // 9: 不作为消息保存的 seq（表情回应变化），判断本地缺失的消息时视为已有
+    "CREATE TABLE skipped_seqs (
+        conversation_id TEXT NOT NULL,
+        seq INTEGER NOT NULL,
+        PRIMARY KEY (conversation_id, seq)
+    );",
+];
+
+/// 本地数据库
+pub struct Store {
+    /// 关闭后为 None
+    conn: Mutex<Option<Connection>>,
+}
+
+/// 数据库连接（持有锁）
+pub(crate) struct Conn<'a>(MutexGuard<'a, Option<Connection>>);
+
+impl Deref for Conn<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        // 构造时已检查
+        self.0.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for Conn<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.0.as_mut().unwrap()
+    }
+}
+
+impl Store {
+    /// 打开（或创建）数据库文件
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, OpenIMError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(path)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        Self::init(conn)
+    }
+
+    /// 内存数据库（未指定数据目录时使用，进程退出即丢失）
+    pub fn open_in_memory() -> Result<Self, OpenIMError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut conn: Connection) -> Result<Self, OpenIMError> {
+        let version: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+        for (i, sql) in MIGRATIONS.iter().enumerate().skip(version) {
+            let tx = conn.transaction()?;
+            tx.execute_batch(sql)?;
+            tx.pragma_update(None, "user_version", i + 1)?;
+            tx.commit()?;
+        }
+        Ok(Self { conn: Mutex::new(Some(conn)) })
+    }
+
+    /// 获取数据库连接（注意不要跨 await 持有），关闭后返回 [`OpenIMError::StoreClosed`]
+    pub(crate) fn conn(&self) -> Result<Conn<'_>, OpenIMError> {
+        let guard = self.conn.lock().unwrap();
+        if guard.is_none() {
+            return Err(OpenIMError::StoreClosed);
+        }
+        Ok(Conn(guard))
+    }
+
+    /// 关闭数据库（退出登录时），之后的读写都返回错误
+    pub fn close(&self) -> Result<(), OpenIMError> {
+        if let Some(conn) = self.conn.lock().unwrap().take() {
+            conn.close().map_err(|(_, e)| e)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! 登录和重连后的同步
+//!
+//! 连接建立后依次同步自己的资料、联系人、已加入的群和会话设置，最后按服务器的最大 seq 补齐离线期间缺失的消息。
+//! 好友、群和会话优先使用服务器的增量接口，版本号保存在本地；服务器不支持时拉取完整列表。
+//! 进度按阶段折算为整体百分比，通过 [`SyncEvent`] 广播。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::sync::broadcast;
+
+use super::OpenIMError;
+
+/// 每个会话同步时最多拉取的消息数，更早的消息在查看历史时再拉取
+pub const SYNC_MSG_LIMIT: i64 = 100;
+
+/// 增量同步版本号的名称
+pub mod version_key {
+    pub const FRIENDS: &str = "friends";
+    pub const JOINED_GROUPS: &str = "joined_groups";
+    pub const CONVERSATIONS: &str = "conversations";
+}
+
+/// 同步阶段（按执行顺序）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncPhase {
+    SelfInfo,
+    /// 好友、黑名单和好友申请
+    Friends,
+    Groups,
+    Conversations,
+    /// 补齐缺失的消息
+    Messages,
+}
+
+impl SyncPhase {
+    pub const ALL: [SyncPhase; 5] = [
+        SyncPhase::SelfInfo,
+        SyncPhase::Friends,
+        SyncPhase::Groups,
+        SyncPhase::Conversations,
+        SyncPhase::Messages,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|p| *p == self).unwrap()
+    }
+}
+
+/// 同步进度
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyncEvent {
+    /// 正在执行 phase，percent 为整体进度（0-100）
+    Progress { phase: SyncPhase, percent: u8 },
+    /// 同步结束，failed 为失败的阶段（下次连接时重试）
+    Finished { failed: Vec<SyncPhase> },
+    /// 连接断开，同步中断（重连后重新同步）
+    Interrupted,
+}
+
+/// phase 完成 done / total 时的整体进度
+pub fn overall_percent(phase: SyncPhase, done: usize, total: usize) -> u8 {
+    let total = total.max(1);
+    let done = done.min(total);
+    ((phase.index() * total + done) * 100 / (SyncPhase::ALL.len() * total)) as u8
+}
+
+/// 同步进度广播，保留最近一次的状态供新的订阅者读取
+pub struct SyncProgress {
+    last: Mutex<Option<SyncEvent>>,
+    events: broadcast::Sender<SyncEvent>,
+}
+
+impl Default for SyncProgress {
+    fn default() -> Self {
+        Self { last: Mutex::new(None), events: broadcast::channel(64).0 }
+    }
+}
+
+impl SyncProgress {
+    pub fn subscribe(&self) -> broadcast::Receiver<SyncEvent> {
+        self.events.subscribe()
+    }
+
+    /// 最近一次的状态，尚未同步过时为 None
+    pub fn current(&self) -> Option<SyncEvent> {
+        self.last.lock().unwrap().clone()
+    }
+
+    /// 是否正在同步
+    pub fn is_running(&self) -> bool {
+        matches!(*self.last.lock().unwrap(), Some(SyncEvent::Progress { .. }))
+    }
+
+    fn emit(&self, event: SyncEvent) {
+        let mut last = self.last.lock().unwrap();
+        // 百分比没有变化时不重复通知
+        if last.as_ref() == Some(&event) {
+            return;
+        }
+        *last = Some(event.clone());
+        let _ = self.events.send(event);
+    }
+
+    /// phase 完成了 done / total
+    pub fn report(&self, phase: SyncPhase, done: usize, total: usize) {
+        self.emit(SyncEvent::Progress { phase, percent: overall_percent(phase, done, total) });
+    }
+
+    pub fn finish(&self, failed: Vec<SyncPhase>) {
+        self.emit(SyncEvent::Finished { failed });
+    }
+
+    /// 连接断开时调用，只在同步进行中时通知
+    pub fn interrupt(&self) {
+        if self.is_running() {
+            self.emit(SyncEvent::Interrupted);
+        }
+    }
+}
+
+/// 增量同步的版本号（首次同步时为空）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncVersion {
+    pub version_id: String,
+    pub version: i64,
+}
+
+/// 读取保存的版本号
+pub(crate) fn load_version(conn: &Connection, name: &str) -> Result<SyncVersion, OpenIMError> {
+    let version = conn
+        .query_row(
+            "SELECT version_id, version FROM sync_versions WHERE name = ?1",
+            params![name],
+            |row| Ok(SyncVersion { version_id: row.get(0)?, version: row.get(1)? }),
+        )
+        .optional()?;
+    Ok(version.unwrap_or_default())
+}
+
+/// 保存版本号（与同步的数据在同一事务中写入）
+pub(crate) fn save_version(conn: &Connection, name: &str, version: &SyncVersion) -> Result<(), OpenIMError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO sync_versions (name, version_id, version) VALUES (?1, ?2, ?3)",
+        params![name, version.version_id, version.version],
+    )?;
+    Ok(())
+}
+
+/// 需要从服务器拉取的 seq 区间 [begin, end]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeqGap {
+    pub conversation_id: String,
+    pub begin: i64,
+    pub end: i64,
+}
+
+/// 比较服务器与本地的最大 seq，得出每个会话缺失的区间（按会话 ID 排序）
+///
+/// 每个会话最多拉取最新的 limit 条；通知会话（`n_` 前缀）不拉取，联系人、群和会话已在前面的阶段同步。
+pub fn missing_ranges(
+    server_max: &HashMap<String, i64>,
+    server_min: &HashMap<String, i64>,
+    local_max: &HashMap<String, i64>,
+    limit: i64,
+) -> Vec<SeqGap> {
+    let mut gaps: Vec<SeqGap> = server_max
+        .iter()
+        .filter(|(id, _)| !id.starts_with("n_"))
+        .filter_map(|(id, &max)| {
+            let local = local_max.get(id).copied().unwrap_or(0);
+            let min = server_min.get(id).copied().unwrap_or(1).max(1);
+            let begin = (local + 1).max(max - limit + 1).max(min);
+            (begin <= max).then(|| SeqGap { conversation_id: id.clone(), begin, end: max })
+        })
+        .collect();
+    gaps.sort_by(|a, b| a.conversation_id.cmp(&b.conversation_id));
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openim::store::Store;
+
+    #[test]
+    fn progress_is_spread_over_phases() {
+        assert_eq!(overall_percent(SyncPhase::SelfInfo, 0, 1), 0);
+        assert_eq!(overall_percent(SyncPhase::Friends, 1, 1), 40);
+        assert_eq!(overall_percent(SyncPhase::Messages, 1, 4), 85);
+        assert_eq!(overall_percent(SyncPhase::Messages, 0, 0), 80);
+        assert_eq!(overall_percent(SyncPhase::Messages, 4, 4), 100);
+
+        let progress = SyncProgress::default();
+        let mut events = progress.subscribe();
+        progress.interrupt();
+        progress.report(SyncPhase::SelfInfo, 0, 1);
+        progress.report(SyncPhase::SelfInfo, 0, 1);
+        assert!(progress.is_running());
+        progress.interrupt();
+        progress.report(SyncPhase::SelfInfo, 0, 1);
+        progress.finish(vec![SyncPhase::Groups]);
+        let seen: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+        assert_eq!(
+            seen,
+            [
+                SyncEvent::Progress { phase: SyncPhase::SelfInfo, percent: 0 },
+                SyncEvent::Interrupted,
+                SyncEvent::Progress { phase: SyncPhase::SelfInfo, percent: 0 },
+                SyncEvent::Finished { failed: vec![SyncPhase::Groups] },
+            ]
+        );
+        assert!(!progress.is_running());
+    }
+
+    #[test]
+    fn gaps_start_after_local_max_and_respect_limit() {
+        let map = |pairs: &[(&str, i64)]| -> HashMap<String, i64> {
+            pairs.iter().map(|(id, seq)| (id.to_string(), *seq)).collect()
+        };
+        let server_max = map(&[("si_a_b", 10), ("sg_g1", 500), ("si_a_c", 3), ("n_a", 7), ("si_a_d", 8)]);
+        let server_min = map(&[("si_a_d", 6)]);
+        let local_max = map(&[("si_a_b", 7), ("si_a_c", 3)]);
+        assert_eq!(
+            missing_ranges(&server_max, &server_min, &local_max, 100),
+            [
+                SeqGap { conversation_id: "sg_g1".to_string(), begin: 401, end: 500 },
+                SeqGap { conversation_id: "si_a_b".to_string(), begin: 8, end: 10 },
+                SeqGap { conversation_id: "si_a_d".to_string(), begin: 6, end: 8 },
+            ]
+        );
+    }
+
+    #[test]
+    fn versions_round_trip() {
+        let store = Store::open_in_memory().unwrap();
+        let conn = store.conn().unwrap();
+        assert_eq!(load_version(&conn, version_key::FRIENDS).unwrap(), SyncVersion::default());
+        let version = SyncVersion { version_id: "v1".to_string(), version: 3 };
+        save_version(&conn, version_key::FRIENDS, &version).unwrap();
+        assert_eq!(load_version(&conn, version_key::FRIENDS).unwrap(), version);
+        assert_eq!(load_version(&conn, version_key::CONVERSATIONS).unwrap(), SyncVersion::default());
+    }
+}
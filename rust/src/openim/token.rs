@@ -0,0 +1,236 @@
+//! token 过期检测与刷新
+//!
+//! 连接前和连接期间根据 JWT 的 `exp` 提前刷新 token，刷新方式由 [`TokenRefresher`] 提供
+//! （Dart 回调，或使用登录凭据重新登录）。
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use tokio::sync::broadcast;
+
+use super::auth::{parse_jwt_claims, Authenticator, Credentials};
+use super::OpenIMError;
+
+/// 过期前多久开始刷新（秒）
+pub const REFRESH_AHEAD_SECS: i64 = 300;
+/// 刷新失败后的重试间隔（秒）
+pub const REFRESH_RETRY_SECS: u64 = 30;
+
+/// 服务器返回的 token 错误码
+pub mod token_err {
+    pub const EXPIRED: i32 = 1501;
+    pub const INVALID: i32 = 1502;
+    pub const MALFORMED: i32 = 1503;
+    pub const NOT_VALID_YET: i32 = 1504;
+    pub const UNKNOWN: i32 = 1505;
+    pub const KICKED: i32 = 1506;
+    pub const NOT_EXIST: i32 = 1507;
+}
+
+/// 是否为 token 相关的错误码（包括被踢下线）
+pub fn is_token_error(code: i32) -> bool {
+    (token_err::EXPIRED..=token_err::NOT_EXIST).contains(&code)
+}
+
+/// 是否可以通过刷新 token 解决（被踢下线时不应自动换 token 重连）
+pub fn is_refreshable_error(code: i32) -> bool {
+    is_token_error(code) && code != token_err::KICKED
+}
+
+/// token 刷新回调，返回新的 token
+pub trait TokenRefresher: Send + Sync {
+    fn refresh(&self) -> BoxFuture<'static, Result<String, OpenIMError>>;
+}
+
+/// 使用登录凭据重新登录获取 token
+pub struct CredentialsRefresher {
+    authenticator: Arc<Authenticator>,
+    credentials: Credentials,
+    platform_id: i32,
+}
+
+impl CredentialsRefresher {
+    pub fn new(authenticator: Arc<Authenticator>, credentials: Credentials, platform_id: i32) -> Self {
+        Self { authenticator, credentials, platform_id }
+    }
+}
+
+impl TokenRefresher for CredentialsRefresher {
+    fn refresh(&self) -> BoxFuture<'static, Result<String, OpenIMError>> {
+        let authenticator = self.authenticator.clone();
+        let credentials = self.credentials.clone();
+        let platform_id = self.platform_id;
+        Box::pin(async move { Ok(authenticator.login(&credentials, platform_id).await?.token) })
+    }
+}
+
+/// token 状态变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenEvent {
+    /// 即将过期（每个 token 只通知一次）
+    WillExpire { expires_at: i64 },
+    /// 已换成新的 token
+    Refreshed { expires_at: i64 },
+    RefreshFailed { error: String },
+}
+
+struct TokenState {
+    token: String,
+    /// 过期时间（秒），token 不是 JWT 时为 None
+    expires_at: Option<i64>,
+    warned: bool,
+}
+
+impl TokenState {
+    fn new(token: String) -> Self {
+        let expires_at = parse_jwt_claims(&token).ok().map(|c| c.exp);
+        Self { token, expires_at, warned: false }
+    }
+}
+
+/// 当前 token 及其刷新
+pub struct TokenManager {
+    state: RwLock<TokenState>,
+    refresher: RwLock<Option<Arc<dyn TokenRefresher>>>,
+    events: broadcast::Sender<TokenEvent>,
+}
+
+impl TokenManager {
+    pub fn new(token: String) -> Self {
+        let (events, _) = broadcast::channel(16);
+        Self {
+            state: RwLock::new(TokenState::new(token)),
+            refresher: RwLock::new(None),
+            events,
+        }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<TokenEvent> {
+        self.events.subscribe()
+    }
+
+    pub fn token(&self) -> String {
+        self.state.read().unwrap().token.clone()
+    }
+
+    pub fn expires_at(&self) -> Option<i64> {
+        self.state.read().unwrap().expires_at
+    }
+
+    pub fn set_refresher(&self, refresher: Arc<dyn TokenRefresher>) {
+        *self.refresher.write().unwrap() = Some(refresher);
+    }
+
+    pub fn has_refresher(&self) -> bool {
+        self.refresher.read().unwrap().is_some()
+    }
+
+    pub fn is_expired(&self, now_secs: i64) -> bool {
+        self.expires_at().is_some_and(|exp| exp <= now_secs)
+    }
+
+    /// 距离需要刷新还有多久，token 不是 JWT 时为 None
+    pub fn refresh_delay(&self, now_secs: i64) -> Option<Duration> {
+        let exp = self.expires_at()?;
+        Some(Duration::from_secs((exp - REFRESH_AHEAD_SECS - now_secs).max(0) as u64))
+    }
+
+    /// 通知 token 即将过期，同一个 token 只通知一次
+    pub fn warn_expiring(&self) {
+        let mut state = self.state.write().unwrap();
+        if state.warned {
+            return;
+        }
+        state.warned = true;
+        if let Some(expires_at) = state.expires_at {
+            tracing::warn!(expires_at, "token 即将过期");
+            let _ = self.events.send(TokenEvent::WillExpire { expires_at });
+        }
+    }
+
+    /// 调用刷新回调换成新的 token
+    pub async fn refresh(&self) -> Result<(), OpenIMError> {
+        let refresher = self.refresher.read().unwrap().clone();
+        let result = match refresher {
+            Some(refresher) => refresher.refresh().await,
+            None => Err(OpenIMError::InvalidToken("未设置 token 刷新回调".to_string())),
+        };
+        match result {
+            Ok(token) => {
+                let state = TokenState::new(token);
+                let expires_at = state.expires_at.unwrap_or_default();
+                *self.state.write().unwrap() = state;
+                tracing::info!(expires_at, "token 已刷新");
+                let _ = self.events.send(TokenEvent::Refreshed { expires_at });
+                Ok(())
+            }
+            Err(e) => {
+                let _ = self.events.send(TokenEvent::RefreshFailed { error: e.to_string() });
+                Err(e)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+
+    use super::*;
+
+    fn jwt(exp: i64) -> String {
+        let payload = format!(r#"{{"UserID":"u1","PlatformID":5,"exp":{}}}"#, exp);
+        format!(
+            "eyJhbGciOiJIUzI1NiJ9.{}.sig",
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload)
+        )
+    }
+
+    struct Fixed(String);
+
+    impl TokenRefresher for Fixed {
+        fn refresh(&self) -> BoxFuture<'static, Result<String, OpenIMError>> {
+            let token = self.0.clone();
+            Box::pin(async move { Ok(token) })
+        }
+    }
+
+    #[test]
+    fn refresh_delay_before_expiry() {
+        let tokens = TokenManager::new(jwt(10_000));
+        assert_eq!(tokens.refresh_delay(9_000), Some(Duration::from_secs(700)));
+        assert_eq!(tokens.refresh_delay(9_800), Some(Duration::ZERO));
+        assert!(!tokens.is_expired(9_999));
+        assert!(tokens.is_expired(10_000));
+        assert_eq!(TokenManager::new("opaque".to_string()).refresh_delay(0), None);
+
+        assert!(is_refreshable_error(token_err::EXPIRED));
+        assert!(!is_refreshable_error(token_err::KICKED));
+        assert!(!is_refreshable_error(0));
+        assert!(is_token_error(token_err::KICKED));
+        assert!(!is_token_error(500));
+    }
+
+    #[tokio::test]
+    async fn refresh_replaces_token() {
+        let tokens = TokenManager::new(jwt(10_000));
+        let mut events = tokens.subscribe();
+
+        tokens.warn_expiring();
+        tokens.warn_expiring();
+        assert_eq!(events.try_recv().unwrap(), TokenEvent::WillExpire { expires_at: 10_000 });
+        assert!(events.try_recv().is_err());
+
+        assert!(tokens.refresh().await.is_err());
+        assert!(matches!(events.try_recv().unwrap(), TokenEvent::RefreshFailed { .. }));
+
+        tokens.set_refresher(Arc::new(Fixed(jwt(20_000))));
+        tokens.refresh().await.unwrap();
+        assert_eq!(tokens.token(), jwt(20_000));
+        assert_eq!(events.try_recv().unwrap(), TokenEvent::Refreshed { expires_at: 20_000 });
+        // 新 token 重新计算提醒
+        tokens.warn_expiring();
+        assert_eq!(events.try_recv().unwrap(), TokenEvent::WillExpire { expires_at: 20_000 });
+    }
+}
@@ -0,0 +1,196 @@
+//! 传输层
+//!
+//! 协议层只通过 [`Transport`] 收发帧，不依赖具体的 WebSocket 实现。默认使用 tokio-tungstenite，
+//! 测试或嵌入其他网络栈时可以换成内存实现（[`MemoryConnector`]）。收发互不阻塞，可以在读循环之外发送。
+
+use futures_util::future::BoxFuture;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use tokio::net::TcpStream;
+use tokio::sync::{mpsc, Mutex};
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use super::OpenIMError;
+
+/// 一帧数据
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    /// 对端关闭连接（附带原因）
+    Close(Option<String>),
+}
+
+/// 已建立的连接
+pub trait Transport: Send + Sync {
+    /// 发送一帧
+    fn send(&self, frame: Frame) -> BoxFuture<'_, Result<(), OpenIMError>>;
+    /// 接收下一帧，连接结束时返回 None
+    fn recv(&self) -> BoxFuture<'_, Option<Result<Frame, OpenIMError>>>;
+    /// 关闭连接
+    fn close(&self) -> BoxFuture<'_, Result<(), OpenIMError>>;
+}
+
+/// 建立连接
+pub trait Connector: Send + Sync {
+    fn connect(&self, url: String) -> BoxFuture<'static, Result<Box<dyn Transport>, OpenIMError>>;
+}
+
+fn transport_error(e: impl std::fmt::Display) -> OpenIMError {
+    OpenIMError::Transport(e.to_string())
+}
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// tokio-tungstenite 连接
+pub struct WsTransport {
+    write: Mutex<SplitSink<WsStream, WsMessage>>,
+    read: Mutex<SplitStream<WsStream>>,
+}
+
+impl WsTransport {
+    pub fn new(stream: WsStream) -> Self {
+        let (write, read) = stream.split();
+        Self { write: Mutex::new(write), read: Mutex::new(read) }
+    }
+}
+
+impl Transport for WsTransport {
+    fn send(&self, frame: Frame) -> BoxFuture<'_, Result<(), OpenIMError>> {
+        let msg = match frame {
+            Frame::Text(text) => WsMessage::Text(text),
+            Frame::Binary(data) => WsMessage::Binary(data),
+            Frame::Ping(data) => WsMessage::Ping(data),
+            Frame::Pong(data) => WsMessage::Pong(data),
+            Frame::Close(_) => WsMessage::Close(None),
+        };
+        Box::pin(async move { self.write.lock().await.send(msg).await.map_err(transport_error) })
+    }
+
+    fn recv(&self) -> BoxFuture<'_, Option<Result<Frame, OpenIMError>>> {
+        Box::pin(async move {
+            let mut read = self.read.lock().await;
+            loop {
+                let frame = match read.next().await? {
+                    Ok(WsMessage::Text(text)) => Frame::Text(text),
+                    Ok(WsMessage::Binary(data)) => Frame::Binary(data),
+                    Ok(WsMessage::Ping(data)) => Frame::Ping(data),
+                    Ok(WsMessage::Pong(data)) => Frame::Pong(data),
+                    Ok(WsMessage::Close(frame)) => Frame::Close(frame.map(|f| f.reason.into_owned())),
+                    // 原始帧只在写入时出现
+                    Ok(WsMessage::Frame(_)) => continue,
+                    Err(e) => return Some(Err(transport_error(e))),
+                };
+                return Some(Ok(frame));
+            }
+        })
+    }
+
+    fn close(&self) -> BoxFuture<'_, Result<(), OpenIMError>> {
+        Box::pin(async move { self.write.lock().await.close().await.map_err(transport_error) })
+    }
+}
+
+/// 使用 tokio-tungstenite 建立 WebSocket 连接（默认）
+#[derive(Default)]
+pub struct WsConnector;
+
+impl Connector for WsConnector {
+    fn connect(&self, url: String) -> BoxFuture<'static, Result<Box<dyn Transport>, OpenIMError>> {
+        Box::pin(async move {
+            let (stream, response) = tokio_tungstenite::connect_async(&url).await.map_err(transport_error)?;
+            tracing::info!(status = %response.status(), "WebSocket 已连接");
+            Ok(Box::new(WsTransport::new(stream)) as Box<dyn Transport>)
+        })
+    }
+}
+
+/// 内存连接，由 [`MemoryTransport::pair`] 成对创建，一端发送的帧由另一端接收
+pub struct MemoryTransport {
+    tx: std::sync::Mutex<Option<mpsc::UnboundedSender<Frame>>>,
+    rx: Mutex<mpsc::UnboundedReceiver<Frame>>,
+}
+
+impl MemoryTransport {
+    pub fn pair() -> (Self, Self) {
+        let (a_tx, a_rx) = mpsc::unbounded_channel();
+        let (b_tx, b_rx) = mpsc::unbounded_channel();
+        (
+            Self { tx: std::sync::Mutex::new(Some(a_tx)), rx: Mutex::new(b_rx) },
+            Self { tx: std::sync::Mutex::new(Some(b_tx)), rx: Mutex::new(a_rx) },
+        )
+    }
+}
+
+impl Transport for MemoryTransport {
+    fn send(&self, frame: Frame) -> BoxFuture<'_, Result<(), OpenIMError>> {
+        let result = match self.tx.lock().unwrap().as_ref() {
+            Some(tx) => tx.send(frame).map_err(|_| transport_error("对端已关闭")),
+            None => Err(transport_error("连接已关闭")),
+        };
+        Box::pin(async move { result })
+    }
+
+    fn recv(&self) -> BoxFuture<'_, Option<Result<Frame, OpenIMError>>> {
+        Box::pin(async move { self.rx.lock().await.recv().await.map(Ok) })
+    }
+
+    fn close(&self) -> BoxFuture<'_, Result<(), OpenIMError>> {
+        // 丢弃发送端，对端读到连接结束
+        self.tx.lock().unwrap().take();
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// 内存连接器：每次连接创建一对 [`MemoryTransport`]，服务端一端和连接地址通过通道交给调用方
+pub struct MemoryConnector {
+    accepted: mpsc::UnboundedSender<(String, MemoryTransport)>,
+}
+
+impl MemoryConnector {
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<(String, MemoryTransport)>) {
+        let (accepted, incoming) = mpsc::unbounded_channel();
+        (Self { accepted }, incoming)
+    }
+}
+
+impl Connector for MemoryConnector {
+    fn connect(&self, url: String) -> BoxFuture<'static, Result<Box<dyn Transport>, OpenIMError>> {
+        let (client, server) = MemoryTransport::pair();
+        let result = self
+            .accepted
+            .send((url, server))
+            .map(|()| Box::new(client) as Box<dyn Transport>)
+            .map_err(|_| transport_error("连接被拒绝"));
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_connector_delivers_frames_both_ways() {
+        let (connector, mut incoming) = MemoryConnector::new();
+        let client = connector.connect("mem://gateway?sendID=u1".to_string()).await.unwrap();
+        let (url, server) = incoming.recv().await.unwrap();
+        assert_eq!(url, "mem://gateway?sendID=u1");
+
+        client.send(Frame::Binary(vec![1, 2])).await.unwrap();
+        assert_eq!(server.recv().await.unwrap().unwrap(), Frame::Binary(vec![1, 2]));
+        server.send(Frame::Text("ok".to_string())).await.unwrap();
+        assert_eq!(client.recv().await.unwrap().unwrap(), Frame::Text("ok".to_string()));
+
+        // 一端关闭后另一端读到结束，关闭的一端不能再发送
+        server.close().await.unwrap();
+        assert!(client.recv().await.is_none());
+        assert!(server.send(Frame::Ping(vec![])).await.is_err());
+
+        drop(incoming);
+        assert!(connector.connect("mem://gateway".to_string()).await.is_err());
+    }
+}
@@ -0,0 +1,195 @@
+//! 正在输入状态：通过 WS_SEND_SIGNAL_MSG 发送，不存储；接收方超时或连接断开时自动清除
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use tokio::sync::broadcast;
+
+use super::content::msg_option;
+
+/// 输入中状态的最小重发间隔（毫秒）
+pub const TYPING_RESEND_INTERVAL_MS: i64 = 3_000;
+/// 超过该时间未收到新的输入中信令，视为停止输入（毫秒）
+pub const TYPING_TIMEOUT_MS: i64 = 6_000;
+
+/// 正在输入状态变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypingEvent {
+    pub conversation_id: String,
+    pub user_id: String,
+    pub typing: bool,
+}
+
+/// 发送端限流：输入中每个会话按间隔最多发送一次，停止输入只在之前发送过输入中时发送
+#[derive(Default)]
+pub struct TypingLimiter {
+    /// 会话 ID -> 上次发送输入中的时间
+    last_sent: Mutex<HashMap<String, i64>>,
+}
+
+impl TypingLimiter {
+    /// 判断本次状态是否需要发送
+    pub fn should_send(&self, conversation_id: &str, typing: bool, now: i64) -> bool {
+        let mut last_sent = self.last_sent.lock().unwrap();
+        if !typing {
+            return last_sent.remove(conversation_id).is_some();
+        }
+        match last_sent.get(conversation_id) {
+            Some(at) if now - at < TYPING_RESEND_INTERVAL_MS => false,
+            _ => {
+                last_sent.insert(conversation_id.to_string(), now);
+                true
+            }
+        }
+    }
+}
+
+/// 接收端：记录各会话中正在输入的用户，超时后自动清除
+pub struct TypingTracker {
+    /// (会话 ID, 用户 ID) -> 过期时间
+    active: Mutex<HashMap<(String, String), i64>>,
+    events: broadcast::Sender<TypingEvent>,
+}
+
+impl TypingTracker {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(64);
+        Self {
+            active: Mutex::new(HashMap::new()),
+            events,
+        }
+    }
+
+    /// 订阅正在输入状态变化
+    pub fn subscribe(&self) -> broadcast::Receiver<TypingEvent> {
+        self.events.subscribe()
+    }
+
+    /// 收到对方的输入信令
+    pub fn on_signal(&self, conversation_id: &str, user_id: &str, typing: bool, now: i64) {
+        let key = (conversation_id.to_string(), user_id.to_string());
+        let changed = {
+            let mut active = self.active.lock().unwrap();
+            if typing {
+                active.insert(key, now + TYPING_TIMEOUT_MS).is_none()
+            } else {
+                active.remove(&key).is_some()
+            }
+        };
+        // 输入中的续期信令不重复通知
+        if changed {
+            self.emit(conversation_id, user_id, typing);
+        }
+    }
+
+    /// 清除已超时的输入状态
+    pub fn expire(&self, now: i64) {
+        let expired: Vec<(String, String)> = {
+            let mut active = self.active.lock().unwrap();
+            let keys: Vec<_> = active
+                .iter()
+                .filter(|(_, expires_at)| **expires_at <= now)
+                .map(|(k, _)| k.clone())
+                .collect();
+            keys.into_iter().filter(|k| active.remove(k).is_some()).collect()
+        };
+        for (conversation_id, user_id) in expired {
+            self.emit(&conversation_id, &user_id, false);
+        }
+    }
+
+    /// 清除全部输入状态（连接断开后收不到停止输入信令，也不再定时检查超时）
+    pub fn clear(&self) {
+        let cleared: Vec<(String, String)> = self.active.lock().unwrap().drain().map(|(k, _)| k).collect();
+        for (conversation_id, user_id) in cleared {
+            self.emit(&conversation_id, &user_id, false);
+        }
+    }
+
+    fn emit(&self, conversation_id: &str, user_id: &str, typing: bool) {
+        let _ = self.events.send(TypingEvent {
+            conversation_id: conversation_id.to_string(),
+            user_id: user_id.to_string(),
+            typing,
+        });
+    }
+}
+
+impl Default for TypingTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 信令消息选项：不存历史、不持久化、不计未读、不离线推送、不更新会话、不同步给发送者其他端
+pub fn signal_options() -> HashMap<String, bool> {
+    [
+        msg_option::IS_HISTORY,
+        msg_option::IS_PERSISTENT,
+        msg_option::IS_UNREAD_COUNT,
+        msg_option::IS_OFFLINE_PUSH,
+        msg_option::IS_CONVERSATION_UPDATE,
+        msg_option::IS_SENDER_SYNC,
+    ]
+    .into_iter()
+    .map(|k| (k.to_string(), false))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 输入中按间隔限流；停止输入只发送一次
+    #[test]
+    fn limiter_rate_limits_typing() {
+        let limiter = TypingLimiter::default();
+        assert!(!limiter.should_send("si_a", false, 0));
+        assert!(limiter.should_send("si_a", true, 0));
+        assert!(!limiter.should_send("si_a", true, 1_000));
+        assert!(limiter.should_send("si_b", true, 1_000));
+        assert!(limiter.should_send("si_a", true, TYPING_RESEND_INTERVAL_MS));
+        assert!(limiter.should_send("si_a", false, 4_000));
+        assert!(!limiter.should_send("si_a", false, 4_100));
+        assert!(limiter.should_send("si_a", true, 4_200));
+    }
+
+    /// 续期不重复通知，超时后自动发出停止输入
+    #[test]
+    fn tracker_expires_typing() {
+        let tracker = TypingTracker::new();
+        let mut events = tracker.subscribe();
+
+        tracker.on_signal("si_a", "u2", true, 0);
+        tracker.on_signal("si_a", "u2", true, 2_000);
+        assert!(events.try_recv().unwrap().typing);
+        assert!(events.try_recv().is_err());
+
+        tracker.expire(TYPING_TIMEOUT_MS);
+        assert!(events.try_recv().is_err());
+        tracker.expire(2_000 + TYPING_TIMEOUT_MS);
+        let event = events.try_recv().unwrap();
+        assert_eq!((event.user_id.as_str(), event.typing), ("u2", false));
+
+        tracker.on_signal("si_a", "u2", false, 9_000);
+        assert!(events.try_recv().is_err());
+    }
+
+    /// 连接结束时清除全部输入状态
+    #[test]
+    fn tracker_clear_stops_all_typing() {
+        let tracker = TypingTracker::new();
+        let mut events = tracker.subscribe();
+
+        tracker.on_signal("si_a", "u2", true, 0);
+        tracker.on_signal("sg_g1", "u3", true, 0);
+        while events.try_recv().is_ok() {}
+
+        tracker.clear();
+        let mut stopped: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).map(|e| (e.user_id, e.typing)).collect();
+        stopped.sort();
+        assert_eq!(stopped, [("u2".to_string(), false), ("u3".to_string(), false)]);
+        tracker.clear();
+        assert!(events.try_recv().is_err());
+    }
+}
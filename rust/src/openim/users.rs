@@ -0,0 +1,171 @@
+//! 用户资料及本地缓存
+//!
+//! 批量获取时优先使用缓存，超过有效期或收到资料变更通知的用户再从服务器获取。
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rusqlite::params;
+use serde::Deserialize;
+
+use super::content::{content_type, notification_detail};
+use super::rest::user::UserInfo;
+use super::rest::ApiClient;
+use super::store::Store;
+use super::OpenIMError;
+
+/// 缓存有效期（毫秒）
+pub const PROFILE_TTL_MS: i64 = 10 * 60 * 1000;
+
+/// 缓存是否需要重新获取（fetched_at 为 0 表示收到过变更通知）
+fn is_stale(fetched_at: i64, now_ms: i64) -> bool {
+    fetched_at == 0 || now_ms - fetched_at >= PROFILE_TTL_MS
+}
+
+/// UserInfoUpdatedTips
+#[derive(Deserialize)]
+struct UserInfoUpdatedTips {
+    #[serde(rename = "userID", default)]
+    user_id: String,
+}
+
+/// 用户资料服务
+#[derive(Clone)]
+pub struct UserProfiles {
+    store: Arc<Store>,
+}
+
+impl UserProfiles {
+    pub fn new(store: Arc<Store>) -> Self {
+        Self { store }
+    }
+
+    /// 缓存中的资料及获取时间
+    fn cached(&self, user_ids: &[String]) -> Result<HashMap<String, (UserInfo, i64)>, OpenIMError> {
+        let conn = self.store.conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT user_id, nickname, face_url, ex, create_time, global_recv_msg_opt, fetched_at \
+             FROM users WHERE user_id = ?1",
+        )?;
+        let mut cached = HashMap::new();
+        for user_id in user_ids {
+            let mut rows = stmt.query(params![user_id])?;
+            if let Some(row) = rows.next()? {
+                let user = UserInfo {
+                    user_id: row.get(0)?,
+                    nickname: row.get(1)?,
+                    face_url: row.get(2)?,
+                    ex: row.get(3)?,
+                    create_time: row.get(4)?,
+                    global_recv_msg_opt: row.get(5)?,
+                };
+                cached.insert(user_id.clone(), (user, row.get(6)?));
+            }
+        }
+        Ok(cached)
+    }
+
+    fn save(&self, users: &[UserInfo], now_ms: i64) -> Result<(), OpenIMError> {
+        let mut conn = self.store.conn()?;
+        let tx = conn.transaction()?;
+        for u in users {
+            tx.execute(
+                "INSERT OR REPLACE INTO users (user_id, nickname, face_url, ex, create_time, global_recv_msg_opt, fetched_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![u.user_id, u.nickname, u.face_url, u.ex, u.create_time, u.global_recv_msg_opt, now_ms],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// 批量获取用户资料（按传入顺序返回，服务器上不存在的用户会被跳过）
+    ///
+    /// `force` 为 true 时忽略缓存。
+    pub async fn get(
+        &self,
+        api: &ApiClient,
+        user_ids: &[String],
+        force: bool,
+        now_ms: i64,
+    ) -> Result<Vec<UserInfo>, OpenIMError> {
+        let mut cached = self.cached(user_ids)?;
+        let stale: Vec<String> = user_ids
+            .iter()
+            .filter(|id| force || cached.get(*id).is_none_or(|(_, fetched_at)| is_stale(*fetched_at, now_ms)))
+            .cloned()
+            .collect();
+        if !stale.is_empty() {
+            let fetched = api.get_users_info(&stale).await?;
+            self.save(&fetched, now_ms)?;
+            for user in fetched {
+                cached.insert(user.user_id.clone(), (user, now_ms));
+            }
+        }
+        Ok(user_ids.iter().filter_map(|id| cached.remove(id).map(|(user, _)| user)).collect())
+    }
+
+    /// 修改自己的资料并刷新缓存，None 表示不修改
+    pub async fn update_self(
+        &self,
+        api: &ApiClient,
+        me: &str,
+        nickname: Option<&str>,
+        face_url: Option<&str>,
+        ex: Option<&str>,
+        now_ms: i64,
+    ) -> Result<UserInfo, OpenIMError> {
+        api.update_user_info_ex(me, nickname, face_url, ex).await?;
+        self.get(api, &[me.to_string()], true, now_ms)
+            .await?
+            .pop()
+            .ok_or_else(|| OpenIMError::NotFound(format!("用户 {}", me)))
+    }
+
+    /// 资料变更通知：缓存标记为过期，下次获取时重新拉取
+    pub fn apply_notification(&self, content_type: i32, content: &[u8]) -> Result<(), OpenIMError> {
+        if content_type != content_type::USER_INFO_UPDATED {
+            return Ok(());
+        }
+        let tips: UserInfoUpdatedTips = notification_detail(content)?;
+        self.store
+            .conn()?
+            .execute("UPDATE users SET fetched_at = 0 WHERE user_id = ?1", params![tips.user_id])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::rest::tests::serve;
+    use super::*;
+
+    #[tokio::test]
+    async fn get_uses_cache_until_stale() {
+        let (base_url, server) = serve(&[
+            r#"{"errCode":0,"data":{"usersInfo":[{"userID":"u1","nickname":"张三"},{"userID":"u2","nickname":"李四"}]}}"#,
+            r#"{"errCode":0,"data":{"usersInfo":[{"userID":"u2","nickname":"李四四"}]}}"#,
+        ])
+        .await;
+        let api = ApiClient::new(base_url, "tok".to_string(), reqwest::Client::new());
+        let users = UserProfiles::new(Arc::new(Store::open_in_memory().unwrap()));
+        let ids = ["u2".to_string(), "u1".to_string()];
+
+        let got = users.get(&api, &ids, false, 1_000).await.unwrap();
+        assert_eq!(got[0].nickname, "李四");
+        assert_eq!(got[1].nickname, "张三");
+
+        // 缓存有效期内不请求服务器
+        users.get(&api, &ids, false, 2_000).await.unwrap();
+
+        // 资料变更通知后只重新获取变更的用户
+        let notification = serde_json::to_vec(&serde_json::json!({ "detail": r#"{"userID":"u2"}"# })).unwrap();
+        users.apply_notification(content_type::USER_INFO_UPDATED, &notification).unwrap();
+        let got = users.get(&api, &ids, false, 3_000).await.unwrap();
+        assert_eq!(got[0].nickname, "李四四");
+        assert_eq!(got[1].nickname, "张三");
+
+        let requests = server.await.unwrap();
+        assert!(requests[1].ends_with(r#"{"userIDs":["u2"]}"#));
+    }
+}